@@ -0,0 +1,84 @@
+use reqwest::{Certificate, Client, Proxy};
+use std::env;
+use std::path::Path;
+use std::sync::OnceLock;
+use thiserror::Error;
+
+/// Proxy URL for all ndl traffic (overrides HTTPS_PROXY / HTTP_PROXY)
+pub const PROXY_ENV: &str = "NDL_PROXY";
+
+/// PEM file of root certificates to trust on top of the built-in roots,
+/// for networks with TLS-inspecting proxies
+pub const CA_BUNDLE_ENV: &str = "NDL_CA_BUNDLE";
+
+/// Program name in the User-Agent, set once at startup (defaults to "ndl")
+static USER_AGENT_PRODUCT: OnceLock<&'static str> = OnceLock::new();
+
+/// Name the running binary in the User-Agent, e.g. `set_user_agent_product("ndld")`.
+/// Call before building any clients; later calls are ignored.
+pub fn set_user_agent_product(product: &'static str) {
+    let _ = USER_AGENT_PRODUCT.set(product);
+}
+
+/// `ndl/0.2.15 (+https://github.com/pgray/ndl)`, so Meta and Bluesky can
+/// attribute traffic from the tool
+pub fn user_agent() -> String {
+    format!(
+        "{}/{} (+{})",
+        USER_AGENT_PRODUCT.get().copied().unwrap_or("ndl"),
+        env!("CARGO_PKG_VERSION"),
+        env!("CARGO_PKG_REPOSITORY")
+    )
+}
+
+#[derive(Debug, Error)]
+pub enum HttpError {
+    #[error("Invalid NDL_PROXY: {0}")]
+    Proxy(reqwest::Error),
+    #[error("NDL_CA_BUNDLE file not found: {0}")]
+    CaBundleMissing(String),
+    #[error("Failed to read NDL_CA_BUNDLE: {0}")]
+    CaBundleRead(#[from] std::io::Error),
+    #[error("Invalid PEM in NDL_CA_BUNDLE: {0}")]
+    CaBundle(reqwest::Error),
+    #[error("NDL_CA_BUNDLE contains no PEM certificates: {0}")]
+    CaBundleEmpty(String),
+    #[error("Failed to build HTTP client: {0}")]
+    Build(reqwest::Error),
+}
+
+/// Build an HTTP client with ndl's proxy and root-certificate settings.
+/// Without NDL_PROXY, reqwest still honors the standard proxy variables.
+pub fn http_client() -> Result<Client, HttpError> {
+    let mut builder = Client::builder().user_agent(user_agent());
+
+    if let Some(url) = env_value(PROXY_ENV) {
+        builder = builder.proxy(Proxy::all(&url).map_err(HttpError::Proxy)?);
+    }
+
+    if let Some(path) = env_value(CA_BUNDLE_ENV) {
+        for cert in load_ca_bundle(&path)? {
+            builder = builder.add_root_certificate(cert);
+        }
+    }
+
+    builder.build().map_err(HttpError::Build)
+}
+
+fn load_ca_bundle(path: &str) -> Result<Vec<Certificate>, HttpError> {
+    if !Path::new(path).exists() {
+        return Err(HttpError::CaBundleMissing(path.to_string()));
+    }
+
+    let pem = std::fs::read(path)?;
+    let certs = Certificate::from_pem_bundle(&pem).map_err(HttpError::CaBundle)?;
+    if certs.is_empty() {
+        return Err(HttpError::CaBundleEmpty(path.to_string()));
+    }
+
+    Ok(certs)
+}
+
+fn env_value(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|v| !v.is_empty())
+}
@@ -1,6 +1,11 @@
+mod http;
 mod oauth;
 
+pub use http::{
+    CA_BUNDLE_ENV, HttpError, PROXY_ENV, http_client, set_user_agent_product, user_agent,
+};
 pub use oauth::{
-    OAUTH_SCOPES, TOKEN_URL, TokenExchangeError, TokenResponse, exchange_code,
-    exchange_for_long_lived_token, refresh_access_token,
+    AUTHORIZE_URL, OAUTH_SCOPES, READ_ONLY_SCOPES, SCOPES_ENV, TOKEN_URL, TokenExchangeError,
+    TokenResponse, authorization_url, exchange_code, exchange_for_long_lived_token, fetch_username,
+    normalize_scopes, oauth_scopes, refresh_access_token,
 };
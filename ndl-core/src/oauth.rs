@@ -2,8 +2,63 @@ use serde::{Deserialize, Deserializer, de};
 use thiserror::Error;
 
 pub const TOKEN_URL: &str = "https://graph.threads.net/oauth/access_token";
+pub const AUTHORIZE_URL: &str = "https://threads.net/oauth/authorize";
+const ME_URL: &str = "https://graph.threads.net/v1.0/me";
 pub const OAUTH_SCOPES: &str =
     "threads_basic,threads_read_replies,threads_manage_replies,threads_content_publish";
+/// Scopes for browsing only: no publishing or reply management
+pub const READ_ONLY_SCOPES: &str = "threads_basic,threads_read_replies";
+/// Environment variable overriding the requested scopes (comma-separated)
+pub const SCOPES_ENV: &str = "NDL_SCOPES";
+
+/// Clean up a comma- or space-separated scope list, or None if it has no scopes
+pub fn normalize_scopes(scopes: &str) -> Option<String> {
+    let mut list: Vec<&str> = Vec::new();
+    for scope in scopes.split([',', ' ', '\t', '\n']).map(str::trim) {
+        if !scope.is_empty() && !list.contains(&scope) {
+            list.push(scope);
+        }
+    }
+    (!list.is_empty()).then(|| list.join(","))
+}
+
+/// Scopes to request at login: `NDL_SCOPES` wins, then `configured`, then the
+/// full or read-only defaults
+pub fn oauth_scopes(configured: Option<&str>, read_only: bool) -> String {
+    std::env::var(SCOPES_ENV)
+        .ok()
+        .and_then(|s| normalize_scopes(&s))
+        .or_else(|| configured.and_then(normalize_scopes))
+        .unwrap_or_else(|| {
+            if read_only {
+                READ_ONLY_SCOPES
+            } else {
+                OAUTH_SCOPES
+            }
+            .to_string()
+        })
+}
+
+/// Threads authorization URL; `state` is echoed back to the redirect URI
+pub fn authorization_url(
+    client_id: &str,
+    redirect_uri: &str,
+    scopes: &str,
+    state: Option<&str>,
+) -> String {
+    let mut url = format!(
+        "{}?client_id={}&redirect_uri={}&scope={}&response_type=code",
+        AUTHORIZE_URL,
+        client_id,
+        urlencoding::encode(redirect_uri),
+        urlencoding::encode(scopes)
+    );
+    if let Some(state) = state {
+        url.push_str("&state=");
+        url.push_str(&urlencoding::encode(state));
+    }
+    url
+}
 
 /// Deserialize user_id from either a string or number (Threads API returns both), or None if missing
 fn deserialize_user_id_opt<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
@@ -68,7 +123,7 @@ pub async fn exchange_code(
     redirect_uri: &str,
     code: &str,
 ) -> Result<TokenResponse, TokenExchangeError> {
-    let client = reqwest::Client::new();
+    let client = crate::http_client().map_err(|e| TokenExchangeError::Request(e.to_string()))?;
 
     let params = [
         ("client_id", client_id),
@@ -99,7 +154,7 @@ pub async fn exchange_for_long_lived_token(
     client_secret: &str,
     short_lived_token: &str,
 ) -> Result<TokenResponse, TokenExchangeError> {
-    let client = reqwest::Client::new();
+    let client = crate::http_client().map_err(|e| TokenExchangeError::Request(e.to_string()))?;
 
     let url = format!(
         "https://graph.threads.net/access_token?grant_type=th_exchange_token&client_secret={}&access_token={}",
@@ -121,11 +176,41 @@ pub async fn exchange_for_long_lived_token(
     parse_token_response(response).await
 }
 
+/// Look up the Threads username a freshly issued token belongs to, for
+/// confirming which account just logged in
+pub async fn fetch_username(access_token: &str) -> Result<String, TokenExchangeError> {
+    #[derive(Deserialize)]
+    struct Me {
+        username: String,
+    }
+
+    let client = crate::http_client().map_err(|e| TokenExchangeError::Request(e.to_string()))?;
+
+    let response = client
+        .get(ME_URL)
+        .query(&[("fields", "username"), ("access_token", access_token)])
+        .send()
+        .await
+        .map_err(|e| TokenExchangeError::Request(e.to_string()))?;
+
+    if !response.status().is_success() {
+        let status = response.status().as_u16();
+        let body = response.text().await.unwrap_or_default();
+        return Err(TokenExchangeError::Http { status, body });
+    }
+
+    let me: Me = response
+        .json()
+        .await
+        .map_err(|e| TokenExchangeError::Parse(e.to_string()))?;
+    Ok(me.username)
+}
+
 /// Refresh a long-lived access token (extends validity by another 60 days)
 pub async fn refresh_access_token(
     long_lived_token: &str,
 ) -> Result<TokenResponse, TokenExchangeError> {
-    let client = reqwest::Client::new();
+    let client = crate::http_client().map_err(|e| TokenExchangeError::Request(e.to_string()))?;
 
     let url = format!(
         "https://graph.threads.net/refresh_access_token?grant_type=th_refresh_token&access_token={}",
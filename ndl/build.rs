@@ -1,3 +1,4 @@
+use std::env;
 use std::process::Command;
 
 fn main() {
@@ -11,7 +12,22 @@ fn main() {
         .map(|s| s.trim().to_string())
         .unwrap_or_else(|| "unknown".to_string());
 
+    // Get the compiler version used for this build (for `ndl version`)
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rustc_version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let target = env::var("TARGET").unwrap_or_else(|_| "unknown".to_string());
+
     println!("cargo::rerun-if-changed=.git/HEAD");
     println!("cargo::rerun-if-changed=.git/refs/tags");
     println!("cargo::rustc-env=NDL_GIT_VERSION={}", git_describe);
+    println!("cargo::rustc-env=NDL_RUSTC_VERSION={}", rustc_version);
+    println!("cargo::rustc-env=NDL_TARGET={}", target);
 }
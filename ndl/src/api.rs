@@ -2,23 +2,230 @@ use async_trait::async_trait;
 use futures::future::join_all;
 use reqwest::Client;
 use serde::Deserialize;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
-use crate::platform::{PlatformError, Post, ReplyThread as PlatformReplyThread, SocialClient};
+use crate::platform::{
+    Capabilities, ImageAttachment, PROFILE_TTL, PlatformError, Post, Profile, ReplyAudience,
+    ReplyThread as PlatformReplyThread, SocialClient, TtlCache,
+};
 
 const BASE_URL: &str = "https://graph.threads.net";
 
+/// How long a created-but-unpublished container is reused when the same post
+/// is sent again. Threads expires containers after 24 hours.
+const CONTAINER_REUSE_TTL: Duration = Duration::from_secs(60 * 60);
+
 #[derive(Debug, Error)]
 pub enum ApiError {
     #[error("HTTP request failed: {0}")]
     Request(#[from] reqwest::Error),
     #[error("API error: {0}")]
     Api(String),
+    #[error(
+        "Your Threads app lacks the {0} permission; run `ndl login threads` to re-authorize with that scope"
+    )]
+    MissingScope(String),
+    /// The post was deleted (or otherwise can't be loaded anymore)
+    #[error("Post {0} no longer exists")]
+    NotFound(String),
+    /// The access token is invalid, expired, or revoked (Meta code 190 / HTTP 401)
+    #[error("Threads access token is invalid or expired: {0}")]
+    Unauthorized(String),
+    /// The API session expired (Meta code 102)
+    #[error("Threads session expired: {0}")]
+    SessionExpired(String),
 }
 
-#[allow(dead_code)]
+impl ApiError {
+    /// Whether logging in again would fix this. Bodies that weren't Graph API
+    /// JSON fall back to a text match.
+    pub fn is_auth(&self) -> bool {
+        match self {
+            ApiError::Unauthorized(_) | ApiError::SessionExpired(_) => true,
+            ApiError::Api(body) => looks_like_auth_error(body),
+            _ => false,
+        }
+    }
+}
+
+/// Graph API error code for an invalid or expired access token
+const INVALID_TOKEN_CODE: i64 = 190;
+
+/// Graph API error code for an expired API session
+const SESSION_EXPIRED_CODE: i64 = 102;
+
+/// Graph API codes for a permission the token wasn't granted: 10, or the
+/// 200-299 range
+fn is_permission_code(code: i64) -> bool {
+    code == 10 || (200..=299).contains(&code)
+}
+
+/// The `error` object of a Graph API error response
 #[derive(Debug, Deserialize)]
+struct GraphError {
+    code: Option<i64>,
+    message: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphErrorBody {
+    error: GraphError,
+}
+
+/// Classify a failed response by its status and Graph API error code
+fn response_error(status: reqwest::StatusCode, body: String) -> ApiError {
+    let code = serde_json::from_str::<GraphErrorBody>(&body)
+        .ok()
+        .and_then(|b| b.error.code);
+    match code {
+        Some(INVALID_TOKEN_CODE) => ApiError::Unauthorized(body),
+        Some(SESSION_EXPIRED_CODE) => ApiError::SessionExpired(body),
+        None if status == reqwest::StatusCode::UNAUTHORIZED => ApiError::Unauthorized(body),
+        _ => ApiError::Api(body),
+    }
+}
+
+/// Text match for auth failures in bodies that didn't parse as a Graph API error
+fn looks_like_auth_error(error: &str) -> bool {
+    if serde_json::from_str::<GraphErrorBody>(error).is_ok() {
+        // Structured errors were already classified by code
+        return false;
+    }
+    let error_lower = error.to_lowercase();
+    error_lower.contains("oauthexception")
+        || error_lower.contains("invalid access token")
+        || error_lower.contains("session has expired")
+        || error_lower.contains("token has expired")
+        || error.contains("\"code\":190") // Facebook/Meta invalid token code
+        || error.contains("\"code\":102") // Session expired
+}
+
+/// Scope assumed when a publish fails on permissions without naming one
+const PUBLISH_SCOPE: &str = "threads_content_publish";
+
+/// Turn a failed publish response into an error, recognizing missing-permission failures
+fn publish_error(context: &str, body: &str) -> ApiError {
+    match missing_scope(body) {
+        Some(scope) => ApiError::MissingScope(scope),
+        None => ApiError::Api(format!("{}: {}", context, body)),
+    }
+}
+
+/// Scope Threads requires to delete posts. It isn't in the default login scopes.
+const DELETE_SCOPE: &str = "threads_delete";
+
+/// Extract the permission a request lacked from a Threads error body.
+/// Falls back to the publish scope when the error doesn't name one.
+fn missing_scope(body: &str) -> Option<String> {
+    missing_scope_or(body, PUBLISH_SCOPE)
+}
+
+/// Like `missing_scope`, with `fallback` for errors that don't name a scope.
+/// Only Graph API permission codes, or a message naming a `threads_*`
+/// permission, count; other errors mentioning permission (like a reply
+/// restriction) don't.
+fn missing_scope_or(body: &str, fallback: &str) -> Option<String> {
+    let error = serde_json::from_str::<GraphErrorBody>(body)
+        .ok()
+        .map(|b| b.error);
+    let permission_code = error
+        .as_ref()
+        .and_then(|e| e.code)
+        .is_some_and(is_permission_code);
+    let message = error
+        .and_then(|e| e.message)
+        .unwrap_or_else(|| body.to_string())
+        .to_lowercase();
+
+    let named = message
+        .find("threads_")
+        .map(|start| {
+            message[start..]
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                .collect::<String>()
+        })
+        .filter(|scope| scope.len() > "threads_".len());
+
+    if permission_code {
+        Some(named.unwrap_or_else(|| fallback.to_string()))
+    } else if message.contains("permission") {
+        named
+    } else {
+        None
+    }
+}
+
+/// Fields requested for the feed unless `threads_fields` overrides them
+pub const DEFAULT_THREAD_FIELDS: &[&str] = &[
+    "id",
+    "text",
+    "username",
+    "timestamp",
+    "media_type",
+    "permalink",
+    "has_replies",
+    "reply_audience",
+];
+
+/// Threads media fields that `threads_fields` may name
+const KNOWN_THREAD_FIELDS: &[&str] = &[
+    "id",
+    "text",
+    "username",
+    "timestamp",
+    "media_type",
+    "media_product_type",
+    "media_url",
+    "permalink",
+    "shortcode",
+    "thumbnail_url",
+    "alt_text",
+    "link_attachment_url",
+    "has_replies",
+    "is_reply",
+    "is_quote_post",
+    "reply_audience",
+    "root_post",
+    "hide_status",
+    "topic_tag",
+];
+
+/// Build the `fields` parameter for feed requests from a configured list,
+/// rejecting names Threads doesn't know. `id` is always included.
+pub fn thread_fields(configured: &[String]) -> Result<String, String> {
+    let mut fields = vec!["id"];
+    for field in configured {
+        let field = field.trim();
+        if !KNOWN_THREAD_FIELDS.contains(&field) {
+            return Err(format!(
+                "unknown field '{}' (known: {})",
+                field,
+                KNOWN_THREAD_FIELDS.join(", ")
+            ));
+        }
+        if !fields.contains(&field) {
+            fields.push(field);
+        }
+    }
+    Ok(fields.join(","))
+}
+
+/// Graph API error subcode for "object does not exist"
+const NONEXISTENT_OBJECT_SUBCODE: &str = "\"error_subcode\":33";
+
+/// Whether a failed response means the requested object is gone
+fn is_not_found(status: reqwest::StatusCode, body: &str) -> bool {
+    status == reqwest::StatusCode::NOT_FOUND
+        || status == reqwest::StatusCode::GONE
+        || body.replace(' ', "").contains(NONEXISTENT_OBJECT_SUBCODE)
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct UserProfile {
     pub id: String,
     pub username: Option<String>,
@@ -35,6 +242,18 @@ pub struct Thread {
     pub timestamp: Option<String>,
     pub media_type: Option<String>,
     pub permalink: Option<String>,
+    /// Only present when the `has_replies` field is requested
+    pub has_replies: Option<bool>,
+    /// Who may reply (`everyone`, `accounts_you_follow`, ...), when requested
+    pub reply_audience: Option<String>,
+    /// The top-level post of the conversation, when the `root_post` field is requested
+    pub root_post: Option<MediaRef>,
+}
+
+/// A reference to another media object by id
+#[derive(Debug, Clone, Deserialize)]
+pub struct MediaRef {
+    pub id: String,
 }
 
 #[allow(dead_code)]
@@ -81,47 +300,106 @@ pub struct PublishResponse {
 pub struct ThreadsClient {
     client: Client,
     access_token: Arc<String>,
+    /// Graph API root, swapped for a local server in tests
+    base_url: Arc<String>,
+    /// `fields` parameter for feed requests
+    fields: Arc<String>,
+    /// The user's own profile, reused for `PROFILE_TTL`
+    profile: TtlCache<UserProfile>,
+    /// Containers whose publish step failed transiently, keyed by the post
+    /// they hold, so sending the same post again publishes them instead of
+    /// creating another
+    unpublished: Arc<Mutex<HashMap<String, (Instant, String)>>>,
 }
 
 impl ThreadsClient {
     pub fn new(access_token: String) -> Self {
         Self {
-            client: Client::new(),
+            // Proxy/CA settings are validated at startup in main
+            client: ndl_core::http_client().unwrap_or_else(|_| {
+                reqwest::Client::builder()
+                    .user_agent(ndl_core::user_agent())
+                    .build()
+                    .unwrap_or_default()
+            }),
             access_token: Arc::new(access_token),
+            base_url: Arc::new(BASE_URL.to_string()),
+            fields: Arc::new(DEFAULT_THREAD_FIELDS.join(",")),
+            profile: TtlCache::new(PROFILE_TTL),
+            unpublished: Arc::default(),
         }
     }
 
-    /// Get the authenticated user's profile
-    #[allow(dead_code)]
+    /// Request these fields (as built by `thread_fields`) for the feed
+    pub fn with_fields(mut self, fields: String) -> Self {
+        self.fields = Arc::new(fields);
+        self
+    }
+
+    /// Point the client at a different Graph API root
+    #[cfg(test)]
+    fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = Arc::new(base_url);
+        self
+    }
+
+    /// Get the authenticated user's profile (cached for a few minutes)
     pub async fn get_profile(&self) -> Result<UserProfile, ApiError> {
+        self.profile.get_or_fetch(|| self.fetch_profile()).await
+    }
+
+    /// Drop the cached profile and fetch it again
+    pub async fn refresh_profile(&self) -> Result<UserProfile, ApiError> {
+        self.profile.clear().await;
+        self.get_profile().await
+    }
+
+    async fn fetch_profile(&self) -> Result<UserProfile, ApiError> {
         let url = format!(
             "{}/me?fields=id,username,name,threads_profile_picture_url,threads_biography&access_token={}",
-            BASE_URL, self.access_token
+            self.base_url, self.access_token
         );
 
         let response = self.client.get(&url).send().await?;
 
         if !response.status().is_success() {
+            let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            return Err(ApiError::Api(body));
+            return Err(response_error(status, body));
         }
 
         Ok(response.json().await?)
     }
 
-    /// Get the authenticated user's threads
+    /// Get the authenticated user's threads. `has_replies` comes back with
+    /// each one by default, so the list never needs a replies request per post.
     pub async fn get_threads(&self, limit: Option<u32>) -> Result<ThreadsResponse, ApiError> {
+        self.get_threads_after(limit, None).await
+    }
+
+    /// Get the page of the user's threads that follows the `after` cursor from
+    /// an earlier response's `paging`, or the first page without one
+    pub async fn get_threads_after(
+        &self,
+        limit: Option<u32>,
+        after: Option<&str>,
+    ) -> Result<ThreadsResponse, ApiError> {
         let limit = limit.unwrap_or(25);
-        let url = format!(
-            "{}/me/threads?fields=id,text,username,timestamp,media_type,permalink&limit={}&access_token={}",
-            BASE_URL, limit, self.access_token
+        let mut url = format!(
+            "{}/me/threads?fields={}&limit={}&access_token={}",
+            self.base_url, self.fields, limit, self.access_token
         );
+        if let Some(after) = after {
+            url.push_str("&after=");
+            url.push_str(&urlencoding::encode(after));
+        }
 
         let response = self.client.get(&url).send().await?;
 
         if !response.status().is_success() {
+            let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            return Err(ApiError::Api(body));
+            return Err(response_error(status, body));
         }
 
         Ok(response.json().await?)
@@ -132,15 +410,16 @@ impl ThreadsClient {
     pub async fn get_replies(&self, limit: Option<u32>) -> Result<ThreadsResponse, ApiError> {
         let limit = limit.unwrap_or(25);
         let url = format!(
-            "{}/me/replies?fields=id,text,username,timestamp,media_type,permalink&limit={}&access_token={}",
-            BASE_URL, limit, self.access_token
+            "{}/me/replies?fields=id,text,username,timestamp,media_type,permalink,reply_audience&limit={}&access_token={}",
+            self.base_url, limit, self.access_token
         );
 
         let response = self.client.get(&url).send().await?;
 
         if !response.status().is_success() {
+            let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            return Err(ApiError::Api(body));
+            return Err(response_error(status, body));
         }
 
         Ok(response.json().await?)
@@ -150,15 +429,19 @@ impl ThreadsClient {
     #[allow(dead_code)]
     pub async fn get_thread(&self, thread_id: &str) -> Result<Thread, ApiError> {
         let url = format!(
-            "{}/{}?fields=id,text,username,timestamp,media_type,permalink&access_token={}",
-            BASE_URL, thread_id, self.access_token
+            "{}/{}?fields=id,text,username,timestamp,media_type,permalink,reply_audience,root_post&access_token={}",
+            self.base_url, thread_id, self.access_token
         );
 
         let response = self.client.get(&url).send().await?;
 
         if !response.status().is_success() {
+            let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            return Err(ApiError::Api(body));
+            if is_not_found(status, &body) {
+                return Err(ApiError::NotFound(thread_id.to_string()));
+            }
+            return Err(response_error(status, body));
         }
 
         Ok(response.json().await?)
@@ -167,15 +450,42 @@ impl ThreadsClient {
     /// Get replies to a specific thread
     pub async fn get_thread_replies(&self, thread_id: &str) -> Result<ThreadsResponse, ApiError> {
         let url = format!(
-            "{}/{}/replies?fields=id,text,username,timestamp&access_token={}",
-            BASE_URL, thread_id, self.access_token
+            "{}/{}/replies?fields=id,text,username,timestamp,reply_audience,root_post&access_token={}",
+            self.base_url, thread_id, self.access_token
         );
 
         let response = self.client.get(&url).send().await?;
 
         if !response.status().is_success() {
+            let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            return Err(ApiError::Api(body));
+            if is_not_found(status, &body) {
+                tracing::debug!("Thread {} not found: {}", thread_id, body);
+                return Err(ApiError::NotFound(thread_id.to_string()));
+            }
+            return Err(response_error(status, body));
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Get every reply in a thread's conversation at any depth, flattened in
+    /// chronological order
+    pub async fn get_conversation(&self, thread_id: &str) -> Result<ThreadsResponse, ApiError> {
+        let url = format!(
+            "{}/{}/conversation?fields=id,text,username,timestamp,permalink,media_type,reply_audience,root_post&reverse=false&access_token={}",
+            self.base_url, thread_id, self.access_token
+        );
+
+        let response = self.client.get(&url).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            if is_not_found(status, &body) {
+                return Err(ApiError::NotFound(thread_id.to_string()));
+            }
+            return Err(response_error(status, body));
         }
 
         Ok(response.json().await?)
@@ -228,41 +538,19 @@ impl ThreadsClient {
 
     /// Wait for container to be ready (poll until FINISHED or ERROR)
     async fn wait_for_container(&self, container_id: &str) -> Result<String, ApiError> {
-        #[derive(Deserialize)]
-        struct StatusResponse {
-            status: Option<String>,
-            error_message: Option<String>,
-        }
-
-        let url = format!(
-            "{}/{}?fields=status,error_message&access_token={}",
-            BASE_URL, container_id, self.access_token
-        );
-
         // Poll up to 15 times with 2s delay (30 seconds max)
         for attempt in 0..15 {
-            let response = self.client.get(&url).send().await?;
-            let body = response.text().await.unwrap_or_default();
-
-            let status_resp: StatusResponse =
-                serde_json::from_str(&body).unwrap_or(StatusResponse {
-                    status: Some("UNKNOWN".to_string()),
-                    error_message: None,
-                });
-
-            let status = status_resp.status.unwrap_or_else(|| "UNKNOWN".to_string());
+            let (status, error_message) = self.container_status(container_id).await?;
             tracing::debug!("Container status check {}: {}", attempt + 1, status);
 
-            if let Some(err) = &status_resp.error_message {
+            if let Some(err) = &error_message {
                 tracing::warn!("Container error: {}", err);
             }
 
             match status.as_str() {
                 "FINISHED" => return Ok(status),
                 "ERROR" => {
-                    let err_msg = status_resp
-                        .error_message
-                        .unwrap_or_else(|| "Unknown error".to_string());
+                    let err_msg = error_message.unwrap_or_else(|| "Unknown error".to_string());
                     return Err(ApiError::Api(format!("Container failed: {}", err_msg)));
                 }
                 "IN_PROGRESS" => {
@@ -277,6 +565,32 @@ impl ThreadsClient {
         Err(ApiError::Api("Container processing timed out".to_string()))
     }
 
+    /// A container's current status (`UNKNOWN` if unreadable) and error message
+    async fn container_status(
+        &self,
+        container_id: &str,
+    ) -> Result<(String, Option<String>), ApiError> {
+        #[derive(Deserialize)]
+        struct StatusResponse {
+            status: Option<String>,
+            error_message: Option<String>,
+        }
+
+        let url = format!(
+            "{}/{}?fields=status,error_message&access_token={}",
+            self.base_url, container_id, self.access_token
+        );
+        let response = self.client.get(&url).send().await?;
+        let body = response.text().await.unwrap_or_default();
+
+        let status_resp: StatusResponse = serde_json::from_str(&body).unwrap_or(StatusResponse {
+            status: Some("UNKNOWN".to_string()),
+            error_message: None,
+        });
+        let status = status_resp.status.unwrap_or_else(|| "UNKNOWN".to_string());
+        Ok((status, status_resp.error_message))
+    }
+
     /// Create a reply to a thread (two-step: create container, then publish)
     pub async fn reply_to_thread(
         &self,
@@ -285,10 +599,27 @@ impl ThreadsClient {
     ) -> Result<PublishResponse, ApiError> {
         tracing::debug!("Attempting reply to thread ID: {}", reply_to_id);
 
+        let key = format!("reply:{}\n{}", reply_to_id, text);
+        let (created, container_id) = match self.unpublished_container(&key).await {
+            Some(container) => container,
+            None => (
+                Instant::now(),
+                self.create_reply_container(reply_to_id, text).await?,
+            ),
+        };
+        self.publish_container(key, created, container_id).await
+    }
+
+    /// Create a reply container and wait until it's ready to publish
+    async fn create_reply_container(
+        &self,
+        reply_to_id: &str,
+        text: &str,
+    ) -> Result<String, ApiError> {
         // Step 1: Create container
         let container_url = format!(
             "{}/me/threads?media_type=TEXT&text={}&reply_to_id={}&access_token={}",
-            BASE_URL,
+            self.base_url,
             urlencoding::encode(text),
             reply_to_id,
             self.access_token
@@ -301,18 +632,12 @@ impl ThreadsClient {
         tracing::debug!("Container creation response ({}): {}", status, body);
 
         if !status.is_success() {
-            return Err(ApiError::Api(format!(
-                "Container creation failed: {}",
-                body
-            )));
+            return Err(publish_error("Container creation failed", &body));
         }
 
         // Check for error in response body (API sometimes returns 200 with error)
         if body.contains("\"error\"") {
-            return Err(ApiError::Api(format!(
-                "Cannot reply to this thread: {}",
-                body
-            )));
+            return Err(publish_error("Cannot reply to this thread", &body));
         }
 
         let container: ContainerResponse = serde_json::from_str(&body)
@@ -330,30 +655,108 @@ impl ThreadsClient {
                 status
             )));
         }
+        Ok(container.id)
+    }
 
-        // Step 2: Publish
+    /// Post a new thread (not a reply), optionally with a link shown as a preview card
+    pub async fn post_thread(
+        &self,
+        text: &str,
+        link_attachment: Option<&str>,
+    ) -> Result<PublishResponse, ApiError> {
+        let key = format!("{}\n{}", link_attachment.unwrap_or_default(), text);
+        let (created, container_id) = match self.unpublished_container(&key).await {
+            Some(container) => container,
+            None => (
+                Instant::now(),
+                self.create_text_container(text, link_attachment).await?,
+            ),
+        };
+        self.publish_container(key, created, container_id).await
+    }
+
+    /// Publish a ready container. A container can only be published once, so
+    /// on a failure that might pass (network, 5xx, 429) it's kept under `key`
+    /// for the next send of the same post, with its original `created` time.
+    async fn publish_container(
+        &self,
+        key: String,
+        created: Instant,
+        container_id: String,
+    ) -> Result<PublishResponse, ApiError> {
         let publish_url = format!(
             "{}/me/threads_publish?creation_id={}&access_token={}",
-            BASE_URL, container.id, self.access_token
+            self.base_url, container_id, self.access_token
         );
 
-        let response = self.client.post(&publish_url).send().await?;
+        let result: Result<PublishResponse, (ApiError, bool)> = async {
+            let response = self
+                .client
+                .post(&publish_url)
+                .send()
+                .await
+                .map_err(|e| (ApiError::from(e), true))?;
+            let status = response.status();
+            if !status.is_success() {
+                let body = response.text().await.unwrap_or_default();
+                let transient =
+                    status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS;
+                return Err((publish_error("Publish failed", &body), transient));
+            }
+            response
+                .json()
+                .await
+                .map_err(|e| (ApiError::from(e), false))
+        }
+        .await;
 
-        if !response.status().is_success() {
-            let body = response.text().await.unwrap_or_default();
-            return Err(ApiError::Api(format!("Publish failed: {}", body)));
+        let mut unpublished = self.unpublished.lock().unwrap_or_else(|e| e.into_inner());
+        if let Err((_, true)) = result {
+            unpublished.insert(key, (created, container_id));
+        } else {
+            unpublished.remove(&key);
         }
+        result.map_err(|(e, _)| e)
+    }
 
-        Ok(response.json().await?)
+    /// A container (with when it was created) left over from a failed publish
+    /// of the same post, if it's recent enough and still ready to publish
+    async fn unpublished_container(&self, key: &str) -> Option<(Instant, String)> {
+        let cached = {
+            let mut unpublished = self.unpublished.lock().unwrap_or_else(|e| e.into_inner());
+            unpublished.retain(|_, (created, _)| created.elapsed() < CONTAINER_REUSE_TTL);
+            unpublished.get(key).cloned()
+        }?;
+        match self.container_status(&cached.1).await {
+            Ok((status, _)) if status == "FINISHED" => {
+                tracing::debug!("Resuming publish of container {}", cached.1);
+                Some(cached)
+            }
+            _ => {
+                self.unpublished
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .remove(key);
+                None
+            }
+        }
     }
 
-    /// Post a new thread (not a reply)
-    pub async fn post_thread(&self, text: &str) -> Result<PublishResponse, ApiError> {
+    /// Create a text container and wait until it's ready to publish
+    async fn create_text_container(
+        &self,
+        text: &str,
+        link_attachment: Option<&str>,
+    ) -> Result<String, ApiError> {
         // Step 1: Create container
+        let link_param = link_attachment
+            .map(|link| format!("&link_attachment={}", urlencoding::encode(link)))
+            .unwrap_or_default();
         let container_url = format!(
-            "{}/me/threads?media_type=TEXT&text={}&access_token={}",
-            BASE_URL,
+            "{}/me/threads?media_type=TEXT&text={}{}&access_token={}",
+            self.base_url,
             urlencoding::encode(text),
+            link_param,
             self.access_token
         );
 
@@ -361,57 +764,236 @@ impl ThreadsClient {
 
         if !response.status().is_success() {
             let body = response.text().await.unwrap_or_default();
-            return Err(ApiError::Api(format!(
-                "Container creation failed: {}",
-                body
-            )));
+            let context = match link_attachment {
+                Some(link) => format!("Container creation failed (link attachment {})", link),
+                None => "Container creation failed".to_string(),
+            };
+            return Err(publish_error(&context, &body));
         }
 
         let container: ContainerResponse = response.json().await?;
 
         // Step 2: Wait for container to be ready
-        let status = self.wait_for_container(&container.id).await?;
+        let status = self.wait_for_container(&container.id).await.map_err(|e| {
+            match (link_attachment, e) {
+                (Some(link), ApiError::Api(msg)) => {
+                    ApiError::Api(format!("Link {} couldn't be previewed: {}", link, msg))
+                }
+                (_, e) => e,
+            }
+        })?;
         if status != "FINISHED" {
             return Err(ApiError::Api(format!(
                 "Container not ready for publish: {}",
                 status
             )));
         }
+        Ok(container.id)
+    }
+
+    /// Delete one of the user's own threads or replies
+    pub async fn delete_thread(&self, thread_id: &str) -> Result<(), ApiError> {
+        let url = format!(
+            "{}/{}?access_token={}",
+            self.base_url, thread_id, self.access_token
+        );
+
+        let response = self.client.delete(&url).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            if is_not_found(status, &body) {
+                return Err(ApiError::NotFound(thread_id.to_string()));
+            }
+            if let Some(scope) = missing_scope_or(&body, DELETE_SCOPE) {
+                return Err(ApiError::MissingScope(scope));
+            }
+            return Err(response_error(status, body));
+        }
+
+        Ok(())
+    }
+
+    /// Repost someone's thread to the user's profile
+    pub async fn repost_thread(&self, thread_id: &str) -> Result<PublishResponse, ApiError> {
+        let url = format!(
+            "{}/{}/repost?access_token={}",
+            self.base_url, thread_id, self.access_token
+        );
+
+        let response = self.client.post(&url).send().await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            if is_not_found(status, &body) {
+                return Err(ApiError::NotFound(thread_id.to_string()));
+            }
+            return Err(publish_error("Repost failed", &body));
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Post an image carousel with a caption. Threads fetches each image itself,
+    /// so image URLs must be publicly reachable.
+    pub async fn post_carousel(
+        &self,
+        text: &str,
+        images: &[ImageAttachment],
+    ) -> Result<PublishResponse, ApiError> {
+        if !(CAROUSEL_MIN_ITEMS..=CAROUSEL_MAX_ITEMS).contains(&images.len()) {
+            return Err(ApiError::Api(format!(
+                "Carousels need {}-{} images, got {}",
+                CAROUSEL_MIN_ITEMS,
+                CAROUSEL_MAX_ITEMS,
+                images.len()
+            )));
+        }
+
+        // Step 1: Create a container per image
+        let mut children = Vec::with_capacity(images.len());
+        for (i, image) in images.iter().enumerate() {
+            let mut params = vec![
+                ("media_type", "IMAGE"),
+                ("image_url", image.url.as_str()),
+                ("is_carousel_item", "true"),
+            ];
+            if let Some(alt) = &image.alt {
+                params.push(("alt_text", alt.as_str()));
+            }
+            let id = self
+                .create_container(&params)
+                .await
+                .map_err(|e| carousel_item_error(i, &image.url, e))?;
+            children.push(id);
+        }
+
+        // Step 2: Wait for every image to finish processing
+        for (i, (id, image)) in children.iter().zip(images).enumerate() {
+            let status = self
+                .wait_for_container(id)
+                .await
+                .map_err(|e| carousel_item_error(i, &image.url, e))?;
+            if status != "FINISHED" {
+                return Err(carousel_item_error(
+                    i,
+                    &image.url,
+                    ApiError::Api(format!("Container not ready: {}", status)),
+                ));
+            }
+        }
+
+        // Step 3: Create the carousel container referencing the children
+        let children = children.join(",");
+        let carousel_id = self
+            .create_container(&[
+                ("media_type", "CAROUSEL"),
+                ("children", children.as_str()),
+                ("text", text),
+            ])
+            .await?;
+
+        let status = self.wait_for_container(&carousel_id).await?;
+        if status != "FINISHED" {
+            return Err(ApiError::Api(format!(
+                "Carousel not ready for publish: {}",
+                status
+            )));
+        }
 
-        // Step 3: Publish
+        // Step 4: Publish
         let publish_url = format!(
             "{}/me/threads_publish?creation_id={}&access_token={}",
-            BASE_URL, container.id, self.access_token
+            self.base_url, carousel_id, self.access_token
         );
 
         let response = self.client.post(&publish_url).send().await?;
 
         if !response.status().is_success() {
             let body = response.text().await.unwrap_or_default();
-            return Err(ApiError::Api(format!("Publish failed: {}", body)));
+            return Err(publish_error("Publish failed", &body));
         }
 
         Ok(response.json().await?)
     }
+
+    /// Create a media container from query parameters, returning its id
+    async fn create_container(&self, params: &[(&str, &str)]) -> Result<String, ApiError> {
+        let query: Vec<String> = params
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, urlencoding::encode(value)))
+            .collect();
+        let container_url = format!(
+            "{}/me/threads?{}&access_token={}",
+            self.base_url,
+            query.join("&"),
+            self.access_token
+        );
+
+        let response = self.client.post(&container_url).send().await?;
+
+        if !response.status().is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(publish_error("Container creation failed", &body));
+        }
+
+        let container: ContainerResponse = response.json().await?;
+        Ok(container.id)
+    }
+}
+
+/// Threads accepts between 2 and 20 items per carousel
+const CAROUSEL_MIN_ITEMS: usize = 2;
+const CAROUSEL_MAX_ITEMS: usize = 20;
+
+/// Say which carousel image failed, keeping permission errors actionable
+fn carousel_item_error(index: usize, image_url: &str, err: ApiError) -> ApiError {
+    match err {
+        ApiError::MissingScope(_) => err,
+        err => ApiError::Api(format!(
+            "Carousel image {} ({}) failed: {}",
+            index + 1,
+            image_url,
+            err
+        )),
+    }
 }
 
 // Implement the platform abstraction trait for ThreadsClient
+/// Map a Threads profile onto the shared `Profile`
+fn to_profile(profile: UserProfile) -> Profile {
+    Profile {
+        handle: profile.username.unwrap_or(profile.id),
+        display_name: profile.name,
+        bio: profile.threads_biography,
+        // Counts need the insights scope, which ndl doesn't request by default
+        ..Default::default()
+    }
+}
+
 #[async_trait]
 impl SocialClient for ThreadsClient {
     async fn get_posts(&self, limit: Option<u32>) -> Result<Vec<Post>, PlatformError> {
         let response = self.get_threads(limit).await?;
-        Ok(response
-            .data
-            .into_iter()
-            .map(|t| Post {
-                id: t.id,
-                text: t.text,
-                author_handle: t.username,
-                timestamp: t.timestamp,
-                permalink: t.permalink,
-                media_type: t.media_type,
-            })
-            .collect())
+        Ok(response.data.into_iter().map(post_from_thread).collect())
+    }
+
+    async fn get_posts_paginated(
+        &self,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<Post>, Option<String>), PlatformError> {
+        let response = self.get_threads_after(limit, cursor).await?;
+        // The last page still has cursors, but no `next` link
+        let next = response
+            .paging
+            .filter(|paging| paging.next.is_some())
+            .and_then(|paging| paging.cursors)
+            .and_then(|cursors| cursors.after);
+        let posts = response.data.into_iter().map(post_from_thread).collect();
+        Ok((posts, next))
     }
 
     async fn get_post_replies(
@@ -423,17 +1005,97 @@ impl SocialClient for ThreadsClient {
         Ok(convert_reply_threads(replies))
     }
 
-    async fn create_post(&self, text: &str) -> Result<(), PlatformError> {
-        self.post_thread(text).await?;
-        Ok(())
+    async fn get_post(&self, id: &str) -> Result<Post, PlatformError> {
+        Ok(post_from_thread(self.get_thread(id).await?))
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            profiles: true,
+            conversations: true,
+            ..Default::default()
+        }
+    }
+
+    async fn get_profile(&self) -> Result<Profile, PlatformError> {
+        Ok(to_profile(ThreadsClient::get_profile(self).await?))
+    }
+
+    async fn refresh_profile(&self) -> Result<Profile, PlatformError> {
+        Ok(to_profile(ThreadsClient::refresh_profile(self).await?))
+    }
+
+    async fn get_conversation(&self, post_id: &str) -> Result<Vec<Post>, PlatformError> {
+        let root = self.get_post(post_id).await?;
+        let replies = ThreadsClient::get_conversation(self, post_id).await?;
+        Ok(std::iter::once(root)
+            .chain(replies.data.into_iter().map(post_from_thread))
+            .collect())
     }
 
-    async fn reply_to_post(&self, post_id: &str, text: &str) -> Result<(), PlatformError> {
-        self.reply_to_thread(post_id, text).await?;
+    async fn create_post(&self, text: &str) -> Result<String, PlatformError> {
+        Ok(self.post_thread(text, None).await?.id)
+    }
+
+    async fn create_link_post(&self, text: &str, link: &str) -> Result<String, PlatformError> {
+        Ok(self.post_thread(text, Some(link)).await?.id)
+    }
+
+    async fn reply_to_post(&self, post_id: &str, text: &str) -> Result<String, PlatformError> {
+        Ok(self.reply_to_thread(post_id, text).await?.id)
+    }
+
+    async fn create_carousel(
+        &self,
+        text: &str,
+        images: &[ImageAttachment],
+    ) -> Result<String, PlatformError> {
+        Ok(self.post_carousel(text, images).await?.id)
+    }
+
+    async fn delete_post(&self, post_id: &str) -> Result<(), PlatformError> {
+        Ok(self.delete_thread(post_id).await?)
+    }
+
+    async fn repost(&self, post_id: &str) -> Result<(), PlatformError> {
+        self.repost_thread(post_id).await?;
         Ok(())
     }
 }
 
+fn post_from_thread(thread: Thread) -> Post {
+    // Top-level posts name themselves as the root
+    let root_id = thread
+        .root_post
+        .map(|root| root.id)
+        .filter(|root| *root != thread.id);
+    Post {
+        root_id,
+        id: thread.id,
+        text: thread.text,
+        author_handle: thread.username,
+        timestamp: thread.timestamp,
+        permalink: thread.permalink,
+        media_type: thread.media_type,
+        // Exact counts come from the insights endpoint, which needs a scope we
+        // don't request, but `has_replies` can at least rule replies out
+        reply_count: thread.has_replies.filter(|has| !has).map(|_| 0),
+        reply_audience: thread.reply_audience.as_deref().and_then(reply_audience),
+        ..Default::default()
+    }
+}
+
+/// Map a Threads `reply_audience` value to the limit it sets (`None` for everyone)
+fn reply_audience(value: &str) -> Option<ReplyAudience> {
+    match value {
+        "accounts_you_follow" => Some(ReplyAudience::Following),
+        "followers_only" => Some(ReplyAudience::Followers),
+        "mentioned_only" => Some(ReplyAudience::Mentioned),
+        "parent_post_author_only" => Some(ReplyAudience::Author),
+        _ => None,
+    }
+}
+
 // Helper to convert Threads reply threads to platform reply threads
 fn convert_reply_threads(threads: Vec<ReplyThread>) -> Vec<PlatformReplyThread> {
     threads
@@ -446,8 +1108,336 @@ fn convert_reply_threads(threads: Vec<ReplyThread>) -> Vec<PlatformReplyThread>
                 timestamp: rt.thread.timestamp,
                 permalink: rt.thread.permalink,
                 media_type: rt.thread.media_type,
+                reply_audience: rt.thread.reply_audience.as_deref().and_then(reply_audience),
+                ..Default::default()
             },
             replies: convert_reply_threads(rt.replies),
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_missing_scope_named() {
+        let body = r#"{"error":{"message":"This method requires the threads_content_publish permission","code":10}}"#;
+        assert_eq!(
+            missing_scope(body),
+            Some("threads_content_publish".to_string())
+        );
+    }
+
+    #[test]
+    fn test_missing_scope_unnamed_falls_back_to_publish() {
+        let body = r#"{"error":{"message":"Application does not have permission for this action","code":10}}"#;
+        assert_eq!(missing_scope(body), Some(PUBLISH_SCOPE.to_string()));
+    }
+
+    #[test]
+    fn test_missing_scope_unnamed_delete() {
+        let body = r#"{"error":{"message":"Application does not have permission for this action","code":10}}"#;
+        assert_eq!(
+            missing_scope_or(body, DELETE_SCOPE),
+            Some("threads_delete".to_string())
+        );
+    }
+
+    #[test]
+    fn test_missing_scope_ignores_reply_restrictions() {
+        let body = r#"{"error":{"message":"You don't have permission to reply to this thread","code":100}}"#;
+        assert_eq!(missing_scope(body), None);
+        assert!(matches!(
+            publish_error("Cannot reply to this thread", body),
+            ApiError::Api(_)
+        ));
+        assert_eq!(
+            missing_scope("Missing permission: threads_manage_replies"),
+            Some("threads_manage_replies".to_string())
+        );
+    }
+
+    #[test]
+    fn test_missing_scope_other_errors() {
+        let body = r#"{"error":{"message":"Invalid parameter","code":100}}"#;
+        assert_eq!(missing_scope(body), None);
+        assert!(matches!(
+            publish_error("Publish failed", body),
+            ApiError::Api(_)
+        ));
+    }
+
+    #[test]
+    fn test_response_error_classifies_auth() {
+        let expired = r#"{"error":{"message":"Error validating access token: Session has expired","type":"OAuthException","code":190,"error_subcode":463}}"#;
+        let err = response_error(reqwest::StatusCode::BAD_REQUEST, expired.to_string());
+        assert!(matches!(err, ApiError::Unauthorized(_)));
+        assert!(err.is_auth());
+
+        let session = r#"{"error":{"message":"API Session","code":102}}"#;
+        assert!(matches!(
+            response_error(reqwest::StatusCode::BAD_REQUEST, session.to_string()),
+            ApiError::SessionExpired(_)
+        ));
+
+        // Permission errors are OAuthExceptions too, but a new login won't fix them
+        let permission = r#"{"error":{"message":"Requires the threads_basic permission","type":"OAuthException","code":10}}"#;
+        let err = response_error(reqwest::StatusCode::FORBIDDEN, permission.to_string());
+        assert!(matches!(err, ApiError::Api(_)));
+        assert!(!err.is_auth());
+
+        // Unparsed bodies fall back to text matching
+        assert!(ApiError::Api("Invalid access token".to_string()).is_auth());
+        assert!(!ApiError::Api("<html>502 Bad Gateway</html>".to_string()).is_auth());
+        assert!(response_error(reqwest::StatusCode::UNAUTHORIZED, String::new()).is_auth());
+    }
+
+    #[test]
+    fn test_is_not_found() {
+        let deleted = r#"{"error":{"message":"Unsupported get request. Object with ID '123' does not exist","type":"GraphMethodException","code":100,"error_subcode":33}}"#;
+        assert!(is_not_found(reqwest::StatusCode::BAD_REQUEST, deleted));
+        assert!(is_not_found(reqwest::StatusCode::NOT_FOUND, ""));
+        assert!(is_not_found(reqwest::StatusCode::GONE, ""));
+
+        let transient = r#"{"error":{"message":"Please retry","code":2}}"#;
+        assert!(!is_not_found(
+            reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+            transient
+        ));
+    }
+
+    #[test]
+    fn test_reply_audience() {
+        let thread: Thread = serde_json::from_str(
+            r#"{"id":"1","text":"hi","reply_audience":"accounts_you_follow"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            post_from_thread(thread).reply_audience,
+            Some(ReplyAudience::Following)
+        );
+        assert_eq!(reply_audience("everyone"), None);
+        assert_eq!(
+            reply_audience("mentioned_only"),
+            Some(ReplyAudience::Mentioned)
+        );
+    }
+
+    #[test]
+    fn test_root_post() {
+        let reply: Thread =
+            serde_json::from_str(r#"{"id":"2","text":"re","root_post":{"id":"1"}}"#).unwrap();
+        assert_eq!(post_from_thread(reply).root_id.as_deref(), Some("1"));
+
+        let root: Thread =
+            serde_json::from_str(r#"{"id":"1","text":"hi","root_post":{"id":"1"}}"#).unwrap();
+        assert_eq!(post_from_thread(root).root_id, None);
+    }
+
+    #[test]
+    fn test_thread_fields() {
+        assert_eq!(
+            thread_fields(&["text".to_string(), "is_quote_post".to_string()]).unwrap(),
+            "id,text,is_quote_post"
+        );
+        assert_eq!(thread_fields(&["id".to_string()]).unwrap(), "id");
+        assert!(thread_fields(&["text".to_string(), "likes".to_string()]).is_err());
+    }
+
+    /// A client pointed at `router`, served on a local port
+    async fn mock_threads(router: axum::Router) -> ThreadsClient {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await });
+        ThreadsClient::new("token".to_string()).with_base_url(format!("http://{}", addr))
+    }
+
+    #[tokio::test]
+    async fn test_get_posts_is_one_request() {
+        use axum::{Json, Router, extract::Query, routing::get};
+        use std::collections::HashMap;
+
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        let app = Router::new().route(
+            "/me/threads",
+            get(move |Query(params): Query<HashMap<String, String>>| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                assert!(params["fields"].split(',').any(|f| f == "has_replies"));
+                async {
+                    Json(serde_json::json!({
+                        "data": [
+                            {"id": "1", "text": "quiet", "has_replies": false},
+                            {"id": "2", "text": "busy", "has_replies": true},
+                            {"id": "3", "text": "busy too", "has_replies": true},
+                        ]
+                    }))
+                }
+            }),
+        );
+        let client = mock_threads(app).await;
+        let posts = client.get_posts(Some(3)).await.unwrap();
+
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        let counts: Vec<_> = posts.iter().map(|p| p.reply_count).collect();
+        assert_eq!(counts, vec![Some(0), None, None]);
+    }
+
+    #[tokio::test]
+    async fn test_paginated_posts_follow_after_cursor() {
+        use axum::{Json, Router, extract::Query, routing::get};
+        use std::collections::HashMap;
+
+        let app = Router::new().route(
+            "/me/threads",
+            get(|Query(params): Query<HashMap<String, String>>| async move {
+                Json(match params.get("after").map(String::as_str) {
+                    None => serde_json::json!({
+                        "data": [{"id": "1"}, {"id": "2"}],
+                        "paging": {"cursors": {"after": "c2"}, "next": "https://next"}
+                    }),
+                    // The last page has cursors but no `next`
+                    Some("c2") => serde_json::json!({
+                        "data": [{"id": "3"}],
+                        "paging": {"cursors": {"after": "c3"}}
+                    }),
+                    Some(other) => panic!("unexpected cursor {}", other),
+                })
+            }),
+        );
+        let client = mock_threads(app).await;
+        let (posts, next) = client.get_posts_paginated(Some(2), None).await.unwrap();
+        assert_eq!(posts.len(), 2);
+        assert_eq!(next.as_deref(), Some("c2"));
+
+        let (posts, next) = client
+            .get_posts_paginated(Some(2), next.as_deref())
+            .await
+            .unwrap();
+        assert_eq!(posts[0].id, "3");
+        assert_eq!(next, None);
+    }
+
+    /// Mock that numbers the containers it creates, answers status checks
+    /// with `statuses` in turn and then FINISHED, and fails the first publish
+    /// with `first_publish`. Returns the client and the container and publish
+    /// counters.
+    async fn publish_mock(
+        first_publish: axum::http::StatusCode,
+        statuses: &'static [&'static str],
+    ) -> (ThreadsClient, Arc<AtomicUsize>, Arc<AtomicUsize>) {
+        use axum::{
+            Json, Router,
+            response::IntoResponse,
+            routing::{get, post},
+        };
+
+        let containers = Arc::new(AtomicUsize::new(0));
+        let publishes = Arc::new(AtomicUsize::new(0));
+        let checks = Arc::new(AtomicUsize::new(0));
+        let (created, published) = (containers.clone(), publishes.clone());
+        let app = Router::new()
+            .route(
+                "/me/threads",
+                post(move || {
+                    let n = created.fetch_add(1, Ordering::SeqCst);
+                    async move { Json(serde_json::json!({"id": format!("c{}", n)})) }
+                }),
+            )
+            .route(
+                "/{id}",
+                get(move || {
+                    let n = checks.fetch_add(1, Ordering::SeqCst);
+                    let status = statuses.get(n).copied().unwrap_or("FINISHED");
+                    async move { Json(serde_json::json!({"status": status})) }
+                }),
+            )
+            .route(
+                "/me/threads_publish",
+                post(move || {
+                    let attempt = published.fetch_add(1, Ordering::SeqCst);
+                    async move {
+                        if attempt == 0 {
+                            let body = r#"{"error":{"message":"Please retry","code":2}}"#;
+                            (first_publish, body).into_response()
+                        } else {
+                            Json(serde_json::json!({"id": "p1"})).into_response()
+                        }
+                    }
+                }),
+            );
+        (mock_threads(app).await, containers, publishes)
+    }
+
+    #[tokio::test]
+    async fn test_retried_post_reuses_container() {
+        let (client, containers, publishes) =
+            publish_mock(axum::http::StatusCode::INTERNAL_SERVER_ERROR, &[]).await;
+        assert!(client.post_thread("hello", None).await.is_err());
+        let published = client.post_thread("hello", None).await.unwrap();
+
+        assert_eq!(published.id, "p1");
+        assert_eq!(containers.load(Ordering::SeqCst), 1);
+        assert_eq!(publishes.load(Ordering::SeqCst), 2);
+
+        // A different post gets its own container
+        client.post_thread("another", None).await.unwrap();
+        assert_eq!(containers.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retried_reply_reuses_container() {
+        let (client, containers, _) =
+            publish_mock(axum::http::StatusCode::SERVICE_UNAVAILABLE, &[]).await;
+        assert!(client.reply_to_thread("42", "hi").await.is_err());
+        client.reply_to_thread("42", "hi").await.unwrap();
+        assert_eq!(containers.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_permanent_publish_failure_drops_container() {
+        let (client, containers, _) = publish_mock(axum::http::StatusCode::BAD_REQUEST, &[]).await;
+        assert!(client.post_thread("hello", None).await.is_err());
+        client.post_thread("hello", None).await.unwrap();
+        assert_eq!(containers.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_expired_container_not_reused() {
+        // Ready when created, expired by the time the post is sent again
+        let (client, containers, _) = publish_mock(
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            &["FINISHED", "EXPIRED"],
+        )
+        .await;
+        assert!(client.post_thread("hello", None).await.is_err());
+        client.post_thread("hello", None).await.unwrap();
+        assert_eq!(containers.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_repost_posts_to_thread() {
+        use axum::{Json, Router, extract::Path, http::StatusCode, response::IntoResponse};
+
+        let app = Router::new().route(
+            "/{id}/repost",
+            axum::routing::post(|Path(id): Path<String>| async move {
+                if id == "gone" {
+                    let body =
+                        r#"{"error":{"message":"Unsupported post request","error_subcode":33}}"#;
+                    (StatusCode::BAD_REQUEST, body).into_response()
+                } else {
+                    Json(serde_json::json!({"id": format!("r{}", id)})).into_response()
+                }
+            }),
+        );
+        let client = mock_threads(app).await;
+        assert_eq!(client.repost_thread("42").await.unwrap().id, "r42");
+        assert!(matches!(
+            client.repost("gone").await,
+            Err(PlatformError::NotFound(_))
+        ));
+    }
+}
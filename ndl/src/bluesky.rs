@@ -1,25 +1,163 @@
+pub mod jetstream;
+pub mod uri;
+
 use async_trait::async_trait;
-use atrium_api::app::bsky::feed::defs::{ThreadViewPostData, ThreadViewPostRepliesItem};
-use atrium_api::app::bsky::feed::get_post_thread::OutputThreadRefs;
+use atrium_api::app::bsky::actor::get_profile;
+use atrium_api::app::bsky::feed::defs::{
+    PostView, ThreadViewPostData, ThreadViewPostParentRefs, ThreadViewPostRepliesItem,
+};
+use atrium_api::app::bsky::feed::get_post_thread::{self, OutputThreadRefs};
 use atrium_api::app::bsky::feed::post::{RecordData, ReplyRefData};
+use atrium_api::app::bsky::feed::{get_posts, like, repost};
+use atrium_api::app::bsky::graph::{block, follow, mute_actor};
 use atrium_api::com::atproto::repo::strong_ref::MainData as StrongRef;
 use atrium_api::types::Union;
-use atrium_api::types::string::Datetime;
+use atrium_api::types::string::{AtIdentifier, Datetime};
+use atrium_api::xrpc;
+use atrium_xrpc_client::reqwest::{ReqwestClient, ReqwestClientBuilder};
 use bsky_sdk::BskyAgent;
+use futures::stream::BoxStream;
+use serde_json::json;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-use crate::platform::{PlatformError, Post, ReplyThread, SocialClient};
+use self::uri::{POST_COLLECTION, did_of, parse_at_uri};
+use crate::platform::{
+    Capabilities, Facet, FacetKind, ImageAttachment, PROFILE_TTL, PlatformError, Post, PostKind,
+    Profile, ReplyAudience, ReplyThread, SocialClient, ThreadError, TtlCache,
+};
+
+/// Default PDS endpoint; the agent switches to the session's PDS after login
+const BSKY_ENDPOINT: &str = "https://bsky.social";
+
+/// Bluesky allows up to four images per post
+const MAX_IMAGES: usize = 4;
+
+/// Public AppView, for handle lookups before there's a session
+const PUBLIC_API: &str = "https://public.api.bsky.app";
+
+/// Largest page the AppView returns for feed queries
+const MAX_PAGE_SIZE: u32 = 100;
+
+/// Reply levels loaded for the conversation view
+const CONVERSATION_DEPTH: u8 = 10;
+
+/// Ancestors loaded for the conversation view (the AppView default)
+const CONVERSATION_PARENT_HEIGHT: u16 = 80;
+
+/// Feed size when the caller doesn't ask for one (matches the AppView default)
+const DEFAULT_FEED_LIMIT: u32 = 50;
+
+/// A login identifier, classified before asking for a password
+#[derive(Debug, PartialEq, Eq)]
+pub enum Identifier {
+    Handle(String),
+    Email(String),
+    Did(String),
+}
+
+impl Identifier {
+    /// The identifier as sent to `createSession`
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Handle(s) | Self::Email(s) | Self::Did(s) => s,
+        }
+    }
+}
+
+/// Whether `domain` is a DNS name with at least two labels, as handles are
+fn is_domain(domain: &str) -> bool {
+    let labels: Vec<&str> = domain.split('.').collect();
+    labels.len() >= 2
+        && labels.iter().all(|label| {
+            (1..=63).contains(&label.len())
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+        })
+        && !labels
+            .last()
+            .is_some_and(|tld| tld.starts_with(|c: char| c.is_ascii_digit()))
+}
+
+/// Classify a login identifier, or `None` if it can't be a handle, email, or
+/// DID. A leading `@` on a handle is dropped.
+pub fn parse_identifier(input: &str) -> Option<Identifier> {
+    let input = input.trim();
+    if input.starts_with("did:") {
+        return (input.len() > "did:".len() && !input.contains(char::is_whitespace))
+            .then(|| Identifier::Did(input.to_string()));
+    }
+
+    let handle = input.strip_prefix('@').unwrap_or(input);
+    if is_domain(handle) {
+        return Some(Identifier::Handle(handle.to_lowercase()));
+    }
+
+    match input.split_once('@') {
+        Some((local, domain))
+            if !local.is_empty() && !local.contains(char::is_whitespace) && is_domain(domain) =>
+        {
+            Some(Identifier::Email(input.to_string()))
+        }
+        _ => None,
+    }
+}
+
+/// Whether a password has the `xxxx-xxxx-xxxx-xxxx` shape of a Bluesky app
+/// password, as opposed to the main account password
+pub fn is_app_password(password: &str) -> bool {
+    let groups: Vec<&str> = password.split('-').collect();
+    groups.len() == 4
+        && groups.iter().all(|group| {
+            group.len() == 4
+                && group
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        })
+}
+
+/// Check that a handle belongs to an account, via the public AppView
+pub async fn handle_exists(handle: &str) -> Result<bool, PlatformError> {
+    let client = ndl_core::http_client().map_err(|e| PlatformError::Request(e.to_string()))?;
+    let response = client
+        .get(format!(
+            "{}/xrpc/com.atproto.identity.resolveHandle",
+            PUBLIC_API
+        ))
+        .query(&[("handle", handle)])
+        .send()
+        .await?;
+
+    match response.status() {
+        status if status.is_success() => Ok(true),
+        // "Unable to resolve handle"
+        reqwest::StatusCode::BAD_REQUEST => Ok(false),
+        status => Err(PlatformError::Api(format!(
+            "Handle lookup failed with status {}",
+            status
+        ))),
+    }
+}
 
 #[derive(Clone)]
 pub struct BlueskyClient {
     agent: Arc<RwLock<BskyAgent>>,
+    /// Follow record URIs created this session, keyed by subject DID.
+    /// The AppView can lag behind, so unfollow checks here before the profile.
+    follows: Arc<RwLock<HashMap<String, String>>>,
+    /// Like record URIs created this session, keyed by post URI, for the same reason
+    likes: Arc<RwLock<HashMap<String, String>>>,
+    /// The user's own profile, reused for `PROFILE_TTL`
+    profile: TtlCache<get_profile::Output>,
 }
 
 impl BlueskyClient {
     /// Create a new Bluesky client and login
     pub async fn login(identifier: &str, password: &str) -> Result<Self, PlatformError> {
         let agent = BskyAgent::builder()
+            .client(xrpc_client()?)
             .build()
             .await
             .map_err(|e| PlatformError::Auth(format!("Failed to create agent: {}", e)))?;
@@ -31,6 +169,9 @@ impl BlueskyClient {
 
         Ok(Self {
             agent: Arc::new(RwLock::new(agent)),
+            follows: Arc::default(),
+            likes: Arc::default(),
+            profile: TtlCache::new(PROFILE_TTL),
         })
     }
 
@@ -45,6 +186,7 @@ impl BlueskyClient {
         // Create agent from config
         let agent = BskyAgent::builder()
             .config(config)
+            .client(xrpc_client()?)
             .build()
             .await
             .map_err(|e| {
@@ -53,9 +195,26 @@ impl BlueskyClient {
 
         Ok(Self {
             agent: Arc::new(RwLock::new(agent)),
+            follows: Arc::default(),
+            likes: Arc::default(),
+            profile: TtlCache::new(PROFILE_TTL),
         })
     }
 
+    /// Check the session with a cheap `getSession` call before fetching the
+    /// feed. The agent refreshes an expired access token along the way, so
+    /// `false` means the refresh token is gone too and a fresh login is needed.
+    pub async fn is_session_valid(&self) -> bool {
+        let agent = self.agent.read().await;
+        match agent.api.com.atproto.server.get_session().await {
+            Ok(_) => true,
+            Err(e) => {
+                tracing::debug!("Bluesky session check failed: {}", e);
+                false
+            }
+        }
+    }
+
     /// Get the session data for persistence
     pub async fn get_session(&self) -> Result<String, PlatformError> {
         let agent = self.agent.read().await;
@@ -68,6 +227,154 @@ impl BlueskyClient {
             .map_err(|e| PlatformError::Api(format!("Failed to serialize session: {}", e)))
     }
 
+    /// Load a post's thread view, `depth` levels of replies deep and, when
+    /// given, `parent_height` ancestors up
+    async fn fetch_thread(
+        &self,
+        post_id: &str,
+        depth: u8,
+        parent_height: Option<u16>,
+    ) -> Result<get_post_thread::Output, PlatformError> {
+        let agent = self.agent.read().await;
+
+        // post_id is the AT URI (e.g., at://did:plc:.../app.bsky.feed.post/...)
+        agent
+            .api
+            .app
+            .bsky
+            .feed
+            .get_post_thread(
+                atrium_api::app::bsky::feed::get_post_thread::ParametersData {
+                    uri: post_id.to_string(),
+                    depth: Some(
+                        atrium_api::types::LimitedU16::try_from(depth as u16)
+                            .unwrap_or(atrium_api::types::LimitedU16::MAX),
+                    ),
+                    parent_height: parent_height.and_then(|h| {
+                        atrium_api::types::LimitedU16::try_from(h).ok()
+                    }),
+                }
+                .into(),
+            )
+            .await
+            .map_err(|e| match e {
+                // The AppView answers NotFound for deleted posts
+                xrpc::Error::XrpcResponse(xrpc::error::XrpcError {
+                    error:
+                        Some(xrpc::error::XrpcErrorKind::Custom(get_post_thread::Error::NotFound(_))),
+                    ..
+                }) => PlatformError::NotFound(post_id.to_string()),
+                e => xrpc_error("Failed to get thread", e),
+            })
+    }
+
+    /// Swap a handle authority in a post's AT URI (as built from a bsky.app
+    /// link) for the DID it resolves to
+    async fn resolve_post_uri(&self, uri: &str) -> Result<String, PlatformError> {
+        let Some(parsed) = parse_at_uri(uri) else {
+            return Ok(uri.to_string());
+        };
+        if did_of(uri).is_some() {
+            return Ok(uri.to_string());
+        }
+        let actor = parsed.authority;
+
+        let handle = actor
+            .parse()
+            .map_err(|e| PlatformError::Api(format!("Invalid handle '{}': {}", actor, e)))?;
+        let agent = self.agent.read().await;
+        let resolved = agent
+            .api
+            .com
+            .atproto
+            .identity
+            .resolve_handle(
+                atrium_api::com::atproto::identity::resolve_handle::ParametersData { handle }
+                    .into(),
+            )
+            .await
+            .map_err(|e| PlatformError::Api(format!("Failed to resolve {}: {}", actor, e)))?;
+
+        Ok(format!(
+            "at://{}/{}/{}",
+            resolved.data.did.as_str(),
+            parsed.collection,
+            parsed.rkey
+        ))
+    }
+
+    /// Look up a profile by DID or handle
+    async fn fetch_profile(&self, actor: &str) -> Result<get_profile::Output, PlatformError> {
+        let actor: AtIdentifier = actor
+            .parse()
+            .map_err(|e| PlatformError::Api(format!("Invalid actor '{}': {}", actor, e)))?;
+
+        let agent = self.agent.read().await;
+
+        agent
+            .api
+            .app
+            .bsky
+            .actor
+            .get_profile(get_profile::ParametersData { actor }.into())
+            .await
+            .map_err(|e| PlatformError::Api(format!("Failed to get profile: {}", e)))
+    }
+
+    /// Get the logged-in user's profile (cached for a few minutes)
+    pub async fn get_profile(&self) -> Result<get_profile::Output, PlatformError> {
+        self.profile
+            .get_or_fetch(|| async {
+                let did = self
+                    .agent
+                    .read()
+                    .await
+                    .get_session()
+                    .await
+                    .ok_or_else(|| PlatformError::Auth("No active session".to_string()))?
+                    .did
+                    .clone();
+                self.fetch_profile(did.as_str()).await
+            })
+            .await
+    }
+
+    /// Drop the cached profile and fetch it again
+    pub async fn refresh_profile(&self) -> Result<get_profile::Output, PlatformError> {
+        self.profile.clear().await;
+        self.get_profile().await
+    }
+
+    /// Create a post record (optionally as a reply) and return a strong ref to it
+    async fn create_post_record(
+        &self,
+        text: &str,
+        reply: Option<ReplyRefData>,
+    ) -> Result<StrongRef, PlatformError> {
+        let what = if reply.is_some() { "reply" } else { "post" };
+        let agent = self.agent.read().await;
+
+        let output = agent
+            .create_record(RecordData {
+                created_at: Datetime::now(),
+                embed: None,
+                entities: None,
+                facets: None,
+                labels: None,
+                langs: None,
+                reply: reply.map(Into::into),
+                tags: None,
+                text: text.to_string(),
+            })
+            .await
+            .map_err(|e| PlatformError::Api(format!("Failed to create {}: {}", what, e)))?;
+
+        Ok(StrongRef {
+            cid: output.cid.clone(),
+            uri: output.uri.clone(),
+        })
+    }
+
     /// Extract replies from a thread view post
     fn extract_replies(&self, thread_view: &ThreadViewPostData) -> Vec<ReplyThread> {
         let Some(replies) = &thread_view.replies else {
@@ -80,29 +387,28 @@ impl BlueskyClient {
             .collect()
     }
 
+    /// Ancestors of a thread view, oldest first
+    fn collect_parents(thread_view: &ThreadViewPostData, out: &mut Vec<Post>) {
+        match &thread_view.parent {
+            Some(Union::Refs(ThreadViewPostParentRefs::ThreadViewPost(parent))) => {
+                Self::collect_parents(&parent.data, out);
+                out.push(post_from_view(&parent.post));
+            }
+            Some(Union::Refs(ThreadViewPostParentRefs::BlockedPost(blocked))) => {
+                out.push(Post::unavailable(blocked.uri.clone(), PostKind::Blocked));
+            }
+            Some(Union::Refs(ThreadViewPostParentRefs::NotFoundPost(not_found))) => {
+                out.push(Post::unavailable(not_found.uri.clone(), PostKind::NotFound));
+            }
+            Some(Union::Unknown(_)) | None => {}
+        }
+    }
+
     /// Convert a reply item (Union<ThreadViewPostRepliesItem>) to a ReplyThread
     fn convert_reply_item(&self, item: &Union<ThreadViewPostRepliesItem>) -> Option<ReplyThread> {
         match item {
             Union::Refs(ThreadViewPostRepliesItem::ThreadViewPost(thread_post)) => {
-                let post_view = &thread_post.data.post;
-
-                // Extract text from the record
-                let text = serde_json::to_value(&post_view.record)
-                    .ok()
-                    .and_then(|v| v.get("text").and_then(|t| t.as_str()).map(String::from));
-
-                let post = Post {
-                    id: post_view.uri.to_string(),
-                    text,
-                    author_handle: Some(post_view.author.handle.as_str().to_string()),
-                    timestamp: Some(post_view.indexed_at.as_ref().to_string()),
-                    permalink: Some(format!(
-                        "https://bsky.app/profile/{}/post/{}",
-                        post_view.author.handle.as_str(),
-                        post_view.uri.split('/').next_back().unwrap_or("")
-                    )),
-                    media_type: None,
-                };
+                let post = post_from_view(&thread_post.data.post);
 
                 // Recursively extract nested replies
                 let nested_replies = self.extract_replies(&thread_post.data);
@@ -112,12 +418,44 @@ impl BlueskyClient {
                     replies: nested_replies,
                 })
             }
-            Union::Refs(ThreadViewPostRepliesItem::BlockedPost(_)) => None,
-            Union::Refs(ThreadViewPostRepliesItem::NotFoundPost(_)) => None,
+            // Keep placeholders so the tree structure shows why a branch is empty
+            Union::Refs(ThreadViewPostRepliesItem::BlockedPost(blocked)) => Some(ReplyThread {
+                post: Post::unavailable(blocked.uri.clone(), PostKind::Blocked),
+                replies: Vec::new(),
+            }),
+            Union::Refs(ThreadViewPostRepliesItem::NotFoundPost(not_found)) => Some(ReplyThread {
+                post: Post::unavailable(not_found.uri.clone(), PostKind::NotFound),
+                replies: Vec::new(),
+            }),
             Union::Unknown(_) => None,
         }
     }
 
+    /// Fetch one post's view, which carries its CID and the user's like/repost records
+    async fn fetch_post_view(&self, uri: &str) -> Result<PostView, PlatformError> {
+        let agent = self.agent.read().await;
+        let output = agent
+            .api
+            .app
+            .bsky
+            .feed
+            .get_posts(
+                get_posts::ParametersData {
+                    uris: vec![uri.to_string()],
+                }
+                .into(),
+            )
+            .await
+            .map_err(|e| xrpc_error("Failed to get post", e))?;
+
+        output
+            .data
+            .posts
+            .into_iter()
+            .next()
+            .ok_or_else(|| PlatformError::NotFound(uri.to_string()))
+    }
+
     /// Get the CID and root info for a post by fetching the thread
     /// Returns (cid, Option<(root_uri, root_cid)>)
     async fn get_post_info(
@@ -165,9 +503,195 @@ impl BlueskyClient {
     }
 }
 
+/// Convert a Bluesky post view into a platform post
+fn post_from_view(post_view: &PostView) -> Post {
+    // The record is Unknown type, we need to serialize it to JSON and extract fields
+    let record = serde_json::to_value(&post_view.record).ok();
+    let text = record
+        .as_ref()
+        .and_then(|v| v.get("text").and_then(|t| t.as_str()).map(String::from));
+    // Replies reference their thread root in the record's reply.root
+    let root_id = record.as_ref().and_then(|v| {
+        v.get("reply")?
+            .get("root")?
+            .get("uri")?
+            .as_str()
+            .map(String::from)
+    });
+
+    let facets = match (&record, &text) {
+        (Some(record), Some(text)) => facets_from_record(record, text),
+        _ => Vec::new(),
+    };
+
+    Post {
+        id: post_view.uri.to_string(),
+        text,
+        author_handle: Some(post_view.author.handle.as_str().to_string()),
+        author_did: Some(post_view.author.did.as_str().to_string()),
+        author_name: post_view
+            .author
+            .display_name
+            .clone()
+            .filter(|n| !n.is_empty()),
+        timestamp: Some(post_view.indexed_at.as_ref().to_string()),
+        permalink: uri::to_web_url(post_view.author.handle.as_str(), &post_view.uri),
+        media_type: None,
+        like_count: count(post_view.like_count),
+        repost_count: count(post_view.repost_count),
+        reply_count: count(post_view.reply_count),
+        root_id,
+        kind: PostKind::Normal,
+        facets,
+        reply_audience: post_view
+            .threadgate
+            .as_ref()
+            .and_then(|gate| serde_json::to_value(&gate.record).ok())
+            .and_then(|record| threadgate_audience(&record)),
+        reply_disabled: post_view
+            .viewer
+            .as_ref()
+            .and_then(|viewer| viewer.reply_disabled)
+            .unwrap_or(false),
+        liked: post_view
+            .viewer
+            .as_ref()
+            .is_some_and(|viewer| viewer.like.is_some()),
+    }
+}
+
+/// The reply limit a threadgate record sets. No `allow` means anyone may
+/// reply and an empty one means nobody; with several rules the first is shown.
+fn threadgate_audience(record: &serde_json::Value) -> Option<ReplyAudience> {
+    let allow = record.get("allow")?.as_array()?;
+    let Some(rule) = allow.first() else {
+        return Some(ReplyAudience::Nobody);
+    };
+    match rule.get("$type")?.as_str()? {
+        "app.bsky.feed.threadgate#mentionRule" => Some(ReplyAudience::Mentioned),
+        "app.bsky.feed.threadgate#followingRule" => Some(ReplyAudience::Following),
+        "app.bsky.feed.threadgate#followerRule" => Some(ReplyAudience::Followers),
+        "app.bsky.feed.threadgate#listRule" => Some(ReplyAudience::List),
+        _ => None,
+    }
+}
+
+/// Link and mention ranges from a post record's `facets`, skipping any that
+/// don't fall on character boundaries of the text
+fn facets_from_record(record: &serde_json::Value, text: &str) -> Vec<Facet> {
+    let Some(facets) = record.get("facets").and_then(|f| f.as_array()) else {
+        return Vec::new();
+    };
+
+    facets
+        .iter()
+        .filter_map(|facet| {
+            let index = facet.get("index")?;
+            let start = index.get("byteStart")?.as_u64()? as usize;
+            let end = index.get("byteEnd")?.as_u64()? as usize;
+            let kind =
+                facet
+                    .get("features")?
+                    .as_array()?
+                    .iter()
+                    .find_map(|feature| match feature.get("$type")?.as_str()? {
+                        "app.bsky.richtext.facet#link" => Some(FacetKind::Link),
+                        "app.bsky.richtext.facet#mention" => Some(FacetKind::Mention),
+                        _ => None,
+                    })?;
+            (start < end && text.get(start..end).is_some()).then_some(Facet { start, end, kind })
+        })
+        .collect()
+}
+
+/// XRPC client that shares ndl's proxy and root-certificate settings
+fn xrpc_client() -> Result<ReqwestClient, PlatformError> {
+    let client = ndl_core::http_client().map_err(|e| PlatformError::Request(e.to_string()))?;
+    Ok(ReqwestClientBuilder::new(BSKY_ENDPOINT)
+        .client(client)
+        .build())
+}
+
+/// Map an XRPC failure, keeping transport errors (no connection, timeouts)
+/// apart from errors the server answered with
+fn xrpc_error<E>(context: &str, e: xrpc::Error<E>) -> PlatformError
+where
+    E: std::fmt::Debug,
+    xrpc::Error<E>: std::fmt::Display,
+{
+    match e {
+        xrpc::Error::HttpClient(e) => PlatformError::Request(format!("{}: {}", context, e)),
+        e => PlatformError::Api(format!("{}: {}", context, e)),
+    }
+}
+
+/// Build a typed record from JSON so optional lexicon fields can be left out
+fn record_from_json<T: serde::de::DeserializeOwned>(
+    value: serde_json::Value,
+) -> Result<T, PlatformError> {
+    serde_json::from_value(value).map_err(|e| PlatformError::Api(format!("Invalid record: {}", e)))
+}
+
+/// Collect up to `limit` items from a cursor-paginated endpoint, starting at
+/// `start`, requesting at most `MAX_PAGE_SIZE` per page and stopping early
+/// when the cursor runs out. Returns the cursor to continue from, if any.
+async fn paginate<T, F, Fut>(
+    limit: u32,
+    start: Option<String>,
+    mut fetch_page: F,
+) -> Result<(Vec<T>, Option<String>), PlatformError>
+where
+    F: FnMut(Option<String>, u8) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, Option<String>), PlatformError>>,
+{
+    let mut items = Vec::new();
+    let mut cursor = start;
+
+    while (items.len() as u32) < limit {
+        let page_size = (limit - items.len() as u32).min(MAX_PAGE_SIZE) as u8;
+        let (page, next) = fetch_page(cursor.take(), page_size).await?;
+        let exhausted = page.is_empty();
+        items.extend(page);
+
+        match next {
+            Some(next) if !exhausted => cursor = Some(next),
+            _ => break,
+        }
+    }
+
+    items.truncate(limit as usize);
+    Ok((items, cursor))
+}
+
+/// Convert an optional AT Protocol count to an unsigned count
+fn count(value: Option<i64>) -> Option<u64> {
+    value.and_then(|c| u64::try_from(c).ok())
+}
+
+/// Map a Bluesky profile onto the shared `Profile`
+fn to_profile(profile: &get_profile::Output) -> Profile {
+    let count = |n: Option<i64>| n.and_then(|n| u64::try_from(n).ok());
+    Profile {
+        handle: profile.handle.as_str().to_string(),
+        display_name: profile.display_name.clone(),
+        bio: profile.description.clone(),
+        followers: count(profile.followers_count),
+        following: count(profile.follows_count),
+        posts: count(profile.posts_count),
+    }
+}
+
 #[async_trait]
 impl SocialClient for BlueskyClient {
     async fn get_posts(&self, limit: Option<u32>) -> Result<Vec<Post>, PlatformError> {
+        Ok(self.get_posts_paginated(limit, None).await?.0)
+    }
+
+    async fn get_posts_paginated(
+        &self,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<Post>, Option<String>), PlatformError> {
         let agent = self.agent.read().await;
 
         // Get the user's DID to fetch their own posts (like Threads /me/threads)
@@ -176,56 +700,47 @@ impl SocialClient for BlueskyClient {
             .await
             .ok_or_else(|| PlatformError::Auth("No active session".to_string()))?;
         let did = session.did.clone();
+        let agent = &*agent;
 
-        // Convert limit to the proper type (LimitedNonZeroU8, max 100)
-        let limit = limit
-            .map(|l| l.min(100) as u8)
-            .and_then(|l| atrium_api::types::LimitedNonZeroU8::try_from(l).ok());
-
-        // Use get_author_feed to get the user's own posts (not timeline)
-        let feed = agent
-            .api
-            .app
-            .bsky
-            .feed
-            .get_author_feed(
-                atrium_api::app::bsky::feed::get_author_feed::ParametersData {
-                    actor: did.into(),
-                    cursor: None,
-                    filter: Some("posts_no_replies".to_string()),
-                    include_pins: None,
-                    limit,
-                }
-                .into(),
-            )
-            .await
-            .map_err(|e| PlatformError::Api(format!("Failed to get posts: {}", e)))?;
+        // Use get_author_feed to get the user's own posts (not timeline),
+        // following the cursor when more than one page is requested
+        let start = cursor.map(str::to_string);
+        paginate(
+            limit.unwrap_or(DEFAULT_FEED_LIMIT),
+            start,
+            |cursor, page_size| {
+                let actor = did.clone().into();
+                async move {
+                    let feed = agent
+                        .api
+                        .app
+                        .bsky
+                        .feed
+                        .get_author_feed(
+                            atrium_api::app::bsky::feed::get_author_feed::ParametersData {
+                                actor,
+                                cursor,
+                                filter: Some("posts_no_replies".to_string()),
+                                include_pins: None,
+                                limit: atrium_api::types::LimitedNonZeroU8::try_from(page_size)
+                                    .ok(),
+                            }
+                            .into(),
+                        )
+                        .await
+                        .map_err(|e| xrpc_error("Failed to get posts", e))?;
 
-        Ok(feed
-            .data
-            .feed
-            .iter()
-            .map(|feed_view| {
-                // Extract text from the record
-                // The record is Unknown type, we need to serialize it to JSON and extract text
-                let text = serde_json::to_value(&feed_view.post.record)
-                    .ok()
-                    .and_then(|v| v.get("text").and_then(|t| t.as_str()).map(String::from));
-
-                Post {
-                    id: feed_view.post.uri.to_string(),
-                    text,
-                    author_handle: Some(feed_view.post.author.handle.as_str().to_string()),
-                    timestamp: Some(feed_view.post.indexed_at.as_ref().to_string()),
-                    permalink: Some(format!(
-                        "https://bsky.app/profile/{}/post/{}",
-                        feed_view.post.author.handle.as_str(),
-                        feed_view.post.uri.split('/').next_back().unwrap_or("")
-                    )),
-                    media_type: None,
+                    let posts = feed
+                        .data
+                        .feed
+                        .iter()
+                        .map(|feed_view| post_from_view(&feed_view.post))
+                        .collect();
+                    Ok((posts, feed.data.cursor))
                 }
-            })
-            .collect())
+            },
+        )
+        .await
     }
 
     async fn get_post_replies(
@@ -233,27 +748,7 @@ impl SocialClient for BlueskyClient {
         post_id: &str,
         depth: u8,
     ) -> Result<Vec<ReplyThread>, PlatformError> {
-        let agent = self.agent.read().await;
-
-        // post_id is the AT URI (e.g., at://did:plc:.../app.bsky.feed.post/...)
-        let thread = agent
-            .api
-            .app
-            .bsky
-            .feed
-            .get_post_thread(
-                atrium_api::app::bsky::feed::get_post_thread::ParametersData {
-                    uri: post_id.to_string(),
-                    depth: Some(
-                        atrium_api::types::LimitedU16::try_from(depth as u16)
-                            .unwrap_or(atrium_api::types::LimitedU16::MAX),
-                    ),
-                    parent_height: None,
-                }
-                .into(),
-            )
-            .await
-            .map_err(|e| PlatformError::Api(format!("Failed to get thread: {}", e)))?;
+        let thread = self.fetch_thread(post_id, depth, None).await?;
 
         // Extract replies from the thread
         match &thread.data.thread {
@@ -265,35 +760,100 @@ impl SocialClient for BlueskyClient {
                 Ok(Vec::new())
             }
             Union::Refs(OutputThreadRefs::AppBskyFeedDefsNotFoundPost(_)) => {
-                // Post not found, return empty
-                Ok(Vec::new())
+                Err(PlatformError::NotFound(post_id.to_string()))
             }
             Union::Unknown(_) => Ok(Vec::new()),
         }
     }
 
-    async fn create_post(&self, text: &str) -> Result<(), PlatformError> {
-        let agent = self.agent.read().await;
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            profiles: true,
+            follows: true,
+            moderation: true,
+            author_dids: true,
+            conversations: true,
+            likes: true,
+        }
+    }
 
-        agent
-            .create_record(RecordData {
-                created_at: Datetime::now(),
-                embed: None,
-                entities: None,
-                facets: None,
-                labels: None,
-                langs: None,
-                reply: None,
-                tags: None,
-                text: text.to_string(),
-            })
+    async fn get_profile(&self) -> Result<Profile, PlatformError> {
+        Ok(to_profile(&BlueskyClient::get_profile(self).await?))
+    }
+
+    async fn refresh_profile(&self) -> Result<Profile, PlatformError> {
+        Ok(to_profile(&BlueskyClient::refresh_profile(self).await?))
+    }
+
+    async fn subscribe(&self) -> Result<BoxStream<'static, Post>, PlatformError> {
+        let session = self
+            .agent
+            .read()
             .await
-            .map_err(|e| PlatformError::Api(format!("Failed to create post: {}", e)))?;
+            .get_session()
+            .await
+            .ok_or_else(|| PlatformError::Auth("No active session".to_string()))?;
+        jetstream::subscribe(session.did.as_str(), session.handle.as_str()).await
+    }
 
-        Ok(())
+    async fn get_post(&self, id: &str) -> Result<Post, PlatformError> {
+        let uri = self.resolve_post_uri(id).await?;
+        let thread = self.fetch_thread(&uri, 0, None).await?;
+
+        match &thread.data.thread {
+            Union::Refs(OutputThreadRefs::AppBskyFeedDefsThreadViewPost(thread_view)) => {
+                Ok(post_from_view(&thread_view.post))
+            }
+            Union::Refs(OutputThreadRefs::AppBskyFeedDefsBlockedPost(_)) => {
+                Ok(Post::unavailable(id.to_string(), PostKind::Blocked))
+            }
+            Union::Refs(OutputThreadRefs::AppBskyFeedDefsNotFoundPost(_)) => {
+                Err(PlatformError::NotFound(id.to_string()))
+            }
+            Union::Unknown(_) => Err(PlatformError::Api(format!(
+                "Unrecognized thread view for {}",
+                id
+            ))),
+        }
+    }
+
+    async fn get_conversation(&self, post_id: &str) -> Result<Vec<Post>, PlatformError> {
+        let thread = self
+            .fetch_thread(
+                post_id,
+                CONVERSATION_DEPTH,
+                Some(CONVERSATION_PARENT_HEIGHT),
+            )
+            .await?;
+
+        match &thread.data.thread {
+            Union::Refs(OutputThreadRefs::AppBskyFeedDefsThreadViewPost(thread_view)) => {
+                let mut posts = Vec::new();
+                Self::collect_parents(&thread_view.data, &mut posts);
+                posts.push(post_from_view(&thread_view.post));
+                posts.extend(ReplyThread::flatten(
+                    self.extract_replies(&thread_view.data),
+                ));
+                Ok(posts)
+            }
+            Union::Refs(OutputThreadRefs::AppBskyFeedDefsBlockedPost(_)) => {
+                Ok(vec![Post::unavailable(
+                    post_id.to_string(),
+                    PostKind::Blocked,
+                )])
+            }
+            Union::Refs(OutputThreadRefs::AppBskyFeedDefsNotFoundPost(_)) => {
+                Err(PlatformError::NotFound(post_id.to_string()))
+            }
+            Union::Unknown(_) => Ok(Vec::new()),
+        }
+    }
+
+    async fn create_post(&self, text: &str) -> Result<String, PlatformError> {
+        Ok(self.create_post_record(text, None).await?.uri)
     }
 
-    async fn reply_to_post(&self, post_id: &str, text: &str) -> Result<(), PlatformError> {
+    async fn reply_to_post(&self, post_id: &str, text: &str) -> Result<String, PlatformError> {
         // post_id is the AT URI of the parent post
         // We need to get the CID and root info for the reply reference
         let (parent_cid, root_info) = self.get_post_info(post_id).await?;
@@ -320,23 +880,415 @@ impl SocialClient for BlueskyClient {
             .into(),
         };
 
+        Ok(self.create_post_record(text, Some(reply_ref)).await?.uri)
+    }
+
+    async fn create_thread(&self, segments: &[String]) -> Result<(), ThreadError> {
+        // Build reply refs from the records we just created; the AppView may
+        // not have indexed them yet, so reply_to_post's thread lookup could fail
+        let mut root: Option<StrongRef> = None;
+        let mut parent: Option<StrongRef> = None;
+
+        for (i, text) in segments.iter().enumerate() {
+            let reply = match (&root, &parent) {
+                (Some(root), Some(parent)) => Some(ReplyRefData {
+                    parent: parent.clone().into(),
+                    root: root.clone().into(),
+                }),
+                _ => None,
+            };
+
+            let created = self
+                .create_post_record(text, reply)
+                .await
+                .map_err(|source| ThreadError {
+                    failed_segment: i + 1,
+                    total: segments.len(),
+                    source,
+                })?;
+            root.get_or_insert_with(|| created.clone());
+            parent = Some(created);
+        }
+
+        Ok(())
+    }
+
+    async fn create_carousel(
+        &self,
+        text: &str,
+        images: &[ImageAttachment],
+    ) -> Result<String, PlatformError> {
+        if images.len() > MAX_IMAGES {
+            return Err(PlatformError::Api(format!(
+                "Bluesky posts can have at most {} images, got {}",
+                MAX_IMAGES,
+                images.len()
+            )));
+        }
+
+        // Bluesky stores images as blobs on the PDS, so fetch and upload each one
+        let http = ndl_core::http_client().map_err(|e| PlatformError::Request(e.to_string()))?;
+        let mut embedded = Vec::with_capacity(images.len());
+        for (i, image) in images.iter().enumerate() {
+            let image_error =
+                |e: String| PlatformError::Api(format!("Image {} ({}): {}", i + 1, image.url, e));
+
+            let bytes = http
+                .get(&image.url)
+                .send()
+                .await
+                .and_then(|r| r.error_for_status())
+                .map_err(|e| image_error(e.to_string()))?
+                .bytes()
+                .await
+                .map_err(|e| image_error(e.to_string()))?;
+
+            let agent = self.agent.read().await;
+            let uploaded = agent
+                .api
+                .com
+                .atproto
+                .repo
+                .upload_blob(bytes.to_vec())
+                .await
+                .map_err(|e| image_error(format!("upload failed: {}", e)))?;
+
+            embedded.push(json!({
+                "alt": image.alt.clone().unwrap_or_default(),
+                "image": uploaded.data.blob,
+            }));
+        }
+
+        let record: RecordData = record_from_json(json!({
+            "text": text,
+            "createdAt": Datetime::now(),
+            "embed": {
+                "$type": "app.bsky.embed.images",
+                "images": embedded,
+            },
+        }))?;
+
+        let agent = self.agent.read().await;
+        let output = agent
+            .create_record(record)
+            .await
+            .map_err(|e| PlatformError::Api(format!("Failed to create post: {}", e)))?;
+
+        Ok(output.uri.clone())
+    }
+
+    async fn delete_post(&self, post_id: &str) -> Result<(), PlatformError> {
+        let uri = parse_at_uri(post_id)
+            .filter(|uri| uri.collection == POST_COLLECTION)
+            .ok_or_else(|| PlatformError::Api(format!("Not a post URI: {}", post_id)))?;
+
         let agent = self.agent.read().await;
+        let session = agent
+            .get_session()
+            .await
+            .ok_or_else(|| PlatformError::Auth("No active session".to_string()))?;
+        if uri.authority != session.did.as_str() && uri.authority != session.handle.as_str() {
+            return Err(PlatformError::Api(
+                "Only your own posts can be deleted".to_string(),
+            ));
+        }
 
+        let invalid = |e: &dyn std::fmt::Display| {
+            PlatformError::Api(format!("Invalid post URI '{}': {}", post_id, e))
+        };
         agent
-            .create_record(RecordData {
-                created_at: Datetime::now(),
-                embed: None,
-                entities: None,
-                facets: None,
-                labels: None,
-                langs: None,
-                reply: Some(reply_ref.into()),
-                tags: None,
-                text: text.to_string(),
-            })
+            .api
+            .com
+            .atproto
+            .repo
+            .delete_record(
+                atrium_api::com::atproto::repo::delete_record::InputData {
+                    collection: uri.collection.parse().map_err(|e| invalid(&e))?,
+                    repo: session.did.clone().into(),
+                    rkey: uri.rkey.parse().map_err(|e| invalid(&e))?,
+                    swap_commit: None,
+                    swap_record: None,
+                }
+                .into(),
+            )
             .await
-            .map_err(|e| PlatformError::Api(format!("Failed to create reply: {}", e)))?;
+            .map_err(|e| xrpc_error("Failed to delete post", e))?;
 
         Ok(())
     }
+
+    async fn follow(&self, actor: &str) -> Result<(), PlatformError> {
+        // Resolve handles to a DID; the profile also tells us if we already follow
+        let profile = self.fetch_profile(actor).await?;
+        let did = profile.did.as_str().to_string();
+        if profile
+            .viewer
+            .as_ref()
+            .is_some_and(|v| v.following.is_some())
+        {
+            return Ok(());
+        }
+
+        let record: follow::RecordData = record_from_json(json!({
+            "subject": did,
+            "createdAt": Datetime::now(),
+        }))?;
+
+        let agent = self.agent.read().await;
+        let output = agent
+            .create_record(record)
+            .await
+            .map_err(|e| PlatformError::Api(format!("Failed to follow: {}", e)))?;
+
+        self.follows.write().await.insert(did, output.uri.clone());
+
+        Ok(())
+    }
+
+    async fn unfollow(&self, actor: &str) -> Result<(), PlatformError> {
+        let profile = self.fetch_profile(actor).await?;
+        let did = profile.did.as_str().to_string();
+
+        let follow_uri = match self.follows.write().await.remove(&did) {
+            Some(uri) => Some(uri),
+            None => profile.viewer.as_ref().and_then(|v| v.following.clone()),
+        };
+        let Some(follow_uri) = follow_uri else {
+            return Err(PlatformError::Api("Not following this account".to_string()));
+        };
+
+        let agent = self.agent.read().await;
+        agent
+            .delete_record(follow_uri)
+            .await
+            .map_err(|e| PlatformError::Api(format!("Failed to unfollow: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn like_post(&self, post_id: &str) -> Result<(), PlatformError> {
+        let view = self.fetch_post_view(post_id).await?;
+        if view.viewer.as_ref().is_some_and(|v| v.like.is_some()) {
+            return Ok(());
+        }
+
+        let record: like::RecordData = record_from_json(json!({
+            "subject": {"uri": view.uri, "cid": view.cid.as_ref().to_string()},
+            "createdAt": Datetime::now(),
+        }))?;
+
+        let agent = self.agent.read().await;
+        let output = agent
+            .create_record(record)
+            .await
+            .map_err(|e| PlatformError::Api(format!("Failed to like: {}", e)))?;
+
+        self.likes
+            .write()
+            .await
+            .insert(post_id.to_string(), output.uri.clone());
+
+        Ok(())
+    }
+
+    async fn unlike_post(&self, post_id: &str) -> Result<(), PlatformError> {
+        let cached = self.likes.write().await.remove(post_id);
+        let like_uri = match cached {
+            Some(uri) => Some(uri),
+            None => self
+                .fetch_post_view(post_id)
+                .await?
+                .viewer
+                .as_ref()
+                .and_then(|v| v.like.clone()),
+        };
+        let Some(like_uri) = like_uri else {
+            return Err(PlatformError::Api(
+                "You haven't liked this post".to_string(),
+            ));
+        };
+
+        let agent = self.agent.read().await;
+        agent
+            .delete_record(like_uri)
+            .await
+            .map_err(|e| PlatformError::Api(format!("Failed to unlike: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn repost(&self, post_id: &str) -> Result<(), PlatformError> {
+        let view = self.fetch_post_view(post_id).await?;
+        if view.viewer.as_ref().is_some_and(|v| v.repost.is_some()) {
+            return Ok(());
+        }
+
+        let record: repost::RecordData = record_from_json(json!({
+            "subject": {"uri": view.uri, "cid": view.cid.as_ref().to_string()},
+            "createdAt": Datetime::now(),
+        }))?;
+
+        let agent = self.agent.read().await;
+        agent
+            .create_record(record)
+            .await
+            .map_err(|e| PlatformError::Api(format!("Failed to repost: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn mute(&self, actor: &str) -> Result<(), PlatformError> {
+        let actor: AtIdentifier = actor
+            .parse()
+            .map_err(|e| PlatformError::Api(format!("Invalid actor '{}': {}", actor, e)))?;
+
+        let agent = self.agent.read().await;
+        agent
+            .api
+            .app
+            .bsky
+            .graph
+            .mute_actor(mute_actor::InputData { actor }.into())
+            .await
+            .map_err(|e| PlatformError::Api(format!("Failed to mute: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn block(&self, actor: &str) -> Result<(), PlatformError> {
+        // Block records need the subject DID
+        let profile = self.fetch_profile(actor).await?;
+        if profile
+            .viewer
+            .as_ref()
+            .is_some_and(|v| v.blocking.is_some())
+        {
+            return Ok(());
+        }
+
+        let record: block::RecordData = record_from_json(json!({
+            "subject": profile.did.as_str(),
+            "createdAt": Datetime::now(),
+        }))?;
+
+        let agent = self.agent.read().await;
+        agent
+            .create_record(record)
+            .await
+            .map_err(|e| PlatformError::Api(format!("Failed to block: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_app_password() {
+        assert!(is_app_password("abcd-2fgh-ijkl-mn0p"));
+        assert!(!is_app_password("correct horse battery staple"));
+        assert!(!is_app_password("ABCD-EFGH-IJKL-MNOP"));
+        assert!(!is_app_password("abcd-efgh-ijkl"));
+        assert!(!is_app_password("abcde-fgh-ijkl-mnop"));
+    }
+
+    #[test]
+    fn test_threadgate_audience() {
+        let gate = |allow: serde_json::Value| threadgate_audience(&json!({ "allow": allow }));
+        assert_eq!(gate(json!([])), Some(ReplyAudience::Nobody));
+        assert_eq!(
+            gate(json!([{ "$type": "app.bsky.feed.threadgate#followingRule" }])),
+            Some(ReplyAudience::Following)
+        );
+        assert_eq!(
+            gate(json!([
+                { "$type": "app.bsky.feed.threadgate#mentionRule" },
+                { "$type": "app.bsky.feed.threadgate#listRule", "list": "at://x" }
+            ])),
+            Some(ReplyAudience::Mentioned)
+        );
+        assert_eq!(threadgate_audience(&json!({ "post": "at://x" })), None);
+        assert_eq!(threadgate_audience(&serde_json::Value::Null), None);
+    }
+
+    #[test]
+    fn test_parse_identifier() {
+        assert_eq!(
+            parse_identifier(" @Alice.bsky.social "),
+            Some(Identifier::Handle("alice.bsky.social".to_string()))
+        );
+        assert_eq!(
+            parse_identifier("me@example.com"),
+            Some(Identifier::Email("me@example.com".to_string()))
+        );
+        assert_eq!(
+            parse_identifier("did:plc:abc123"),
+            Some(Identifier::Did("did:plc:abc123".to_string()))
+        );
+        for typo in [
+            "alice",
+            "alice.",
+            "alice bsky.social",
+            "me@localhost",
+            "@",
+            "a.1com",
+        ] {
+            assert_eq!(parse_identifier(typo), None, "{}", typo);
+        }
+    }
+
+    /// Serve `total` numbered items in pages, recording each requested page size
+    async fn fake_feed(limit: u32, total: usize) -> (Vec<usize>, Vec<u8>) {
+        let (items, requested, _) = fake_feed_from(None, limit, total).await;
+        (items, requested)
+    }
+
+    async fn fake_feed_from(
+        start: Option<String>,
+        limit: u32,
+        total: usize,
+    ) -> (Vec<usize>, Vec<u8>, Option<String>) {
+        let mut requested = Vec::new();
+        let (items, next) = paginate(limit, start, |cursor: Option<String>, page_size| {
+            requested.push(page_size);
+            let start = cursor.map_or(0, |c| c.parse().unwrap());
+            let end = (start + page_size as usize).min(total);
+            let next = (end < total).then(|| end.to_string());
+            async move { Ok::<_, PlatformError>(((start..end).collect(), next)) }
+        })
+        .await
+        .unwrap();
+        (items, requested, next)
+    }
+
+    #[tokio::test]
+    async fn test_paginate_resumes_from_cursor() {
+        let (items, _, next) = fake_feed_from(None, 25, 60).await;
+        assert_eq!(items, (0..25).collect::<Vec<_>>());
+        assert_eq!(next.as_deref(), Some("25"));
+
+        let (items, _, next) = fake_feed_from(next, 50, 60).await;
+        assert_eq!(items, (25..60).collect::<Vec<_>>());
+        assert_eq!(next, None);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_past_page_size() {
+        let (items, requested) = fake_feed(150, 500).await;
+        assert_eq!(items, (0..150).collect::<Vec<_>>());
+        assert_eq!(requested, vec![100, 50]);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_when_feed_runs_out() {
+        let (items, requested) = fake_feed(150, 120).await;
+        assert_eq!(items.len(), 120);
+        assert_eq!(requested, vec![100, 50]);
+
+        let (items, requested) = fake_feed(25, 500).await;
+        assert_eq!(items.len(), 25);
+        assert_eq!(requested, vec![25]);
+    }
 }
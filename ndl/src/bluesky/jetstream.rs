@@ -0,0 +1,107 @@
+//! Jetstream subscription for near-real-time posts
+
+use futures::future;
+use futures::stream::{BoxStream, StreamExt};
+use serde::Deserialize;
+
+use super::facets_from_record;
+use super::uri::{self, POST_COLLECTION};
+use crate::platform::{PlatformError, Post};
+
+/// Public Jetstream instance run by Bluesky
+const JETSTREAM_URL: &str = "wss://jetstream2.us-east.bsky.network/subscribe";
+
+/// One Jetstream message; only commit events are of interest
+#[derive(Debug, Deserialize)]
+struct Event {
+    did: String,
+    kind: String,
+    commit: Option<Commit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Commit {
+    operation: String,
+    collection: String,
+    rkey: String,
+    record: Option<serde_json::Value>,
+}
+
+/// Turn a Jetstream message into a post if it announces a newly created one
+fn post_from_event(message: &str, handle: &str) -> Option<Post> {
+    let event: Event = serde_json::from_str(message).ok()?;
+    let commit = event.commit.filter(|c| {
+        event.kind == "commit" && c.operation == "create" && c.collection == POST_COLLECTION
+    })?;
+    let record = commit.record?;
+    let id = format!("at://{}/{}/{}", event.did, POST_COLLECTION, commit.rkey);
+    let field = |path: &[&str]| {
+        path.iter()
+            .try_fold(&record, |value, key| value.get(key))
+            .and_then(|v| v.as_str())
+            .map(String::from)
+    };
+    let text = field(&["text"]);
+
+    Some(Post {
+        permalink: uri::to_web_url(handle, &id),
+        id,
+        facets: text
+            .as_deref()
+            .map(|text| facets_from_record(&record, text))
+            .unwrap_or_default(),
+        text,
+        author_handle: Some(handle.to_string()),
+        author_did: Some(event.did),
+        timestamp: field(&["createdAt"]),
+        root_id: field(&["reply", "root", "uri"]),
+        ..Default::default()
+    })
+}
+
+/// Stream the account's new posts as they're created. The stream ends when
+/// the socket closes or fails.
+pub async fn subscribe(did: &str, handle: &str) -> Result<BoxStream<'static, Post>, PlatformError> {
+    let url = format!(
+        "{}?wantedCollections={}&wantedDids={}",
+        JETSTREAM_URL, POST_COLLECTION, did
+    );
+    let (socket, _) = tokio_tungstenite::connect_async(url.as_str())
+        .await
+        .map_err(|e| PlatformError::Request(format!("Couldn't connect to Jetstream: {}", e)))?;
+
+    let handle = handle.to_string();
+    Ok(socket
+        .take_while(|message| future::ready(message.is_ok()))
+        .filter_map(move |message| {
+            let post = message
+                .ok()
+                .and_then(|m| m.to_text().ok().and_then(|t| post_from_event(t, &handle)));
+            future::ready(post)
+        })
+        .boxed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_post_from_event() {
+        let created = r#"{"did":"did:plc:abc","time_us":1,"kind":"commit","commit":{"rev":"r","operation":"create","collection":"app.bsky.feed.post","rkey":"3kabc","record":{"$type":"app.bsky.feed.post","text":"hello","createdAt":"2024-01-01T00:00:00Z"},"cid":"c"}}"#;
+        let post = post_from_event(created, "alice.bsky.social").unwrap();
+        assert_eq!(post.id, "at://did:plc:abc/app.bsky.feed.post/3kabc");
+        assert_eq!(post.text.as_deref(), Some("hello"));
+        assert_eq!(
+            post.permalink.as_deref(),
+            Some("https://bsky.app/profile/alice.bsky.social/post/3kabc")
+        );
+        assert_eq!(post.timestamp.as_deref(), Some("2024-01-01T00:00:00Z"));
+        assert!(post.root_id.is_none());
+
+        let deleted = r#"{"did":"did:plc:abc","time_us":2,"kind":"commit","commit":{"rev":"r","operation":"delete","collection":"app.bsky.feed.post","rkey":"3kabc"}}"#;
+        assert!(post_from_event(deleted, "alice.bsky.social").is_none());
+        let identity = r#"{"did":"did:plc:abc","time_us":3,"kind":"identity","identity":{}}"#;
+        assert!(post_from_event(identity, "alice.bsky.social").is_none());
+    }
+}
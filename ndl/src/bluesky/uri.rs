@@ -0,0 +1,178 @@
+//! AT URI and bsky.app permalink helpers
+
+use super::is_domain;
+
+/// Collection NSID of post records
+pub const POST_COLLECTION: &str = "app.bsky.feed.post";
+
+/// The parts of an `at://<authority>/<collection>/<rkey>` record URI
+#[derive(Debug, PartialEq, Eq)]
+pub struct AtUri<'a> {
+    /// DID or handle of the repo holding the record
+    pub authority: &'a str,
+    /// NSID of the record's collection, e.g. `app.bsky.feed.post`
+    pub collection: &'a str,
+    /// Record key within the collection
+    pub rkey: &'a str,
+}
+
+/// Split a record AT URI into its parts, or `None` if it doesn't name a
+/// single record. Query strings and fragments are ignored.
+pub fn parse_at_uri(uri: &str) -> Option<AtUri<'_>> {
+    let path = uri.strip_prefix("at://")?.split(['?', '#']).next()?;
+    let mut parts = path.split('/');
+    let (authority, collection, rkey) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() {
+        return None;
+    }
+
+    let valid_authority = match authority.strip_prefix("did:") {
+        Some(did) => did.contains(':') && !did.starts_with(':') && !did.ends_with(':'),
+        None => is_domain(authority),
+    };
+    let valid_collection = collection.split('.').count() >= 3
+        && collection.split('.').all(|segment| {
+            !segment.is_empty()
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+    let valid_rkey = (1..=512).contains(&rkey.len())
+        && rkey != "."
+        && rkey != ".."
+        && rkey
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "._:~-".contains(c));
+
+    (valid_authority && valid_collection && valid_rkey).then_some(AtUri {
+        authority,
+        collection,
+        rkey,
+    })
+}
+
+/// DID of the repo an AT URI points into, if it names one (rather than a handle)
+pub fn did_of(uri: &str) -> Option<&str> {
+    parse_at_uri(uri)
+        .map(|uri| uri.authority)
+        .filter(|authority| authority.starts_with("did:"))
+}
+
+/// bsky.app permalink for a post's AT URI, shown under `handle`. `None` if the
+/// URI isn't a post record.
+pub fn to_web_url(handle: &str, uri: &str) -> Option<String> {
+    let parsed = parse_at_uri(uri).filter(|uri| uri.collection == POST_COLLECTION)?;
+    let actor = if handle.is_empty() {
+        parsed.authority
+    } else {
+        handle
+    };
+    Some(format!(
+        "https://bsky.app/profile/{}/post/{}",
+        actor, parsed.rkey
+    ))
+}
+
+/// AT URI of the post a bsky.app permalink points to. The authority is the
+/// handle or DID from the link, so handles still need resolving.
+pub fn parse_web_url(url: &str) -> Option<String> {
+    let rest = url
+        .trim()
+        .strip_prefix("https://")
+        .or_else(|| url.trim().strip_prefix("http://"))?;
+    let rest = rest.split(['?', '#']).next()?;
+    let (host, path) = rest.split_once('/')?;
+    if host.strip_prefix("www.").unwrap_or(host) != "bsky.app" {
+        return None;
+    }
+
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    let ["profile", actor, "post", rkey] = segments.as_slice() else {
+        return None;
+    };
+    let uri = format!("at://{}/{}/{}", actor, POST_COLLECTION, rkey);
+    parse_at_uri(&uri).is_some().then_some(uri)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_at_uri() {
+        assert_eq!(
+            parse_at_uri("at://did:plc:abc123/app.bsky.feed.post/3kabc2xyz"),
+            Some(AtUri {
+                authority: "did:plc:abc123",
+                collection: "app.bsky.feed.post",
+                rkey: "3kabc2xyz",
+            })
+        );
+        let by_handle = parse_at_uri("at://alice.bsky.social/app.bsky.feed.like/3k?x=1").unwrap();
+        assert_eq!(by_handle.authority, "alice.bsky.social");
+        assert_eq!(by_handle.rkey, "3k");
+
+        for bad in [
+            "https://bsky.app/profile/alice.bsky.social/post/3k",
+            "at://did:plc:abc123",
+            "at://did:plc:abc123/app.bsky.feed.post",
+            "at://did:plc:abc123/app.bsky.feed.post/",
+            "at://did:plc:abc123/app.bsky.feed.post/3k/extra",
+            "at://did:/app.bsky.feed.post/3k",
+            "at://alice/app.bsky.feed.post/3k",
+            "at://did:plc:abc123/feed/3k",
+            "at://did:plc:abc123/app.bsky.feed.post/..",
+            "at://did:plc:abc123/app.bsky.feed.post/a b",
+        ] {
+            assert_eq!(parse_at_uri(bad), None, "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_did_of() {
+        let uri = "at://did:plc:abc123/app.bsky.feed.post/3kabc";
+        assert_eq!(did_of(uri), Some("did:plc:abc123"));
+        assert_eq!(
+            did_of("at://alice.bsky.social/app.bsky.feed.post/3kabc"),
+            None
+        );
+        assert_eq!(did_of("at://did:plc:abc123/app.bsky.feed.post/"), None);
+    }
+
+    #[test]
+    fn test_web_urls() {
+        let uri = "at://did:plc:abc123/app.bsky.feed.post/3kabc";
+        assert_eq!(
+            to_web_url("alice.bsky.social", uri).as_deref(),
+            Some("https://bsky.app/profile/alice.bsky.social/post/3kabc")
+        );
+        assert_eq!(
+            to_web_url("", uri).as_deref(),
+            Some("https://bsky.app/profile/did:plc:abc123/post/3kabc")
+        );
+        assert_eq!(
+            to_web_url(
+                "alice.bsky.social",
+                "at://did:plc:abc123/app.bsky.feed.like/3k"
+            ),
+            None
+        );
+
+        assert_eq!(
+            parse_web_url("https://bsky.app/profile/alice.bsky.social/post/3kabc?ref=x").as_deref(),
+            Some("at://alice.bsky.social/app.bsky.feed.post/3kabc")
+        );
+        assert_eq!(
+            parse_web_url("https://bsky.app/profile/alice.bsky.social"),
+            None
+        );
+        assert_eq!(
+            parse_web_url("https://example.com/profile/a.b/post/3k"),
+            None
+        );
+        assert_eq!(
+            parse_web_url("https://bsky.app/profile/alice/post/3k"),
+            None
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::config::{Config, ConfigError, write_file};
+use crate::platform::{Platform, Post};
+
+/// Last fetched posts per platform, shown at startup while the first fetch
+/// runs (~/.config/ndl/posts_cache.json)
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PostCache {
+    #[serde(default)]
+    pub posts: HashMap<Platform, Vec<Post>>,
+}
+
+impl PostCache {
+    /// Get the cache file path (~/.config/ndl/posts_cache.json)
+    pub fn path() -> Result<PathBuf, ConfigError> {
+        Ok(Config::dir()?.join("posts_cache.json"))
+    }
+
+    /// Load the cache, or return an empty one if there is none
+    pub fn load() -> Result<Self, ConfigError> {
+        let path = Self::path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    pub fn save(&self) -> Result<(), ConfigError> {
+        let contents = serde_json::to_string(self)?;
+        write_file(&Self::path()?, &contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::{Facet, FacetKind, PostKind};
+
+    #[test]
+    fn test_cache_roundtrip() {
+        let mut cache = PostCache::default();
+        cache.posts.insert(
+            Platform::Bluesky,
+            vec![Post {
+                id: "at://did:plc:abc/app.bsky.feed.post/1".to_string(),
+                text: Some("see https://example.com".to_string()),
+                kind: PostKind::Normal,
+                facets: vec![Facet {
+                    start: 4,
+                    end: 23,
+                    kind: FacetKind::Link,
+                }],
+                ..Default::default()
+            }],
+        );
+
+        let json = serde_json::to_string(&cache).unwrap();
+        let loaded: PostCache = serde_json::from_str(&json).unwrap();
+        let post = &loaded.posts[&Platform::Bluesky][0];
+        assert_eq!(post.text.as_deref(), Some("see https://example.com"));
+        assert_eq!(post.facets, cache.posts[&Platform::Bluesky][0].facets);
+    }
+}
@@ -0,0 +1,12 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use std::io::{self, Write};
+
+/// Copy text to the system clipboard using the OSC 52 terminal escape sequence.
+/// This goes through the terminal emulator, so it also works over SSH.
+pub fn copy(text: &str) -> io::Result<()> {
+    let encoded = STANDARD.encode(text);
+    let mut out = io::stdout();
+    write!(out, "\x1b]52;c;{}\x07", encoded)?;
+    out.flush()
+}
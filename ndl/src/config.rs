@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -8,8 +8,105 @@ pub enum ConfigError {
     NoConfigDir,
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
+    #[error("Could not write {}: {source}", path.display())]
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
     #[error("JSON parse error: {0}")]
     JsonParse(#[from] serde_json::Error),
+    #[error("Could not parse {}: {message}", path.display())]
+    Parse { path: PathBuf, message: String },
+}
+
+/// Points at a JSON or TOML file with the Threads client ID and secret
+pub const CREDENTIALS_FILE_ENV: &str = "NDL_CREDENTIALS_FILE";
+
+/// Threads client credentials kept outside the main config and the process
+/// environment, e.g. written by a secret manager or CI
+#[derive(Debug, Default, Deserialize)]
+pub struct ClientCredentials {
+    #[serde(alias = "id")]
+    pub client_id: Option<String>,
+    #[serde(alias = "secret")]
+    pub client_secret: Option<String>,
+}
+
+impl ClientCredentials {
+    /// Load the file named by `NDL_CREDENTIALS_FILE`, or nothing if it's unset
+    pub fn from_env() -> Result<Self, ConfigError> {
+        match std::env::var_os(CREDENTIALS_FILE_ENV).filter(|p| !p.is_empty()) {
+            Some(path) => Self::load(Path::new(&path)),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Parse a `.toml` file as TOML and anything else as JSON
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let contents = std::fs::read_to_string(path)?;
+        let parsed = if path.extension().is_some_and(|ext| ext == "toml") {
+            toml::from_str(&contents).map_err(|e| e.to_string())
+        } else {
+            serde_json::from_str(&contents).map_err(|e| e.to_string())
+        };
+        parsed.map_err(|message| ConfigError::Parse {
+            path: path.to_path_buf(),
+            message,
+        })
+    }
+}
+
+/// Write a file under the config directory, creating the directory first.
+/// Errors name the path so a read-only home dir is easy to spot.
+pub(crate) fn write_file(path: &Path, contents: &str) -> Result<(), ConfigError> {
+    let write = || {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, contents)
+    };
+    write().map_err(|source| ConfigError::Write {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Placeholder for the Threads access token in redacted output
+const REDACTED_TOKEN: &str = "thr_***";
+
+/// Placeholder for every other secret in redacted output
+const REDACTED: &str = "***";
+
+/// Whether a config key holds a credential. Matched by name so fields added
+/// later are hidden without updating a list.
+fn is_secret_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    ["token", "secret", "password", "session"]
+        .iter()
+        .any(|word| key.contains(word))
+        && !key.ends_with("_expires_at")
+}
+
+/// Replace secret values in place, keeping `null` so unset fields still show
+fn redact(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_secret_key(key) && !value.is_null() {
+                    let placeholder = if key == "access_token" {
+                        REDACTED_TOKEN
+                    } else {
+                        REDACTED
+                    };
+                    *value = serde_json::Value::String(placeholder.to_string());
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact),
+        _ => {}
+    }
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -22,9 +119,36 @@ pub struct Config {
     pub client_secret: Option<String>,
     /// Optional auth server URL for hosted OAuth flow
     pub auth_server: Option<String>,
+    /// Redirect URI registered on the Threads app, for local OAuth
+    /// (`NDL_REDIRECT_URI` overrides; defaults to `https://localhost:1337/callback`)
+    pub redirect_uri: Option<String>,
+    /// Threads scopes to request at login (comma-separated; `NDL_SCOPES` overrides)
+    pub scopes: Option<String>,
+    /// Fields to request for Threads feed posts (defaults to what ndl displays)
+    pub threads_fields: Option<Vec<String>>,
 
     // Bluesky credentials
     pub bluesky: Option<BlueskyConfig>,
+
+    // TUI preferences
+    /// Show a preview popup and require a second Enter before sending a post
+    #[serde(default)]
+    pub confirm_before_post: bool,
+    /// Maximum characters shown per list row (defaults to the full panel width)
+    pub list_preview_chars: Option<usize>,
+    /// List row template, e.g. `{relative_time} @{handle}: {text}`
+    pub list_format: Option<String>,
+    /// Refuse to send images until each one has alt text
+    #[serde(default)]
+    pub require_alt_text: bool,
+    /// Browse only: log in without publishing scopes and disable posting in the TUI
+    #[serde(default)]
+    pub read_only: bool,
+    /// Start with background refreshes paused (Ctrl+R resumes)
+    #[serde(default)]
+    pub pause_auto_refresh: bool,
+    /// Seconds between background refreshes (defaults to 11, at least 5; 0 disables them)
+    pub refresh_interval_secs: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -78,16 +202,19 @@ impl Config {
 
     /// Save config to disk, creating the directory if needed
     pub fn save(&self) -> Result<(), ConfigError> {
-        let dir = Self::dir()?;
-        std::fs::create_dir_all(&dir)?;
-        let path = Self::path()?;
         let contents = serde_json::to_string_pretty(self)?;
-        std::fs::write(path, contents)?;
-        Ok(())
+        write_file(&Self::path()?, &contents)
+    }
+
+    /// The config as JSON with tokens, secrets, passwords, and sessions
+    /// replaced by placeholders, safe to paste into a bug report
+    pub fn redacted(&self) -> Result<serde_json::Value, ConfigError> {
+        let mut value = serde_json::to_value(self)?;
+        redact(&mut value);
+        Ok(value)
     }
 
     /// Check if client credentials are configured
-    #[allow(dead_code)]
     pub fn has_credentials(&self) -> bool {
         self.client_id.is_some() && self.client_secret.is_some()
     }
@@ -102,6 +229,11 @@ impl Config {
         self.access_token.is_some()
     }
 
+    /// Check if Threads client credentials are saved but login never finished
+    pub fn needs_threads_login(&self) -> bool {
+        self.has_credentials() && !self.has_threads()
+    }
+
     /// Check if the Threads token needs refreshing (within 27 days of expiration or already expired)
     pub fn should_refresh_token(&self) -> bool {
         if let Some(expires_at) = self.token_expires_at {
@@ -133,6 +265,49 @@ impl Config {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_credentials_file_json_and_toml() {
+        let dir = std::env::temp_dir().join(format!("ndl-creds-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let json = dir.join("creds.json");
+        std::fs::write(&json, r#"{"client_id": "123", "client_secret": "shh"}"#).unwrap();
+        let creds = ClientCredentials::load(&json).unwrap();
+        assert_eq!(creds.client_id.as_deref(), Some("123"));
+        assert_eq!(creds.client_secret.as_deref(), Some("shh"));
+
+        let toml = dir.join("creds.toml");
+        std::fs::write(&toml, "id = \"456\"\nsecret = \"hush\"\n").unwrap();
+        let creds = ClientCredentials::load(&toml).unwrap();
+        assert_eq!(creds.client_id.as_deref(), Some("456"));
+        assert_eq!(creds.client_secret.as_deref(), Some("hush"));
+
+        std::fs::write(&json, "not json").unwrap();
+        assert!(matches!(
+            ClientCredentials::load(&json),
+            Err(ConfigError::Parse { .. })
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_needs_threads_login_with_credentials_only() {
+        let mut config = Config {
+            client_id: Some("123".to_string()),
+            client_secret: Some("shh".to_string()),
+            ..Default::default()
+        };
+        assert!(config.needs_threads_login());
+
+        config.access_token = Some("token".to_string());
+        assert!(!config.needs_threads_login());
+
+        config.client_secret = None;
+        config.access_token = None;
+        assert!(!config.needs_threads_login());
+    }
+
     #[test]
     fn test_config_preserves_bluesky_on_threads_update() {
         // Create a config with both Threads and Bluesky
@@ -147,6 +322,7 @@ mod tests {
                 password: "secret".to_string(),
                 session: Some("session_data".to_string()),
             }),
+            ..Default::default()
         };
 
         // Simulate updating Threads token (what login does)
@@ -161,6 +337,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_redacted_hides_secrets() {
+        let config = Config {
+            access_token: Some("THAAsecret".to_string()),
+            token_expires_at: Some(42),
+            client_id: Some("123".to_string()),
+            client_secret: Some("shh".to_string()),
+            bluesky: Some(BlueskyConfig {
+                identifier: "user.bsky.social".to_string(),
+                password: "hunter2".to_string(),
+                session: Some("{\"accessJwt\":\"eyJ\"}".to_string()),
+            }),
+            ..Default::default()
+        };
+
+        let value = config.redacted().unwrap();
+        let output = value.to_string();
+        for secret in ["THAAsecret", "shh", "hunter2", "eyJ"] {
+            assert!(!output.contains(secret), "{} leaked", secret);
+        }
+        assert_eq!(value["access_token"], "thr_***");
+        assert_eq!(value["bluesky"]["password"], "***");
+        assert_eq!(value["token_expires_at"], 42);
+        assert_eq!(value["client_id"], "123");
+        assert_eq!(value["bluesky"]["identifier"], "user.bsky.social");
+        assert!(value["auth_server"].is_null());
+    }
+
     #[test]
     fn test_config_serialization_roundtrip() {
         let config = Config {
@@ -174,6 +378,7 @@ mod tests {
                 password: "secret".to_string(),
                 session: Some("session_data".to_string()),
             }),
+            ..Default::default()
         };
 
         // Serialize to JSON
@@ -191,4 +396,17 @@ mod tests {
             "user.bsky.social"
         );
     }
+
+    #[test]
+    fn test_write_file_error_names_path() {
+        // A regular file where the directory should be makes the write fail
+        let blocker = std::env::temp_dir().join(format!("ndl-blocker-{}", std::process::id()));
+        std::fs::write(&blocker, "").unwrap();
+        let path = blocker.join("config.json");
+
+        let err = write_file(&path, "{}").unwrap_err();
+        std::fs::remove_file(&blocker).unwrap();
+        assert!(matches!(err, ConfigError::Write { .. }));
+        assert!(err.to_string().contains(&path.display().to_string()));
+    }
 }
@@ -0,0 +1,95 @@
+use crate::api::{self, ApiError, DEFAULT_THREAD_FIELDS, ThreadsClient};
+use crate::bluesky::BlueskyClient;
+use crate::config::{BlueskyConfig, Config};
+use crate::platform::{Platform, PlatformError, SocialClient};
+
+/// The `fields` parameter for Threads feed requests: `threads_fields` when
+/// it's set and valid, otherwise the defaults
+pub fn threads_fields(config: &Config) -> String {
+    match config.threads_fields.as_deref().map(api::thread_fields) {
+        Some(Ok(fields)) => fields,
+        Some(Err(e)) => {
+            tracing::warn!("Ignoring threads_fields: {}", e);
+            DEFAULT_THREAD_FIELDS.join(",")
+        }
+        None => DEFAULT_THREAD_FIELDS.join(","),
+    }
+}
+
+/// Create a Threads client and check the token with a minimal request
+pub async fn threads(token: String, fields: String) -> Result<ThreadsClient, ApiError> {
+    let client = ThreadsClient::new(token).with_fields(fields);
+    client.get_threads(Some(1)).await?;
+    Ok(client)
+}
+
+/// Restore the saved Bluesky session (falling back to a fresh login) and
+/// persist the resulting session for next time
+pub async fn bluesky(mut bsky_config: BlueskyConfig) -> Result<BlueskyClient, PlatformError> {
+    // Try to use saved session first, checking it before the first feed fetch
+    let restored = if let Some(ref session) = bsky_config.session {
+        tracing::debug!("Attempting to restore Bluesky session");
+        match BlueskyClient::from_session(session.clone()).await {
+            Ok(client) if client.is_session_valid().await => {
+                tracing::info!("Successfully restored Bluesky session");
+                Some(client)
+            }
+            Ok(_) => {
+                tracing::warn!("Saved Bluesky session has expired, will re-authenticate");
+                None
+            }
+            Err(e) => {
+                tracing::warn!("Failed to restore session, will re-authenticate: {}", e);
+                None
+            }
+        }
+    } else {
+        tracing::debug!("No saved session, logging in to Bluesky");
+        None
+    };
+
+    let client = match restored {
+        Some(client) => client,
+        None => BlueskyClient::login(&bsky_config.identifier, &bsky_config.password).await?,
+    };
+
+    // Update session in config for next time
+    if let Ok(new_session) = client.get_session().await
+        && bsky_config.session.as_ref() != Some(&new_session)
+    {
+        bsky_config.session = Some(new_session);
+        let mut config_mut = Config::load().unwrap_or_default();
+        config_mut.bluesky = Some(bsky_config);
+        let _ = config_mut.save(); // Best effort, don't fail if this errors
+    }
+
+    Ok(client)
+}
+
+/// Connect to a configured platform (used to retry from the TUI and by
+/// headless commands)
+pub async fn platform(
+    platform: Platform,
+    config: &Config,
+) -> Result<Box<dyn SocialClient>, PlatformError> {
+    match platform {
+        Platform::Threads => {
+            let token = config.access_token.clone().ok_or_else(|| {
+                PlatformError::NotConfigured(
+                    "Threads is not configured; run 'ndl login threads'".to_string(),
+                )
+            })?;
+            let client = threads(token, threads_fields(config)).await?;
+            Ok(Box::new(client))
+        }
+        Platform::Bluesky => {
+            let bsky_config = config.bluesky.clone().ok_or_else(|| {
+                PlatformError::NotConfigured(
+                    "Bluesky is not configured; run 'ndl login bluesky'".to_string(),
+                )
+            })?;
+            let client = bluesky(bsky_config).await?;
+            Ok(Box::new(client))
+        }
+    }
+}
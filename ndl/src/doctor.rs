@@ -0,0 +1,232 @@
+use std::fs::OpenOptions;
+
+use crate::config::Config;
+use crate::connect;
+use crate::platform::Platform;
+
+/// Result of one `ndl doctor` check
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// Worth knowing about, but ndl still works (e.g. a platform not set up)
+    Warn,
+    /// Something ndl needs is broken; `ndl doctor` exits non-zero
+    Fail,
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub detail: String,
+}
+
+impl Check {
+    fn new(name: &'static str, status: Status, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Run every check: the config file, each configured platform's login, and
+/// the hosted auth server (when one is used)
+pub async fn run(auth_server: &str) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    let config = match config_checks(&mut checks) {
+        Some(config) => config,
+        None => return checks,
+    };
+
+    for platform in [Platform::Threads, Platform::Bluesky] {
+        checks.push(platform_check(platform, &config).await);
+    }
+    if config.has_threads() {
+        checks.push(token_expiry_check(&config));
+    }
+    checks.push(auth_server_check(auth_server).await);
+    checks
+}
+
+/// Whether the config file can be read and written. Returns the config if it
+/// loaded, since the remaining checks need it.
+fn config_checks(checks: &mut Vec<Check>) -> Option<Config> {
+    let path = match Config::path() {
+        Ok(path) => path,
+        Err(e) => {
+            checks.push(Check::new("Config file", Status::Fail, e.to_string()));
+            return None;
+        }
+    };
+
+    if !path.exists() {
+        checks.push(Check::new(
+            "Config file",
+            Status::Warn,
+            format!("{} doesn't exist yet; run 'ndl login'", path.display()),
+        ));
+        return Some(Config::default());
+    }
+
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            checks.push(Check::new(
+                "Config file",
+                Status::Fail,
+                format!("Can't read {}: {}", path.display(), e),
+            ));
+            return None;
+        }
+    };
+    // Opening for append checks write permission without changing the file
+    checks.push(match OpenOptions::new().append(true).open(&path) {
+        Ok(_) => Check::new("Config file", Status::Ok, path.display().to_string()),
+        Err(e) => Check::new(
+            "Config file",
+            Status::Fail,
+            format!("{} isn't writable: {}", path.display(), e),
+        ),
+    });
+    Some(config)
+}
+
+/// Connect with the saved login, as the TUI does at startup
+async fn platform_check(platform: Platform, config: &Config) -> Check {
+    let name = match platform {
+        Platform::Threads => "Threads",
+        Platform::Bluesky => "Bluesky",
+    };
+    let configured = match platform {
+        Platform::Threads => config.has_threads(),
+        Platform::Bluesky => config.has_bluesky(),
+    };
+    if !configured {
+        let hint = if platform == Platform::Threads && config.needs_threads_login() {
+            "client credentials set but not logged in; run 'ndl login threads'"
+        } else {
+            "not configured"
+        };
+        return Check::new(name, Status::Warn, hint);
+    }
+
+    match connect::platform(platform, config).await {
+        Ok(client) => {
+            let detail = match client.get_profile().await {
+                Ok(profile) => format!("logged in as @{}", profile.handle),
+                Err(_) => "logged in".to_string(),
+            };
+            Check::new(name, Status::Ok, detail)
+        }
+        Err(e) => Check::new(name, Status::Fail, e.to_string()),
+    }
+}
+
+fn token_expiry_check(config: &Config) -> Check {
+    let Some(expires_at) = config.token_expires_at else {
+        return Check::new("Threads token", Status::Ok, "no expiry recorded");
+    };
+    let now = chrono::Utc::now().timestamp();
+    let days = (expires_at as i64 - now) / (24 * 60 * 60);
+    if days < 0 {
+        Check::new(
+            "Threads token",
+            Status::Fail,
+            "expired; run 'ndl login threads'",
+        )
+    } else if config.should_refresh_token() {
+        Check::new(
+            "Threads token",
+            Status::Warn,
+            format!("expires in {} days; ndl refreshes it at startup", days),
+        )
+    } else {
+        Check::new(
+            "Threads token",
+            Status::Ok,
+            format!("expires in {} days", days),
+        )
+    }
+}
+
+/// Reach the auth server's `/health`. Only needed to log in, so a failure is
+/// a warning.
+async fn auth_server_check(auth_server: &str) -> Check {
+    if auth_server.is_empty() {
+        return Check::new("Auth server", Status::Ok, "local OAuth (no server)");
+    }
+    let url = format!("{}/health", auth_server.trim_end_matches('/'));
+    let client = match ndl_core::http_client() {
+        Ok(client) => client,
+        Err(e) => return Check::new("Auth server", Status::Fail, e.to_string()),
+    };
+    match client.get(&url).send().await {
+        Ok(response) if response.status().is_success() => {
+            Check::new("Auth server", Status::Ok, auth_server.to_string())
+        }
+        Ok(response) => Check::new(
+            "Auth server",
+            Status::Warn,
+            format!("{} returned HTTP {}", url, response.status().as_u16()),
+        ),
+        Err(e) => Check::new(
+            "Auth server",
+            Status::Warn,
+            format!("can't reach {}: {}", url, e),
+        ),
+    }
+}
+
+/// The report printed by `ndl doctor`, one line per check
+pub fn report(checks: &[Check]) -> String {
+    checks
+        .iter()
+        .map(|check| {
+            let label = match check.status {
+                Status::Ok => "ok",
+                Status::Warn => "warn",
+                Status::Fail => "FAIL",
+            };
+            format!("{:<5} {:<14} {}\n", label, check.name, check.detail)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_lines() {
+        let checks = vec![
+            Check::new("Config file", Status::Ok, "/tmp/config.json"),
+            Check::new("Bluesky", Status::Warn, "not configured"),
+            Check::new("Threads", Status::Fail, "token expired"),
+        ];
+        assert_eq!(
+            report(&checks),
+            "ok    Config file    /tmp/config.json\n\
+             warn  Bluesky        not configured\n\
+             FAIL  Threads        token expired\n"
+        );
+    }
+
+    #[test]
+    fn test_token_expiry_check() {
+        let mut config = Config {
+            access_token: Some("token".to_string()),
+            ..Default::default()
+        };
+        config.token_expires_at = Some(Config::calculate_expiration(60 * 24 * 60 * 60));
+        assert_eq!(token_expiry_check(&config).status, Status::Ok);
+
+        config.token_expires_at = Some(Config::calculate_expiration(3 * 24 * 60 * 60));
+        assert_eq!(token_expiry_check(&config).status, Status::Warn);
+
+        config.token_expires_at = Some(1);
+        assert_eq!(token_expiry_check(&config).status, Status::Fail);
+    }
+}
@@ -0,0 +1,206 @@
+use chrono::{DateTime, Local, Utc};
+
+use crate::platform::{Platform, Post, parse_timestamp};
+
+/// A value a list row template can show
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Handle,
+    Name,
+    Time,
+    RelativeTime,
+    Text,
+    Platform,
+    Replies,
+}
+
+impl Token {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "handle" => Self::Handle,
+            "name" => Self::Name,
+            "time" => Self::Time,
+            "relative_time" => Self::RelativeTime,
+            "text" => Self::Text,
+            "platform" => Self::Platform,
+            "replies" => Self::Replies,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Token(Token),
+}
+
+/// A list row template such as `{relative_time} @{handle}: {text}`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListFormat {
+    segments: Vec<Segment>,
+}
+
+impl ListFormat {
+    /// Parse a template, rejecting unknown tokens and unbalanced braces
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut rest = template;
+
+        while let Some(open) = rest.find(['{', '}']) {
+            if rest[open..].starts_with('}') {
+                return Err(format!("Unmatched '}}' in list format '{}'", template));
+            }
+            if open > 0 {
+                segments.push(Segment::Literal(rest[..open].to_string()));
+            }
+            let after = &rest[open + 1..];
+            let close = after
+                .find('}')
+                .ok_or_else(|| format!("Unclosed '{{' in list format '{}'", template))?;
+            let name = &after[..close];
+            let token = Token::parse(name)
+                .ok_or_else(|| format!("Unknown token '{{{}}}' in list format", name))?;
+            segments.push(Segment::Token(token));
+            rest = &after[close + 1..];
+        }
+        if !rest.is_empty() {
+            segments.push(Segment::Literal(rest.to_string()));
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// Fill in the template for one post. `text` is the row's text (or media
+    /// indicator); missing values render as empty.
+    pub fn render(
+        &self,
+        post: &Post,
+        platform: Platform,
+        text: &str,
+        now: DateTime<Utc>,
+    ) -> String {
+        let posted = post.timestamp.as_deref().and_then(parse_timestamp);
+
+        let mut row = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(literal) => row.push_str(literal),
+                Segment::Token(Token::Handle) => {
+                    row.push_str(post.author_handle.as_deref().unwrap_or_default())
+                }
+                Segment::Token(Token::Name) => row.push_str(
+                    post.author_name
+                        .as_deref()
+                        .or(post.author_handle.as_deref())
+                        .unwrap_or_default(),
+                ),
+                Segment::Token(Token::Time) => {
+                    if let Some(posted) = posted {
+                        row.push_str(&local_time(posted.with_timezone(&Utc)));
+                    }
+                }
+                Segment::Token(Token::RelativeTime) => {
+                    if let Some(posted) = posted {
+                        row.push_str(&relative_time(posted.with_timezone(&Utc), now));
+                    }
+                }
+                Segment::Token(Token::Text) => row.push_str(&text.replace('\n', " ")),
+                Segment::Token(Token::Platform) => row.push_str(&platform.to_string()),
+                Segment::Token(Token::Replies) => {
+                    if let Some(replies) = post.reply_count {
+                        row.push_str(&replies.to_string());
+                    }
+                }
+            }
+        }
+        row
+    }
+}
+
+/// A post's timestamp for the detail pane: its age (`just now`, `12m`, `3h`,
+/// `2d`) and local time, or the raw string if it doesn't parse
+pub fn humanize_timestamp(ts: &str, now: DateTime<Utc>) -> String {
+    let Some(posted) = parse_timestamp(ts) else {
+        return ts.to_string();
+    };
+    let posted = posted.with_timezone(&Utc);
+    let age = if (now - posted).num_seconds() < 60 {
+        "just now".to_string()
+    } else {
+        relative_time(posted, now)
+    };
+    format!("{} · {}", age, local_time(posted))
+}
+
+fn local_time(posted: DateTime<Utc>) -> String {
+    posted
+        .with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M")
+        .to_string()
+}
+
+/// Short age like `45s`, `12m`, `3h`, or `5d`
+fn relative_time(posted: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let secs = (now - posted).num_seconds().max(0);
+    match secs {
+        0..60 => format!("{}s", secs),
+        60..3600 => format!("{}m", secs / 60),
+        3600..86400 => format!("{}h", secs / 3600),
+        _ => format!("{}d", secs / 86400),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post() -> Post {
+        Post {
+            id: "1".to_string(),
+            text: Some("hello".to_string()),
+            author_handle: Some("alice.test".to_string()),
+            timestamp: Some("2024-06-01T12:00:00+0000".to_string()),
+            reply_count: Some(4),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_render_template() {
+        let format =
+            ListFormat::parse("{relative_time} @{handle} ({name}): {text} [{replies}]").unwrap();
+        let now = "2024-06-01T15:30:00Z".parse().unwrap();
+        assert_eq!(
+            format.render(&post(), Platform::Bluesky, "hello\nworld", now),
+            "3h @alice.test (alice.test): hello world [4]"
+        );
+
+        let format = ListFormat::parse("{platform}: {text}").unwrap();
+        assert_eq!(
+            format.render(&post(), Platform::Threads, "hi", now),
+            "Threads: hi"
+        );
+    }
+
+    #[test]
+    fn test_humanize_timestamp() {
+        let now = "2024-06-01T15:30:00Z".parse().unwrap();
+        let local = local_time("2024-06-01T12:00:00Z".parse().unwrap());
+        assert_eq!(
+            humanize_timestamp("2024-06-01T12:00:00+0000", now),
+            format!("3h · {}", local)
+        );
+        assert!(humanize_timestamp("2024-06-01T15:29:30+0000", now).starts_with("just now · "));
+        assert!(humanize_timestamp("2024-05-30T15:30:00+0000", now).starts_with("2d · "));
+        assert_eq!(humanize_timestamp("yesterday", now), "yesterday");
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_templates() {
+        assert!(ListFormat::parse("{bogus}").is_err());
+        assert!(ListFormat::parse("{text").is_err());
+        assert!(ListFormat::parse("text}").is_err());
+        assert!(ListFormat::parse("plain").is_ok());
+    }
+}
@@ -1,31 +1,69 @@
 mod api;
 mod bluesky;
+mod cache;
+mod clipboard;
 mod config;
+mod connect;
+mod doctor;
+mod list_format;
 mod oauth;
 mod platform;
+mod schedule;
 mod tui;
+mod ui_state;
 
 use api::ThreadsClient;
 use bluesky::BlueskyClient;
-use config::Config;
-use platform::{Platform, SocialClient};
+use chrono::{DateTime, FixedOffset};
+use config::{ClientCredentials, Config};
+use platform::{Platform, PlatformError, Post, SocialClient, parse_since, parse_timestamp};
 use std::collections::HashMap;
 use std::env;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
-use tui::App;
+use tui::{App, AppConfig};
+
+/// Open ndl.log in the config directory, or in a temp directory if the
+/// config directory can't be written
+fn open_log_file() -> Option<tracing_appender::rolling::RollingFileAppender> {
+    let config_dir = Config::dir()
+        .inspect_err(|e| eprintln!("Warning: {}", e))
+        .ok();
+    let temp_dir = env::temp_dir().join("ndl");
+
+    for dir in config_dir.into_iter().chain([temp_dir]) {
+        let appender = std::fs::create_dir_all(&dir)
+            .map_err(|e| e.to_string())
+            .and_then(|_| {
+                tracing_appender::rolling::RollingFileAppender::builder()
+                    .rotation(tracing_appender::rolling::Rotation::NEVER)
+                    .filename_prefix("ndl.log")
+                    .build(&dir)
+                    .map_err(|e| e.to_string())
+            });
+        match appender {
+            Ok(appender) => return Some(appender),
+            Err(e) => eprintln!("Warning: can't write logs to {}: {}", dir.display(), e),
+        }
+    }
+    None
+}
 
 fn init_logging() {
-    let log_dir = Config::dir().expect("Failed to get config directory");
-    std::fs::create_dir_all(&log_dir).expect("Failed to create config directory");
-
-    let file_appender = tracing_appender::rolling::never(&log_dir, "ndl.log");
+    let filter =
+        tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| "ndl=info".into());
+
+    let Some(file_appender) = open_log_file() else {
+        // Last resort; the TUI will draw over anything logged here
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
+            .init();
+        return;
+    };
     let (non_blocking, _guard) = tracing_appender::non_blocking(file_appender);
 
     tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "ndl=info".into()),
-        )
+        .with(filter)
         .with(tracing_subscriber::fmt::layer().with_writer(non_blocking))
         .init();
 
@@ -35,7 +73,7 @@ fn init_logging() {
 
 #[tokio::main]
 async fn main() {
-    // Initialize logging to ~/.config/ndl/ndl.log
+    // Initialize logging to ~/.config/ndl/ndl.log (falls back to a temp dir)
     init_logging();
     tracing::info!("ndl starting");
 
@@ -43,30 +81,51 @@ async fn main() {
     rustls::crypto::ring::default_provider()
         .install_default()
         .expect("Failed to install rustls crypto provider");
-    let args: Vec<String> = env::args().collect();
+
+    // Fail fast on a bad proxy or CA cert rather than silently connecting directly
+    if let Err(e) = ndl_core::http_client() {
+        tracing::error!("{}", e);
+        eprintln!("Error: {}", e);
+        std::process::exit(EXIT_FAILURE);
+    }
+    // `--read-only` can go anywhere: `ndl --read-only` or `ndl login --read-only`
+    let read_only_flag = env::args().any(|a| a == READ_ONLY_FLAG);
+    let args: Vec<String> = env::args().filter(|a| a != READ_ONLY_FLAG).collect();
 
     match args.get(1).map(|s| s.as_str()) {
         Some("--version") | Some("-V") => {
-            print_version();
+            let verbose = matches!(args.get(2).map(|s| s.as_str()), Some("--verbose" | "-v"));
+            print_version(verbose);
+        }
+        Some("version") => {
+            print_version(true);
         }
         Some("login") => {
-            // Check if a platform is specified
-            let platform = args.get(2).map(|s| s.as_str());
+            // Check if a platform is specified; flags can come before or after it
+            let login_args = &args[2..];
+            let platform = login_args
+                .iter()
+                .map(|s| s.as_str())
+                .find(|a| !a.starts_with("--"));
+            let has_flag = |flag: &str| login_args.iter().any(|a| a == flag);
             match platform {
                 Some("bluesky") | Some("bsky") => {
                     tracing::info!("login bluesky command");
-                    if let Err(e) = run_bluesky_login().await {
+                    let allow_main_password = has_flag(ALLOW_MAIN_PASSWORD_FLAG);
+                    if let Err(e) = run_bluesky_login(allow_main_password).await {
                         tracing::error!("Bluesky login failed: {}", e);
                         eprintln!("Bluesky login failed: {}", e);
-                        std::process::exit(1);
+                        std::process::exit(exit_code(e.as_ref()));
                     }
                 }
                 Some("threads") | None => {
                     tracing::info!("login threads command");
-                    if let Err(e) = run_login().await {
+                    let options =
+                        login_options(has_flag(NO_BROWSER_FLAG), has_flag(PASTE_CODE_FLAG));
+                    if let Err(e) = run_login(read_only_flag, options).await {
                         tracing::error!("Login failed: {}", e);
                         eprintln!("Login failed: {}", e);
-                        std::process::exit(1);
+                        std::process::exit(exit_code(e.as_ref()));
                     }
                 }
                 Some(platform) => {
@@ -76,12 +135,47 @@ async fn main() {
                 }
             }
         }
+        Some("config") => match args.get(2).map(|s| s.as_str()) {
+            Some("show") | None => {
+                if let Err(e) = run_config_show() {
+                    eprintln!("Failed to show config: {}", e);
+                    std::process::exit(exit_code(e.as_ref()));
+                }
+            }
+            Some(cmd) => {
+                eprintln!("Unknown config command: {}", cmd);
+                eprintln!("Usage: ndl config show");
+                std::process::exit(1);
+            }
+        },
+        Some("timeline") => {
+            tracing::info!("timeline command");
+            let since = match timeline_since(&args[2..]) {
+                Ok(since) => since,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    eprintln!("Usage: ndl timeline [--since <30m|1h|2d|YYYY-MM-DD|RFC 3339>]");
+                    std::process::exit(1);
+                }
+            };
+            if let Err(e) = run_timeline(since).await {
+                tracing::error!("Timeline failed: {}", e);
+                eprintln!("Timeline failed: {}", e);
+                std::process::exit(exit_code(e.as_ref()));
+            }
+        }
+        Some("doctor") => {
+            tracing::info!("doctor command");
+            if !run_doctor().await {
+                std::process::exit(EXIT_FAILURE);
+            }
+        }
         Some("logout") => {
             tracing::info!("logout command");
             if let Err(e) = run_logout() {
                 tracing::error!("Logout failed: {}", e);
                 eprintln!("Logout failed: {}", e);
-                std::process::exit(1);
+                std::process::exit(exit_code(e.as_ref()));
             }
         }
         Some(cmd) => {
@@ -90,19 +184,239 @@ async fn main() {
             std::process::exit(1);
         }
         None => {
-            if let Err(e) = run_tui().await {
+            if let Err(e) = run_tui(read_only_flag).await {
                 tracing::error!("TUI error: {}", e);
                 eprintln!("Error: {}", e);
-                std::process::exit(1);
+                std::process::exit(exit_code(e.as_ref()));
             }
         }
     }
 }
 
+/// Exit codes for failed commands, so scripts can tell an expired login
+/// from a dropped connection (usage errors exit with `EXIT_FAILURE`)
+const EXIT_FAILURE: i32 = 1;
+const EXIT_AUTH: i32 = 2;
+const EXIT_NETWORK: i32 = 3;
+const EXIT_NOT_CONFIGURED: i32 = 4;
+
+/// The exit code for a command's error, from the typed error underneath
+fn exit_code(e: &(dyn std::error::Error + 'static)) -> i32 {
+    if let Some(e) = e.downcast_ref::<PlatformError>() {
+        match e {
+            PlatformError::Auth(_) => EXIT_AUTH,
+            PlatformError::Request(_) => EXIT_NETWORK,
+            PlatformError::NotConfigured(_) => EXIT_NOT_CONFIGURED,
+            _ => EXIT_FAILURE,
+        }
+    } else if let Some(e) = e.downcast_ref::<api::ApiError>() {
+        match e {
+            e if e.is_auth() => EXIT_AUTH,
+            api::ApiError::Request(_) => EXIT_NETWORK,
+            _ => EXIT_FAILURE,
+        }
+    } else if let Some(e) = e.downcast_ref::<oauth::OAuthError>() {
+        match e {
+            oauth::OAuthError::AuthorizationDenied(_) | oauth::OAuthError::TokenExchange(_) => {
+                EXIT_AUTH
+            }
+            oauth::OAuthError::ServerBusy(_) => EXIT_NETWORK,
+            _ => EXIT_FAILURE,
+        }
+    } else if e.is::<reqwest::Error>() {
+        EXIT_NETWORK
+    } else {
+        EXIT_FAILURE
+    }
+}
+
 const DEFAULT_OAUTH_ENDPOINT: &str = "https://ndl.pgray.dev";
+const READ_ONLY_FLAG: &str = "--read-only";
+const ALLOW_MAIN_PASSWORD_FLAG: &str = "--allow-main-password";
+const NO_BROWSER_FLAG: &str = "--no-browser";
+const PASTE_CODE_FLAG: &str = "--paste-code";
+const SINCE_FLAG: &str = "--since";
+
+/// Posts `timeline` prints without `--since`
+const TIMELINE_FIRST_PAGE: u32 = 25;
+/// Page size when paging back to `--since`; Threads serves at most 100 a page
+const TIMELINE_PAGE: u32 = 100;
+/// Posts `timeline` fetches per platform before giving up on reaching `--since`
+const TIMELINE_MAX_POSTS: usize = 1000;
+
+/// Read `--since <value>` (or `--since=<value>`) from the timeline args
+fn timeline_since(args: &[String]) -> Result<Option<DateTime<FixedOffset>>, String> {
+    let value = match args {
+        [] => return Ok(None),
+        [flag, value] if flag == SINCE_FLAG => value.as_str(),
+        [flag] if flag == SINCE_FLAG => return Err(format!("{} needs a value", SINCE_FLAG)),
+        [arg] => match arg
+            .strip_prefix(SINCE_FLAG)
+            .and_then(|v| v.strip_prefix('='))
+        {
+            Some(value) => value,
+            None => return Err(format!("Unknown timeline option: {}", arg)),
+        },
+        _ => return Err(format!("Unknown timeline options: {}", args.join(" "))),
+    };
+    parse_since(value, chrono::Utc::now()).map(Some)
+}
+
+/// Print each configured platform's posts, newest first, for scripts and cron
+async fn run_timeline(
+    since: Option<DateTime<FixedOffset>>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let config = Config::load()?;
+    let platforms = [
+        (Platform::Threads, config.has_threads()),
+        (Platform::Bluesky, config.has_bluesky()),
+    ];
+    if !platforms.iter().any(|(_, configured)| *configured) {
+        let message = if threads_login_pending(&config) {
+            "Threads client credentials are set, but you haven't logged in. Run 'ndl login threads'."
+        } else {
+            "No platforms configured. Run 'ndl login' or 'ndl login bluesky'."
+        };
+        return Err(PlatformError::NotConfigured(message.to_string()).into());
+    }
+
+    for (platform, _) in platforms.iter().filter(|(_, configured)| *configured) {
+        let client = connect::platform(*platform, &config).await?;
+        let (posts, capped) = fetch_since(client.as_ref(), since).await?;
+        if capped {
+            eprintln!(
+                "Warning: stopped after {} {} posts without reaching --since; older posts are missing",
+                TIMELINE_MAX_POSTS, platform
+            );
+        }
+        for post in posts {
+            println!(
+                "{}\t{}\t@{}\t{}",
+                post.timestamp.as_deref().unwrap_or("-"),
+                platform,
+                post.author_handle.as_deref().unwrap_or("unknown"),
+                post.text
+                    .as_deref()
+                    .unwrap_or("")
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Fetch posts newer than `since`, a page at a time until a page reaches
+/// past it, the feed runs out, or `TIMELINE_MAX_POSTS` have been fetched.
+/// Posts without a readable timestamp are left out when filtering. Also
+/// returns whether the cap cut the results short.
+async fn fetch_since(
+    client: &dyn SocialClient,
+    since: Option<DateTime<FixedOffset>>,
+) -> Result<(Vec<Post>, bool), PlatformError> {
+    let Some(since) = since else {
+        return Ok((client.get_posts(Some(TIMELINE_FIRST_PAGE)).await?, false));
+    };
+    let time_of = |post: &Post| post.timestamp.as_deref().and_then(parse_timestamp);
+
+    let mut posts = Vec::new();
+    let mut fetched = 0;
+    let mut cursor: Option<String> = None;
+    loop {
+        let (page, next) = client
+            .get_posts_paginated(Some(TIMELINE_PAGE), cursor.as_deref())
+            .await?;
+        fetched += page.len();
+        let reached = page.iter().any(|p| time_of(p).is_some_and(|t| t < since));
+        let done = reached || page.is_empty() || next.is_none();
+        posts.extend(
+            page.into_iter()
+                .filter(|p| time_of(p).is_some_and(|t| t >= since)),
+        );
+        if done {
+            return Ok((posts, false));
+        }
+        if fetched >= TIMELINE_MAX_POSTS {
+            return Ok((posts, true));
+        }
+        cursor = next;
+    }
+}
+
+fn login_options(no_browser: bool, paste_code: bool) -> oauth::LoginOptions {
+    oauth::LoginOptions {
+        open_browser: !(no_browser || oauth::is_headless()),
+        paste_code,
+        redirect_uri: env::var("NDL_REDIRECT_URI").ok().filter(|u| !u.is_empty()),
+    }
+}
+
+/// Threads client credentials are set (in the config or the environment)
+/// but there's no token yet, e.g. on first run before `ndl login`
+fn threads_login_pending(config: &Config) -> bool {
+    let env_credentials = ["NDL_CLIENT_ID", "NDL_CLIENT_SECRET"]
+        .iter()
+        .all(|var| env::var(var).is_ok_and(|v| !v.is_empty()));
+    let file_credentials = ClientCredentials::from_env()
+        .is_ok_and(|c| c.client_id.is_some() && c.client_secret.is_some());
+    config.needs_threads_login() || ((env_credentials || file_credentials) && !config.has_threads())
+}
+
+/// Offer to finish a half-done Threads setup before starting the TUI. Without
+/// a terminal to ask on, print the command instead. Returns whether login ran.
+async fn offer_threads_login(read_only_flag: bool) -> Result<bool, Box<dyn std::error::Error>> {
+    use std::io::{IsTerminal, Write};
 
-async fn run_login() -> Result<(), Box<dyn std::error::Error>> {
+    println!("Threads client credentials are set, but you haven't logged in yet.");
+    if !std::io::stdin().is_terminal() {
+        println!("Run 'ndl login threads' to finish setting up Threads.");
+        return Ok(false);
+    }
+    print!("Log in to Threads now? [Y/n] ");
+    std::io::stdout().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    if !matches!(answer.trim().to_lowercase().as_str(), "" | "y" | "yes") {
+        println!("Skipping. Run 'ndl login threads' when you're ready.");
+        return Ok(false);
+    }
+    run_login(read_only_flag, login_options(false, false)).await?;
+    Ok(true)
+}
+
+/// The hosted auth server to use: env var > config > default. Empty means
+/// "use local OAuth".
+fn auth_server(config: &Config) -> String {
+    env::var("NDL_OAUTH_ENDPOINT")
+        .ok()
+        .or_else(|| config.auth_server.clone())
+        .unwrap_or_else(|| DEFAULT_OAUTH_ENDPOINT.to_string())
+}
+
+/// Check the config, each platform's login, and the auth server, printing a
+/// report. Returns whether every critical check passed.
+async fn run_doctor() -> bool {
+    let config = Config::load().unwrap_or_default();
+    let checks = doctor::run(&auth_server(&config)).await;
+    print!("{}", doctor::report(&checks));
+    !checks.iter().any(|c| c.status == doctor::Status::Fail)
+}
+
+async fn run_login(
+    read_only_flag: bool,
+    mut options: oauth::LoginOptions,
+) -> Result<(), Box<dyn std::error::Error>> {
     let mut config = Config::load()?;
+    if options.redirect_uri.is_none() {
+        options.redirect_uri = config.redirect_uri.clone();
+    }
+    let read_only = read_only_flag || config.read_only;
+    if read_only {
+        println!("Read-only mode: requesting read scopes only (no posting or replying)");
+    }
+    let scopes = ndl_core::oauth_scopes(config.scopes.as_deref(), read_only);
+    tracing::info!("Requesting scopes: {}", scopes);
 
     // Preserve existing Bluesky config
     let existing_bluesky = config.bluesky.clone();
@@ -112,37 +426,48 @@ async fn run_login() -> Result<(), Box<dyn std::error::Error>> {
         config.has_threads()
     );
 
-    // Determine auth server: env var > config > default
-    // Empty string means "use local OAuth"
-    let auth_server = env::var("NDL_OAUTH_ENDPOINT")
-        .ok()
-        .or_else(|| config.auth_server.clone())
-        .unwrap_or_else(|| DEFAULT_OAUTH_ENDPOINT.to_string());
+    let auth_server = auth_server(&config);
 
     let token = if !auth_server.is_empty() {
         // Use hosted auth server
         tracing::info!("Using hosted auth server: {}", auth_server);
-        oauth::hosted_login(&auth_server).await?
+        if options.paste_code {
+            println!("The auth server receives the callback itself; no code to paste.");
+        }
+        oauth::hosted_login(&auth_server, &scopes, options).await?
     } else {
         // Fall back to local OAuth flow
         tracing::info!("Using local OAuth flow");
+        // Config, then the credentials file, then the environment
+        let credentials = ClientCredentials::from_env()?;
         let client_id = config
             .client_id
             .clone()
+            .or_else(|| credentials.client_id.clone())
             .or_else(|| env::var("NDL_CLIENT_ID").ok())
-            .ok_or("Missing client_id. Set NDL_CLIENT_ID or add to config.")?;
+            .ok_or(
+                "Missing client_id. Set NDL_CLIENT_ID or NDL_CREDENTIALS_FILE, or add to config.",
+            )?;
 
         let client_secret = config
             .client_secret
             .clone()
+            .or_else(|| credentials.client_secret.clone())
             .or_else(|| env::var("NDL_CLIENT_SECRET").ok())
-            .ok_or("Missing client_secret. Set NDL_CLIENT_SECRET or add to config.")?;
-
-        // Save credentials to config for future use
-        config.client_id = Some(client_id.clone());
-        config.client_secret = Some(client_secret.clone());
+            .ok_or(
+                "Missing client_secret. Set NDL_CLIENT_SECRET or NDL_CREDENTIALS_FILE, or add to config.",
+            )?;
+
+        // Save credentials from the environment to config for future use;
+        // ones from the credentials file stay there
+        if credentials.client_id.is_none() {
+            config.client_id = Some(client_id.clone());
+        }
+        if credentials.client_secret.is_none() {
+            config.client_secret = Some(client_secret.clone());
+        }
 
-        oauth::login(&client_id, &client_secret).await?
+        oauth::login(&client_id, &client_secret, &scopes, options).await?
     };
 
     // Save token to config
@@ -180,20 +505,140 @@ fn run_logout() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-fn print_version() {
+/// Environment variables that change which account or server ndl uses.
+/// Values of the secret ones are never printed.
+const ENV_OVERRIDES: &[(&str, bool)] = &[
+    ("NDL_OAUTH_ENDPOINT", false),
+    ("NDL_CLIENT_ID", false),
+    ("NDL_CLIENT_SECRET", true),
+    (config::CREDENTIALS_FILE_ENV, false),
+    ("NDL_REDIRECT_URI", false),
+    (ndl_core::SCOPES_ENV, false),
+    // Proxy URLs can carry credentials
+    (ndl_core::PROXY_ENV, true),
+    (ndl_core::CA_BUNDLE_ENV, false),
+    (BSKY_STREAM_ENV, false),
+];
+
+/// Set to `1` to follow Bluesky through Jetstream instead of polling
+const BSKY_STREAM_ENV: &str = "NDL_BSKY_STREAM";
+
+/// Print the effective config with secrets redacted, for bug reports
+fn run_config_show() -> Result<(), Box<dyn std::error::Error>> {
+    let path = Config::path()?;
+    println!("config: {}", path.display());
+    if !path.exists() {
+        println!("(file does not exist; showing defaults)");
+    }
+
+    let config = Config::load()?;
+    println!("{}", serde_json::to_string_pretty(&config.redacted()?)?);
+
+    println!();
+    println!("environment overrides:");
+    let mut any = false;
+    for (name, secret) in ENV_OVERRIDES {
+        if let Ok(value) = env::var(name) {
+            any = true;
+            if *secret {
+                println!("  {} (set)", name);
+            } else {
+                println!("  {}={:?}", name, value);
+            }
+        }
+    }
+    if !any {
+        println!("  none");
+    }
+    Ok(())
+}
+
+fn print_version(verbose: bool) {
     const VERSION: &str = env!("CARGO_PKG_VERSION");
     const GIT_VERSION: &str = env!("NDL_GIT_VERSION");
+    const RUSTC_VERSION: &str = env!("NDL_RUSTC_VERSION");
+    const TARGET: &str = env!("NDL_TARGET");
     println!("ndl {} ({})", VERSION, GIT_VERSION);
+
+    if !verbose {
+        return;
+    }
+
+    println!("target:    {}", TARGET);
+    println!("rustc:     {}", RUSTC_VERSION);
+
+    match Config::path() {
+        Ok(path) => println!("config:    {}", path.display()),
+        Err(e) => println!("config:    unavailable ({})", e),
+    }
+
+    let config = Config::load().unwrap_or_default();
+    let mut platforms = Vec::new();
+    if config.has_threads() {
+        platforms.push(Platform::Threads.to_string());
+    }
+    if config.has_bluesky() {
+        platforms.push(Platform::Bluesky.to_string());
+    }
+    if platforms.is_empty() {
+        println!("platforms: none configured");
+    } else {
+        println!("platforms: {}", platforms.join(", "));
+    }
 }
 
-async fn run_bluesky_login() -> Result<(), Box<dyn std::error::Error>> {
+/// Read a line from the terminal without echoing it, so passwords stay out of
+/// the screen and scrollback. Falls back to a plain read when stdin isn't a
+/// terminal (e.g. piped input).
+fn read_password() -> std::io::Result<String> {
+    use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+    use crossterm::terminal;
+    use std::io::IsTerminal;
+
+    if !std::io::stdin().is_terminal() {
+        let mut line = String::new();
+        std::io::stdin().read_line(&mut line)?;
+        return Ok(line);
+    }
+
+    terminal::enable_raw_mode()?;
+    let mut password = String::new();
+    let result = loop {
+        match event::read() {
+            Ok(Event::Key(key)) if key.kind != KeyEventKind::Release => match key.code {
+                KeyCode::Enter => break Ok(()),
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    break Err(std::io::Error::new(
+                        std::io::ErrorKind::Interrupted,
+                        "Login cancelled",
+                    ));
+                }
+                KeyCode::Backspace => {
+                    password.pop();
+                }
+                KeyCode::Char(c) => password.push(c),
+                _ => {}
+            },
+            // Pasting into a terminal with bracketed paste enabled
+            Ok(Event::Paste(text)) => password.push_str(&text),
+            Ok(_) => {}
+            Err(e) => break Err(e),
+        }
+    };
+    terminal::disable_raw_mode()?;
+    println!();
+
+    result.map(|()| password)
+}
+
+async fn run_bluesky_login(allow_main_password: bool) -> Result<(), Box<dyn std::error::Error>> {
     use std::io::{self, Write};
 
     println!("Bluesky Login");
     println!("=============");
     println!();
     println!("You can use your handle (e.g., user.bsky.social) or email as identifier.");
-    println!("For enhanced security, consider using an app-specific password:");
+    println!("ndl stores an app password, not your main password. Create one at:");
     println!("https://bsky.app/settings/app-passwords");
     println!();
 
@@ -208,17 +653,54 @@ async fn run_bluesky_login() -> Result<(), Box<dyn std::error::Error>> {
         return Err("Identifier cannot be empty".into());
     }
 
-    // Prompt for password
-    print!("Password (or app password): ");
+    // Catch typos before the password prompt and the login round-trip
+    let identifier = bluesky::parse_identifier(&identifier).ok_or_else(|| {
+        format!(
+            "'{}' doesn't look like a handle (e.g. user.bsky.social) or email",
+            identifier
+        )
+    })?;
+    if let bluesky::Identifier::Handle(handle) = &identifier {
+        match bluesky::handle_exists(handle).await {
+            Ok(true) => {}
+            Ok(false) => return Err(format!("No Bluesky account found for @{}", handle).into()),
+            // Lookup trouble isn't a reason to refuse; the login will tell
+            Err(e) => tracing::warn!("Couldn't check handle {}: {}", handle, e),
+        }
+    }
+    let identifier = identifier.as_str().to_string();
+
+    // Prompt for password without echoing it
+    print!("App password: ");
     io::stdout().flush()?;
-    let mut password = String::new();
-    io::stdin().read_line(&mut password)?;
-    let password = password.trim().to_string();
+    let password = read_password()?.trim().to_string();
 
     if password.is_empty() {
         return Err("Password cannot be empty".into());
     }
 
+    // The password is stored in the config, so only keep revocable app passwords
+    // unless the user insists
+    if !bluesky::is_app_password(&password) {
+        if !allow_main_password {
+            return Err(format!(
+                "That looks like your main account password, which ndl would store in the config.\n\
+                 Create an app password at https://bsky.app/settings/app-passwords,\n\
+                 or run 'ndl login bluesky {}' to use it anyway.",
+                ALLOW_MAIN_PASSWORD_FLAG
+            )
+            .into());
+        }
+        println!(
+            "Warning: storing your main account password in {}",
+            Config::path()?.display()
+        );
+        tracing::warn!(
+            "Storing a main Bluesky password ({} given)",
+            ALLOW_MAIN_PASSWORD_FLAG
+        );
+    }
+
     // Test login
     println!();
     println!("Authenticating...");
@@ -262,12 +744,15 @@ async fn run_bluesky_login() -> Result<(), Box<dyn std::error::Error>> {
             println!("You can now use ndl with Bluesky!");
             Ok(())
         }
-        Err(e) => Err(format!("Authentication failed: {}", e).into()),
+        Err(e) => Err(e.into()),
     }
 }
 
-async fn run_tui() -> Result<(), Box<dyn std::error::Error>> {
+async fn run_tui(read_only_flag: bool) -> Result<(), Box<dyn std::error::Error>> {
     let mut config = Config::load()?;
+    if threads_login_pending(&config) && offer_threads_login(read_only_flag).await? {
+        config = Config::load()?;
+    }
 
     let mut clients: HashMap<Platform, Box<dyn SocialClient>> = HashMap::new();
 
@@ -307,15 +792,15 @@ async fn run_tui() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize Threads if configured
     if config.has_threads() {
         let token = config.access_token.clone().unwrap();
-        let client = ThreadsClient::new(token.clone());
+        let fields = connect::threads_fields(&config);
 
         // Verify token is still valid
-        match client.get_threads(Some(1)).await {
-            Ok(_) => {
+        match connect::threads(token.clone(), fields.clone()).await {
+            Ok(client) => {
                 tracing::debug!("Threads token is valid");
-                clients.insert(Platform::Threads, Box::new(ThreadsClient::new(token)));
+                clients.insert(Platform::Threads, Box::new(client));
             }
-            Err(e) if is_auth_error(&e.to_string()) => {
+            Err(e) if e.is_auth() => {
                 tracing::warn!("Threads token expired, skipping");
                 eprintln!(
                     "Warning: Threads token expired. Run 'ndl login threads' to re-authenticate."
@@ -325,49 +810,19 @@ async fn run_tui() -> Result<(), Box<dyn std::error::Error>> {
                 tracing::error!("Failed to connect to Threads: {}", e);
                 eprintln!("Warning: Failed to connect to Threads: {}", e);
                 // Still add the client - TUI will retry
-                clients.insert(Platform::Threads, Box::new(ThreadsClient::new(token)));
+                let client = ThreadsClient::new(token).with_fields(fields);
+                clients.insert(Platform::Threads, Box::new(client));
             }
         }
     }
 
     // Initialize Bluesky if configured
     if config.has_bluesky() {
-        let mut bsky_config = config.bluesky.clone().unwrap();
-
-        // Try to use saved session first
-        let client_result = if let Some(ref session) = bsky_config.session {
-            tracing::debug!("Attempting to restore Bluesky session");
-            match BlueskyClient::from_session(session.clone()).await {
-                Ok(client) => {
-                    tracing::info!("Successfully restored Bluesky session");
-                    Ok(client)
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to restore session, will re-authenticate: {}", e);
-                    // Fall back to login
-                    BlueskyClient::login(&bsky_config.identifier, &bsky_config.password).await
-                }
-            }
-        } else {
-            // No session saved, login normally
-            tracing::debug!("No saved session, logging in to Bluesky");
-            BlueskyClient::login(&bsky_config.identifier, &bsky_config.password).await
-        };
+        let bsky_config = config.bluesky.clone().unwrap();
 
-        match client_result {
+        match connect::bluesky(bsky_config).await {
             Ok(client) => {
                 tracing::info!("Successfully connected to Bluesky");
-
-                // Update session in config for next time
-                if let Ok(new_session) = client.get_session().await
-                    && bsky_config.session.as_ref() != Some(&new_session)
-                {
-                    bsky_config.session = Some(new_session);
-                    let mut config_mut = Config::load().unwrap_or_default();
-                    config_mut.bluesky = Some(bsky_config);
-                    let _ = config_mut.save(); // Best effort, don't fail if this errors
-                }
-
                 clients.insert(Platform::Bluesky, Box::new(client));
             }
             Err(e) => {
@@ -386,13 +841,24 @@ async fn run_tui() -> Result<(), Box<dyn std::error::Error>> {
             eprintln!("  ndl login bluesky  - Login to Bluesky");
             return Ok(());
         }
-        eprintln!("Failed to connect to any platform.");
-        return Ok(());
+        // Start anyway: the TUI offers reconnect and login from its empty state
+        tracing::warn!("Failed to connect to any platform");
     }
 
     // Create and run the app
     tracing::info!("Starting TUI with {} platform(s)", clients.len());
-    let mut app = App::new(clients);
+    let mut app_config = AppConfig::from_config(&config);
+    app_config.read_only |= read_only_flag;
+    app_config.stream_bluesky = env::var(BSKY_STREAM_ENV).is_ok_and(|v| v == "1");
+    let mut app = App::with_config(clients, app_config);
+    app.post_cache = Some(cache::PostCache::load().unwrap_or_else(|e| {
+        tracing::warn!("Failed to load post cache: {}", e);
+        cache::PostCache::default()
+    }));
+    app.ui_state = Some(ui_state::UiState::load().unwrap_or_else(|e| {
+        tracing::warn!("Failed to load UI state: {}", e);
+        ui_state::UiState::default()
+    }));
     app.run().await?;
     tracing::info!("TUI exited");
     Ok(())
@@ -404,23 +870,137 @@ fn print_usage() {
     println!("Commands:");
     println!("  login [platform]  Authenticate (platforms: threads, bluesky)");
     println!("  logout            Remove saved access token");
-    println!("  --version         Show version information");
+    println!("  timeline          Print recent posts (add --since 1h to limit by age)");
+    println!("  config show       Print the config with secrets redacted");
+    println!("  doctor            Check the config, logins, and auth server");
+    println!("  version           Show version, build, and config information");
+    println!("  --version         Show version information (add --verbose for details)");
+    println!();
+    println!("Options:");
+    println!("  --read-only       Browse without posting (login requests read scopes only)");
+    println!(
+        "  --allow-main-password  Let 'login bluesky' store the main password, not an app password"
+    );
+    println!("  --no-browser      Print the login URL instead of opening a browser");
+    println!(
+        "  --paste-code      Local OAuth: paste the redirect URL instead of running a callback server"
+    );
     println!();
     println!("Examples:");
     println!("  ndl login         - Login to Threads (default)");
     println!("  ndl login bluesky - Login to Bluesky");
+    println!("  ndl timeline --since 1h - Posts from the last hour");
     println!();
     println!("Run without arguments to start the TUI.");
 }
 
-/// Check if an API error indicates an authentication problem
-fn is_auth_error(error: &str) -> bool {
-    let error_lower = error.to_lowercase();
-    error_lower.contains("oauthexception")
-        || error_lower.contains("invalid access token")
-        || error_lower.contains("session has expired")
-        || error_lower.contains("token has expired")
-        || error_lower.contains("requires the threads_")  // permission errors
-        || error.contains("\"code\":190")  // Facebook/Meta invalid token code
-        || error.contains("\"code\":102") // Session expired
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::ReplyThread;
+    use async_trait::async_trait;
+
+    /// Feed of one post a minute going back from `NOW`, served at most 100 a
+    /// page like the Threads Graph API
+    struct PagedClient(usize);
+
+    const NOW: &str = "2026-01-01T12:00:00+00:00";
+
+    fn minutes_ago(minutes: usize) -> DateTime<FixedOffset> {
+        parse_timestamp(NOW).unwrap() - chrono::Duration::minutes(minutes as i64)
+    }
+
+    #[async_trait]
+    impl SocialClient for PagedClient {
+        async fn get_posts(&self, limit: Option<u32>) -> Result<Vec<Post>, PlatformError> {
+            Ok(self.get_posts_paginated(limit, None).await?.0)
+        }
+
+        async fn get_posts_paginated(
+            &self,
+            limit: Option<u32>,
+            cursor: Option<&str>,
+        ) -> Result<(Vec<Post>, Option<String>), PlatformError> {
+            let start: usize = cursor.map_or(0, |c| c.parse().unwrap());
+            let end = (start + limit.unwrap_or(25).min(100) as usize).min(self.0);
+            let posts = (start..end)
+                .map(|i| Post {
+                    id: i.to_string(),
+                    timestamp: Some(minutes_ago(i).to_rfc3339()),
+                    ..Default::default()
+                })
+                .collect();
+            Ok((posts, (end < self.0).then(|| end.to_string())))
+        }
+
+        async fn get_post_replies(
+            &self,
+            _post_id: &str,
+            _depth: u8,
+        ) -> Result<Vec<ReplyThread>, PlatformError> {
+            Ok(Vec::new())
+        }
+
+        async fn create_post(&self, _text: &str) -> Result<String, PlatformError> {
+            Ok(String::new())
+        }
+
+        async fn reply_to_post(
+            &self,
+            _post_id: &str,
+            _text: &str,
+        ) -> Result<String, PlatformError> {
+            Ok(String::new())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_since_pages_past_the_page_cap() {
+        let client = PagedClient(500);
+        let (posts, capped) = fetch_since(&client, Some(minutes_ago(249))).await.unwrap();
+        assert_eq!(posts.len(), 250);
+        assert!(!capped);
+
+        let (posts, capped) = fetch_since(&client, Some(minutes_ago(10_000)))
+            .await
+            .unwrap();
+        assert_eq!(posts.len(), 500);
+        assert!(!capped);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_since_reports_the_cap() {
+        let client = PagedClient(TIMELINE_MAX_POSTS * 2);
+        let (posts, capped) = fetch_since(&client, Some(minutes_ago(10_000)))
+            .await
+            .unwrap();
+        assert_eq!(posts.len(), TIMELINE_MAX_POSTS);
+        assert!(capped);
+    }
+
+    #[test]
+    fn test_exit_codes() {
+        let code = |e: Box<dyn std::error::Error>| exit_code(e.as_ref());
+        assert_eq!(
+            code(PlatformError::Auth("expired".into()).into()),
+            EXIT_AUTH
+        );
+        assert_eq!(
+            code(PlatformError::Request("timed out".into()).into()),
+            EXIT_NETWORK
+        );
+        assert_eq!(
+            code(PlatformError::NotConfigured("run 'ndl login'".into()).into()),
+            EXIT_NOT_CONFIGURED
+        );
+        assert_eq!(
+            code(api::ApiError::SessionExpired("x".into()).into()),
+            EXIT_AUTH
+        );
+        assert_eq!(
+            code(oauth::OAuthError::ServerBusy(503).into()),
+            EXIT_NETWORK
+        );
+        assert_eq!(code("Identifier cannot be empty".into()), EXIT_FAILURE);
+    }
 }
@@ -4,14 +4,104 @@ use rcgen::{CertifiedKey, generate_simple_self_signed};
 use serde::Deserialize;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use tokio::sync::oneshot;
 
-use ndl_core::OAUTH_SCOPES;
 pub use ndl_core::TokenResponse;
 
 const OAUTH_PORT: u16 = 1337;
 
+/// How the auth URL reaches the browser and how the code comes back
+#[derive(Debug, Clone)]
+pub struct LoginOptions {
+    /// Open the auth URL automatically (off with --no-browser or on headless machines)
+    pub open_browser: bool,
+    /// Read the code from a pasted redirect URL instead of running the callback server
+    pub paste_code: bool,
+    /// A registered redirect URI other than `https://localhost:1337/callback`
+    /// (`NDL_REDIRECT_URI` or the `redirect_uri` config key). A different
+    /// localhost port or path moves the callback server there; any other
+    /// address needs the code pasted, e.g. from a page that displays it.
+    pub redirect_uri: Option<String>,
+}
+
+/// Whether there's likely no local browser to open: an SSH session, or (on
+/// Linux/BSD) no X11 or Wayland display
+pub fn is_headless() -> bool {
+    let set = |var| std::env::var_os(var).is_some_and(|v| !v.is_empty());
+    if set("SSH_CONNECTION") || set("SSH_TTY") {
+        return true;
+    }
+    cfg!(all(unix, not(target_os = "macos"))) && !set("DISPLAY") && !set("WAYLAND_DISPLAY")
+}
+
+/// Print the auth URL and, if allowed, try to open it in a browser
+fn show_auth_url(auth_url: &str, open_browser: bool) {
+    if !open_browser {
+        println!("Open this URL in a browser to authorize ndl:\n{}", auth_url);
+        return;
+    }
+
+    println!("Opening browser for authorization...");
+    println!("If it doesn't open, visit:\n{}", auth_url);
+
+    // Don't fail if it doesn't work - user can visit URL manually
+    if let Err(e) = open::that(auth_url) {
+        eprintln!("Could not open browser automatically: {}", e);
+    }
+}
+
+/// Pull the authorization code out of a pasted redirect URL
+/// (`https://localhost:1337/callback?code=...#_`) or a bare code
+pub fn parse_pasted_code(input: &str) -> Result<String, OAuthError> {
+    let input = input.trim();
+    let Ok(url) = reqwest::Url::parse(input) else {
+        // Meta appends `#_` to the redirect; tolerate it on a bare code too
+        let code = input.trim_end_matches("#_");
+        if code.is_empty() {
+            return Err(OAuthError::InvalidPastedCode);
+        }
+        return Ok(code.to_string());
+    };
+
+    let mut code = None;
+    let mut error = None;
+    for (key, value) in url.query_pairs() {
+        match key.as_ref() {
+            "code" => code = Some(value.into_owned()),
+            "error_description" => error = Some(value.into_owned()),
+            "error" if error.is_none() => error = Some(value.into_owned()),
+            _ => {}
+        }
+    }
+    match (code, error) {
+        (Some(code), _) if !code.is_empty() => Ok(code),
+        (_, Some(error)) => Err(OAuthError::AuthorizationDenied(error)),
+        _ => Err(OAuthError::InvalidPastedCode),
+    }
+}
+
+/// Prompt for the redirect URL the browser ended up on
+fn read_pasted_code(redirect_uri: &str) -> Result<String, OAuthError> {
+    println!();
+    println!(
+        "After authorizing, your browser is sent to {}",
+        redirect_uri
+    );
+    println!(
+        "(it may fail to load). Copy the full URL from the address bar, or the code, and paste it here."
+    );
+    print!("Redirect URL or code: ");
+    std::io::Write::flush(&mut std::io::stdout()).map_err(|_| OAuthError::Cancelled)?;
+
+    let mut input = String::new();
+    match std::io::stdin().read_line(&mut input) {
+        Ok(0) | Err(_) => Err(OAuthError::Cancelled),
+        Ok(_) => parse_pasted_code(&input),
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CallbackParams {
     pub code: Option<String>,
@@ -23,24 +113,21 @@ pub struct OAuthConfig {
     pub client_id: String,
     pub client_secret: String,
     pub redirect_uri: String,
+    pub scopes: String,
 }
 
 impl OAuthConfig {
-    pub fn new(client_id: String, client_secret: String) -> Self {
+    pub fn new(client_id: String, client_secret: String, scopes: String) -> Self {
         Self {
             client_id,
             client_secret,
             redirect_uri: format!("https://localhost:{}/callback", OAUTH_PORT),
+            scopes,
         }
     }
 
     pub fn authorization_url(&self) -> String {
-        format!(
-            "https://threads.net/oauth/authorize?client_id={}&redirect_uri={}&scope={}&response_type=code",
-            self.client_id,
-            urlencoding::encode(&self.redirect_uri),
-            OAUTH_SCOPES
-        )
+        ndl_core::authorization_url(&self.client_id, &self.redirect_uri, &self.scopes, None)
     }
 
     /// Exchange an authorization code for an access token, then upgrade to long-lived
@@ -68,33 +155,66 @@ pub fn generate_localhost_cert() -> Result<CertifiedKey, rcgen::Error> {
     generate_simple_self_signed(subject_alt_names)
 }
 
-/// Start the OAuth callback server and wait for the authorization code
-pub async fn wait_for_callback() -> Result<String, OAuthError> {
-    let (tx, rx) = oneshot::channel::<Result<String, OAuthError>>();
+/// The port and path the callback server must listen on to receive
+/// `redirect_uri`, if it's an https address on this machine
+fn local_callback(redirect_uri: &str) -> Option<(u16, String)> {
+    let url = reqwest::Url::parse(redirect_uri).ok()?;
+    if url.scheme() != "https" || !matches!(url.host_str(), Some("localhost" | "127.0.0.1")) {
+        return None;
+    }
+    Some((url.port_or_known_default()?, url.path().to_string()))
+}
+
+/// Start the OAuth callback server on `port`, wait for the authorization
+/// code to arrive at `path`, and exchange it for a token. The browser's
+/// response waits for the exchange so the page can say which account
+/// logged in.
+pub async fn wait_for_callback(
+    config: Arc<OAuthConfig>,
+    port: u16,
+    path: &str,
+) -> Result<TokenResponse, OAuthError> {
+    let (tx, rx) = oneshot::channel::<Result<TokenResponse, OAuthError>>();
     let tx = Arc::new(std::sync::Mutex::new(Some(tx)));
 
     let tx_clone = Arc::clone(&tx);
     let app = Router::new()
         .route(
-            "/callback",
+            path,
             get(move |params: Query<CallbackParams>| {
                 let tx = Arc::clone(&tx_clone);
+                let config = Arc::clone(&config);
                 async move {
-                    let result = if let Some(code) = params.code.clone() {
-                        Ok(code)
-                    } else {
-                        Err(OAuthError::AuthorizationDenied(
+                    // A reload after the code was used: nothing left to exchange
+                    let Some(tx) = tx.lock().unwrap().take() else {
+                        return Html(callback_html(Ok(None)));
+                    };
+
+                    let result = match params.code.clone() {
+                        Some(code) => {
+                            println!("Exchanging code for access token...");
+                            config.exchange_code(&code).await
+                        }
+                        None => Err(OAuthError::AuthorizationDenied(
                             params.error_description.clone().unwrap_or_else(|| {
                                 params.error.clone().unwrap_or("Unknown error".to_string())
                             }),
-                        ))
+                        )),
                     };
 
-                    if let Some(tx) = tx.lock().unwrap().take() {
-                        let _ = tx.send(result);
-                    }
-
-                    Html(CALLBACK_HTML)
+                    let page = match &result {
+                        Ok(token) => {
+                            // Only for display; a failed lookup still logs in
+                            let username = ndl_core::fetch_username(&token.access_token)
+                                .await
+                                .inspect_err(|e| tracing::debug!("Username lookup failed: {}", e))
+                                .ok();
+                            callback_html(Ok(username.as_deref()))
+                        }
+                        Err(e) => callback_html(Err(&e.to_string())),
+                    };
+                    let _ = tx.send(result);
+                    Html(page)
                 }
             }),
         )
@@ -111,7 +231,7 @@ pub async fn wait_for_callback() -> Result<String, OAuthError> {
     .await
     .map_err(|e| OAuthError::TlsConfig(e.to_string()))?;
 
-    let addr = SocketAddr::from(([127, 0, 0, 1], OAUTH_PORT));
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
 
     // Spawn the server
     let server = axum_server::bind_rustls(addr, config).serve(app.into_make_service());
@@ -142,32 +262,67 @@ pub enum OAuthError {
     TokenExchange(String),
     #[error("Hosted auth error: {0}")]
     HostedAuth(String),
-    #[error("Auth session timeout")]
+    #[error("Timed out waiting for authorization in the browser")]
     SessionTimeout,
+    #[error("Auth server is busy (HTTP {0}); try again in a few minutes")]
+    ServerBusy(u16),
+    #[error("Login cancelled")]
+    Cancelled,
+    #[error("No authorization code found in the pasted text")]
+    InvalidPastedCode,
 }
 
 /// Run the complete OAuth login flow
-pub async fn login(client_id: &str, client_secret: &str) -> Result<TokenResponse, OAuthError> {
-    let config = OAuthConfig::new(client_id.to_string(), client_secret.to_string());
-    let auth_url = config.authorization_url();
+pub async fn login(
+    client_id: &str,
+    client_secret: &str,
+    scopes: &str,
+    options: LoginOptions,
+) -> Result<TokenResponse, OAuthError> {
+    let mut config = OAuthConfig::new(
+        client_id.to_string(),
+        client_secret.to_string(),
+        scopes.to_string(),
+    );
+    if let Some(redirect_uri) = options.redirect_uri {
+        // Must match the redirect URI in both the auth URL and the code exchange
+        config.redirect_uri = redirect_uri;
+    }
 
-    println!("Opening browser for authorization...");
-    println!("If it doesn't open, visit:\n{}", auth_url);
-    println!();
-    println!("Note: You may need to accept the self-signed certificate warning.");
+    // Listen wherever the redirect points, or ask for the code if that's
+    // somewhere the callback server can't be
+    let callback = if options.paste_code {
+        None
+    } else {
+        let callback = local_callback(&config.redirect_uri);
+        if callback.is_none() {
+            eprintln!(
+                "Warning: the callback server can't receive redirects to {} (only https://localhost addresses); paste the code instead.",
+                config.redirect_uri
+            );
+        }
+        callback
+    };
+    let auth_url = config.authorization_url();
 
-    // Open browser (don't fail if it doesn't work - user can visit URL manually)
-    if let Err(e) = open::that(&auth_url) {
-        eprintln!("Could not open browser automatically: {}", e);
-    }
+    show_auth_url(&auth_url, options.open_browser);
 
-    // Wait for callback
-    println!("Waiting for authorization...");
-    let code = wait_for_callback().await?;
+    let token = match callback {
+        // Skip the callback server entirely; nothing listens on localhost
+        None => {
+            let code = read_pasted_code(&config.redirect_uri)?;
+            println!("Exchanging code for access token...");
+            config.exchange_code(&code).await?
+        }
+        Some((port, path)) => {
+            println!();
+            println!("Note: You may need to accept the self-signed certificate warning.");
 
-    // Exchange code for token
-    println!("Exchanging code for access token...");
-    let token = config.exchange_code(&code).await?;
+            // Wait for callback; the server exchanges the code itself
+            println!("Waiting for authorization...");
+            wait_for_callback(Arc::new(config), port, &path).await?
+        }
+    };
 
     println!("Login successful!");
     Ok(token)
@@ -194,18 +349,64 @@ const CALLBACK_HTML: &str = r#"
             padding: 2rem;
         }
         h1 { color: #00d4aa; }
+        h1.failed { color: #ff4444; }
         p { color: #888; }
     </style>
 </head>
 <body>
     <div class="container">
-        <h1>Authorization Complete</h1>
-        <p>You can close this window and return to ndl.</p>
+        {{BODY}}
     </div>
+    {{SCRIPT}}
 </body>
 </html>
 "#;
 
+/// How long the success page stays up before trying to close its tab
+const CALLBACK_CLOSE_DELAY_MS: u32 = 3000;
+
+/// The page the browser lands on after the callback: the logged-in handle on
+/// success (`None` if the lookup failed), or the error. Success pages try to
+/// close the tab; browsers only allow that for script-opened tabs, so the
+/// text still says it's safe to close.
+fn callback_html(result: Result<Option<&str>, &str>) -> String {
+    let (body, script) = match result {
+        Ok(username) => {
+            let account = username
+                .map(|u| format!("<p>Logged in as <strong>@{}</strong>.</p>", escape_html(u)))
+                .unwrap_or_default();
+            (
+                format!(
+                    "<h1>Authorization Complete</h1>\n        {}\n        <p>You can close this window and return to ndl.</p>",
+                    account
+                ),
+                format!(
+                    "<script>setTimeout(() => window.close(), {});</script>",
+                    CALLBACK_CLOSE_DELAY_MS
+                ),
+            )
+        }
+        Err(error) => (
+            format!(
+                "<h1 class=\"failed\">Authorization Failed</h1>\n        <p>{}</p>\n        <p>Return to ndl for details.</p>",
+                escape_html(error)
+            ),
+            String::new(),
+        ),
+    };
+    CALLBACK_HTML
+        .replace("{{BODY}}", &body)
+        .replace("{{SCRIPT}}", &script)
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
 // ============================================================================
 // Hosted Auth Client (for use with ndld server)
 // ============================================================================
@@ -216,6 +417,9 @@ use serde::Serialize;
 pub struct StartAuthResponse {
     pub session_id: String,
     pub auth_url: String,
+    /// Seconds the server keeps the session; older servers don't say
+    #[serde(default)]
+    pub expires_in: Option<u64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -227,18 +431,24 @@ pub enum PollStatus {
 }
 
 #[derive(Debug, Serialize)]
-struct EmptyBody {}
+struct StartAuthRequest<'a> {
+    scopes: &'a str,
+}
 
 /// Run OAuth login flow using a hosted auth server
-pub async fn hosted_login(auth_server: &str) -> Result<TokenResponse, OAuthError> {
-    let client = reqwest::Client::new();
+pub async fn hosted_login(
+    auth_server: &str,
+    scopes: &str,
+    options: LoginOptions,
+) -> Result<TokenResponse, OAuthError> {
+    let client = ndl_core::http_client().map_err(|e| OAuthError::HostedAuth(e.to_string()))?;
 
     // Step 1: Start auth session
     println!("Connecting to auth server...");
     let start_url = format!("{}/auth/start", auth_server);
     let response = client
         .post(&start_url)
-        .json(&EmptyBody {})
+        .json(&StartAuthRequest { scopes })
         .send()
         .await
         .map_err(|e| OAuthError::HostedAuth(format!("Failed to start auth: {}", e)))?;
@@ -253,37 +463,99 @@ pub async fn hosted_login(auth_server: &str) -> Result<TokenResponse, OAuthError
         .await
         .map_err(|e| OAuthError::HostedAuth(format!("Invalid response: {}", e)))?;
 
-    // Step 2: Show auth URL to user
-    println!("Opening browser for authorization...");
-    println!("If it doesn't open, visit:\n{}", start_resp.auth_url);
-
-    // Open browser (don't fail if it doesn't work - user can visit URL manually)
-    if let Err(e) = open::that(&start_resp.auth_url) {
-        eprintln!("Could not open browser automatically: {}", e);
-    }
+    // Step 2: Show auth URL to user. The server receives the callback, so this
+    // works from any device without pasting anything back.
+    show_auth_url(&start_resp.auth_url, options.open_browser);
 
-    // Step 3: Poll for completion
+    // Step 3: Poll for completion (Ctrl+C abandons the session)
     println!("Waiting for authorization...");
     let poll_url = format!("{}/auth/poll/{}", auth_server, start_resp.session_id);
+    let timeout = start_resp
+        .expires_in
+        .map_or(DEFAULT_POLL_TIMEOUT, Duration::from_secs);
+
+    let result = tokio::select! {
+        result = poll_for_token(&client, &poll_url, timeout) => result,
+        _ = tokio::signal::ctrl_c() => Err(OAuthError::Cancelled),
+    };
+
+    // Let the server drop the session right away instead of waiting for its TTL
+    if matches!(
+        result,
+        Err(OAuthError::SessionTimeout)
+            | Err(OAuthError::ServerBusy(_))
+            | Err(OAuthError::Cancelled)
+    ) {
+        cancel_hosted_session(&client, auth_server, &start_resp.session_id).await;
+    }
+
+    result
+}
 
-    // Poll every 2 seconds for up to 5 minutes
-    for _ in 0..150 {
-        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+/// How often to poll the auth server for a finished login
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How long to wait for the user to authorize when the server doesn't say
+/// how long it keeps the session
+const DEFAULT_POLL_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Longest `Retry-After` honored, so a bad header can't stall the login
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// The delay a 429/503 response asks for, in seconds (HTTP dates aren't used by ndld)
+fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let secs: u64 = headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(secs).min(MAX_RETRY_AFTER))
+}
+
+/// Poll the auth server until the session completes, fails, or `timeout` passes
+async fn poll_for_token(
+    client: &reqwest::Client,
+    poll_url: &str,
+    timeout: Duration,
+) -> Result<TokenResponse, OAuthError> {
+    let deadline = Instant::now() + timeout;
+    let mut delay = POLL_INTERVAL;
+    // Last status the server answered with when it couldn't help; used to
+    // tell "server busy" apart from "user never authorized" on timeout
+    let mut busy_status = None;
+
+    while Instant::now() + delay < deadline {
+        tokio::time::sleep(delay).await;
+        delay = POLL_INTERVAL;
 
         let response = client
-            .get(&poll_url)
+            .get(poll_url)
             .send()
             .await
             .map_err(|e| OAuthError::HostedAuth(format!("Poll failed: {}", e)))?;
 
-        if response.status() == reqwest::StatusCode::NOT_FOUND {
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
             return Err(OAuthError::SessionTimeout);
         }
 
-        if !response.status().is_success() {
-            continue; // Retry on server errors
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
+            // Back off as asked instead of hammering a loaded server
+            if let Some(wait) = retry_after(response.headers()) {
+                tracing::debug!("Auth server returned {}, retrying in {:?}", status, wait);
+                delay = wait.max(POLL_INTERVAL);
+            }
+            busy_status = Some(status.as_u16());
+            continue;
         }
 
+        if !status.is_success() {
+            continue;
+        }
+        busy_status = None;
+
         let poll_resp: PollStatus = response
             .json()
             .await
@@ -306,5 +578,114 @@ pub async fn hosted_login(auth_server: &str) -> Result<TokenResponse, OAuthError
         }
     }
 
-    Err(OAuthError::SessionTimeout)
+    Err(match busy_status {
+        Some(status) => OAuthError::ServerBusy(status),
+        None => OAuthError::SessionTimeout,
+    })
+}
+
+/// Tell the auth server to discard an abandoned session (best effort)
+async fn cancel_hosted_session(client: &reqwest::Client, auth_server: &str, session_id: &str) {
+    let cancel_url = format!("{}/auth/cancel/{}", auth_server, session_id);
+    match client.post(&cancel_url).send().await {
+        Ok(response) => {
+            tracing::debug!(
+                "Cancelled auth session {}: {}",
+                session_id,
+                response.status()
+            );
+        }
+        Err(e) => {
+            tracing::debug!("Failed to cancel auth session {}: {}", session_id, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_auth_response_expiry() {
+        let resp: StartAuthResponse =
+            serde_json::from_str(r#"{"session_id":"s","auth_url":"u","expires_in":1800}"#).unwrap();
+        assert_eq!(resp.expires_in, Some(1800));
+
+        // Servers from before `expires_in` leave it out
+        let resp: StartAuthResponse =
+            serde_json::from_str(r#"{"session_id":"s","auth_url":"u"}"#).unwrap();
+        assert_eq!(resp.expires_in, None);
+    }
+
+    #[test]
+    fn test_retry_after() {
+        use reqwest::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(7)));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("3600"));
+        assert_eq!(retry_after(&headers), Some(MAX_RETRY_AFTER));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[test]
+    fn test_callback_html() {
+        let page = callback_html(Ok(Some("pgray")));
+        assert!(page.contains("Logged in as <strong>@pgray</strong>"));
+        assert!(page.contains("window.close()"));
+
+        let page = callback_html(Ok(None));
+        assert!(!page.contains("Logged in as"));
+        assert!(page.contains("You can close this window"));
+
+        let page = callback_html(Err("<script>bad</script>"));
+        assert!(page.contains("&lt;script&gt;bad&lt;/script&gt;"));
+        assert!(!page.contains("window.close()"));
+    }
+
+    #[test]
+    fn test_local_callback() {
+        assert_eq!(
+            local_callback("https://localhost:1337/callback"),
+            Some((OAUTH_PORT, "/callback".to_string()))
+        );
+        assert_eq!(
+            local_callback("https://127.0.0.1:8443/threads/done?x=1"),
+            Some((8443, "/threads/done".to_string()))
+        );
+        assert_eq!(
+            local_callback("https://localhost/cb"),
+            Some((443, "/cb".to_string()))
+        );
+        assert_eq!(local_callback("http://localhost:1337/callback"), None);
+        assert_eq!(local_callback("https://example.com/ndl-code"), None);
+        assert_eq!(local_callback("not a url"), None);
+    }
+
+    #[test]
+    fn test_parse_pasted_code() {
+        assert_eq!(
+            parse_pasted_code("https://localhost:1337/callback?code=AQBx%2D9#_\n").unwrap(),
+            "AQBx-9"
+        );
+        assert_eq!(parse_pasted_code("  AQBx9#_ ").unwrap(), "AQBx9");
+        assert!(matches!(
+            parse_pasted_code("https://localhost:1337/callback?error=access_denied"),
+            Err(OAuthError::AuthorizationDenied(e)) if e == "access_denied"
+        ));
+        assert!(matches!(
+            parse_pasted_code("https://localhost:1337/callback"),
+            Err(OAuthError::InvalidPastedCode)
+        ));
+        assert!(parse_pasted_code("   ").is_err());
+    }
 }
@@ -1,7 +1,14 @@
 use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeDelta, Utc};
+use futures::stream::BoxStream;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
+use tokio::sync::RwLock;
+
+use crate::bluesky::uri;
 
 /// Errors that can occur when interacting with social platforms
 #[derive(Debug, Error)]
@@ -12,6 +19,155 @@ pub enum PlatformError {
     Auth(String),
     #[error("API error: {0}")]
     Api(String),
+    #[error("{0} is not supported on this platform")]
+    NotImplemented(String),
+    /// The account or app is missing a permission; the message says how to fix it
+    #[error("{0}")]
+    Permission(String),
+    /// The post (by id) was deleted or can no longer be loaded
+    #[error("Post {0} is no longer available")]
+    NotFound(String),
+    /// There are no credentials yet; the message says which login to run
+    #[error("{0}")]
+    NotConfigured(String),
+}
+
+impl PlatformError {
+    /// Whether the request never got an answer (no connection, timeout)
+    pub fn is_network(&self) -> bool {
+        matches!(self, PlatformError::Request(_))
+    }
+}
+
+/// A thread chain that stopped partway through
+#[derive(Debug, Error)]
+#[error("Segment {failed_segment} of {total} failed: {source}")]
+pub struct ThreadError {
+    /// 1-based index of the segment that wasn't posted; earlier segments were
+    pub failed_segment: usize,
+    pub total: usize,
+    #[source]
+    pub source: PlatformError,
+}
+
+/// Separates segments of a thread chain in the composer
+pub const THREAD_DELIMITER: &str = "---";
+
+/// Split composed text into thread segments on lines that are just `---`.
+/// Text without a delimiter line comes back as a single segment.
+pub fn split_thread(text: &str) -> Vec<String> {
+    let mut segments = Vec::new();
+    let mut current = String::new();
+
+    for line in text.split_inclusive('\n') {
+        if line.trim() == THREAD_DELIMITER {
+            segments.push(std::mem::take(&mut current));
+        } else {
+            current.push_str(line);
+        }
+    }
+    segments.push(current);
+
+    segments
+        .into_iter()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// An image to attach by URL, with optional alt text for screen readers
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImageAttachment {
+    pub url: String,
+    pub alt: Option<String>,
+}
+
+impl ImageAttachment {
+    /// Pair image URLs with alt texts by position; blank or missing alt becomes `None`
+    pub fn with_alts(urls: Vec<String>, alts: &[String]) -> Vec<Self> {
+        urls.into_iter()
+            .enumerate()
+            .map(|(i, url)| Self {
+                url,
+                alt: alts.get(i).filter(|a| !a.trim().is_empty()).cloned(),
+            })
+            .collect()
+    }
+}
+
+/// Composer prefix marking an image URL to attach (e.g. `@img:https://...`)
+pub const IMAGE_PREFIX: &str = "@img:";
+
+/// Composer prefix for a link to show as a preview card (e.g. `@link:https://...`)
+pub const LINK_PREFIX: &str = "@link:";
+
+/// Pull `@img:<url>` tokens out of composed text, returning the remaining
+/// text and the image URLs in order
+pub fn extract_images(text: &str) -> (String, Vec<String>) {
+    extract_prefixed(text, IMAGE_PREFIX)
+}
+
+/// Pull `@link:<url>` tokens out of composed text, returning the remaining
+/// text and the link URLs in order
+pub fn extract_links(text: &str) -> (String, Vec<String>) {
+    extract_prefixed(text, LINK_PREFIX)
+}
+
+/// Remove `<prefix><value>` words from text, returning what's left and the values
+fn extract_prefixed(text: &str, prefix: &str) -> (String, Vec<String>) {
+    let mut values = Vec::new();
+    let lines: Vec<String> = text
+        .lines()
+        .map(|line| {
+            let words: Vec<&str> = line
+                .split_whitespace()
+                .filter(|word| match word.strip_prefix(prefix) {
+                    Some(value) if !value.is_empty() => {
+                        values.push(value.to_string());
+                        false
+                    }
+                    _ => true,
+                })
+                .collect();
+            words.join(" ")
+        })
+        .collect();
+
+    if values.is_empty() {
+        return (text.to_string(), values);
+    }
+
+    let remaining: Vec<String> = lines.into_iter().filter(|l| !l.is_empty()).collect();
+    (remaining.join("\n"), values)
+}
+
+/// Characters of composed text that count against a platform's limit: the
+/// caption when there are `@img:`/`@link:` attachments, otherwise the longest
+/// segment of a thread chain
+pub fn post_length(text: &str) -> usize {
+    let (text, links) = extract_links(text);
+    let (caption, images) = extract_images(&text);
+    if !links.is_empty() || !images.is_empty() {
+        return caption.trim().chars().count();
+    }
+    split_thread(&text)
+        .iter()
+        .map(|segment| segment.chars().count())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Check a link attachment is an absolute http(s) URL
+pub fn validate_link(url: &str) -> Result<String, String> {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.host().is_some() => {
+            Ok(parsed.to_string())
+        }
+        _ => Err(format!(
+            "Invalid link attachment '{}': use an http(s) URL",
+            url
+        )),
+    }
 }
 
 /// Platform identifier
@@ -30,31 +186,446 @@ impl fmt::Display for Platform {
     }
 }
 
+impl Platform {
+    /// Short tag for rows that mix platforms, like `[bsky]`
+    pub fn tag(self) -> &'static str {
+        match self {
+            Platform::Threads => "th",
+            Platform::Bluesky => "bsky",
+        }
+    }
+
+    /// Most characters a single post can have
+    pub fn char_limit(self) -> usize {
+        match self {
+            Platform::Threads => 500,
+            Platform::Bluesky => 300,
+        }
+    }
+}
+
+/// A post identified by a pasted link, with the id its platform's client expects
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostLink {
+    pub platform: Platform,
+    /// Threads media id or Bluesky AT URI (whose authority may still be a handle)
+    pub id: String,
+}
+
+/// Characters of an Instagram-style shortcode, in base-64 digit order
+const SHORTCODE_ALPHABET: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Decode a Threads permalink shortcode into its numeric media id
+fn shortcode_to_media_id(code: &str) -> Option<String> {
+    if code.is_empty() {
+        return None;
+    }
+    let mut id: u128 = 0;
+    for c in code.chars() {
+        let digit = SHORTCODE_ALPHABET.find(c)? as u128;
+        id = id.checked_mul(64)?.checked_add(digit)?;
+    }
+    Some(id.to_string())
+}
+
+/// Recognize a Bluesky AT URI, a bsky.app post URL, or a Threads permalink
+pub fn parse_post_link(input: &str) -> Option<PostLink> {
+    let input = input.trim();
+
+    if input.starts_with("at://") {
+        return uri::parse_at_uri(input)
+            .filter(|parsed| parsed.collection == uri::POST_COLLECTION)
+            .map(|_| PostLink {
+                platform: Platform::Bluesky,
+                id: input.to_string(),
+            });
+    }
+
+    let rest = input
+        .strip_prefix("https://")
+        .or_else(|| input.strip_prefix("http://"))?;
+    // Drop any query string or fragment
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+    let (host, path) = rest.split_once('/')?;
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    match host.strip_prefix("www.").unwrap_or(host) {
+        "bsky.app" => uri::parse_web_url(input).map(|id| PostLink {
+            platform: Platform::Bluesky,
+            id,
+        }),
+        "threads.net" | "threads.com" => match segments.as_slice() {
+            [user, "post", code] if user.starts_with('@') => Some(PostLink {
+                platform: Platform::Threads,
+                id: shortcode_to_media_id(code)?,
+            }),
+            ["t", code] => Some(PostLink {
+                platform: Platform::Threads,
+                id: shortcode_to_media_id(code)?,
+            }),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Drop repeats of a post id, keeping the first occurrence and the order.
+/// Overlapping pages or a slow cursor can return a post twice, but within
+/// one platform's feed an id only appears once.
+pub fn dedupe_posts(posts: &mut Vec<Post>) {
+    let mut seen = std::collections::HashSet::new();
+    posts.retain(|p| seen.insert(p.id.clone()));
+}
+
+/// Parse a post timestamp (RFC 3339 from Bluesky, `+0000` offsets from Threads)
+pub fn parse_timestamp(ts: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(ts)
+        .or_else(|_| DateTime::parse_from_str(ts, "%Y-%m-%dT%H:%M:%S%z"))
+        .ok()
+}
+
+/// Parse a `--since` value: a duration before `now` (`90s`, `30m`, `1h`,
+/// `2d`, `1w`), an RFC 3339 timestamp, or a `YYYY-MM-DD` date (midnight UTC)
+pub fn parse_since(input: &str, now: DateTime<Utc>) -> Result<DateTime<FixedOffset>, String> {
+    let input = input.trim();
+    if let Some(ts) = parse_timestamp(input) {
+        return Ok(ts);
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        return Ok(date
+            .and_hms_opt(0, 0, 0)
+            .unwrap_or_default()
+            .and_utc()
+            .fixed_offset());
+    }
+
+    let invalid = || {
+        format!(
+            "Invalid --since '{}': use a duration like 30m, 1h, or 2d, or a date like 2024-01-31",
+            input
+        )
+    };
+    let split = input
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(invalid)?;
+    let (amount, unit) = input.split_at(split);
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+    let delta = match unit {
+        "s" => TimeDelta::try_seconds(amount),
+        "m" => TimeDelta::try_minutes(amount),
+        "h" => TimeDelta::try_hours(amount),
+        "d" => TimeDelta::try_days(amount),
+        "w" => TimeDelta::try_weeks(amount),
+        _ => None,
+    }
+    .ok_or_else(invalid)?;
+    now.checked_sub_signed(delta)
+        .map(|t| t.fixed_offset())
+        .ok_or_else(invalid)
+}
+
+/// Whether a post is viewable or a placeholder for one that isn't
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PostKind {
+    #[default]
+    Normal,
+    /// The author blocked us (or we blocked them)
+    Blocked,
+    /// The post was deleted or can't be found
+    NotFound,
+}
+
+/// Who may reply to a post, when the author limited it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplyAudience {
+    /// Accounts the author follows
+    Following,
+    /// The author's followers
+    Followers,
+    /// Accounts mentioned in the post
+    Mentioned,
+    /// Only the author of the post being replied to
+    Author,
+    /// Members of a list the author picked
+    List,
+    /// Nobody: replies are turned off
+    Nobody,
+}
+
+impl ReplyAudience {
+    /// Detail pane line explaining the limit
+    pub fn describe(self) -> &'static str {
+        match self {
+            ReplyAudience::Following => "Replies limited to followed accounts",
+            ReplyAudience::Followers => "Replies limited to followers",
+            ReplyAudience::Mentioned => "Replies limited to mentioned accounts",
+            ReplyAudience::Author => "Replies limited to the author",
+            ReplyAudience::List => "Replies limited to list members",
+            ReplyAudience::Nobody => "Replies are turned off",
+        }
+    }
+}
+
 /// Platform-agnostic post representation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Post {
     pub id: String,
     pub text: Option<String>,
     pub author_handle: Option<String>,
+    /// Stable author identifier (Bluesky DID), when the platform has one
+    pub author_did: Option<String>,
+    /// Author display name, when the platform has one
+    pub author_name: Option<String>,
     pub timestamp: Option<String>,
     pub permalink: Option<String>,
     /// Media type (e.g., "REPOST_FACADE", "IMAGE", "VIDEO", "CAROUSEL_ALBUM")
     pub media_type: Option<String>,
+    /// Engagement counts, when the platform provides them
+    pub like_count: Option<u64>,
+    pub repost_count: Option<u64>,
+    pub reply_count: Option<u64>,
+    /// Id of the thread's root post, when this post is itself a reply
+    pub root_id: Option<String>,
+    pub kind: PostKind,
+    /// Rich-text ranges supplied by the platform (Bluesky facets)
+    pub facets: Vec<Facet>,
+    /// Who may reply, when the author limited it (`None` means anyone)
+    pub reply_audience: Option<ReplyAudience>,
+    /// The platform says the logged-in account can't reply to this post
+    pub reply_disabled: bool,
+    /// The logged-in account has liked this post
+    pub liked: bool,
+}
+
+/// What a highlighted range of post text refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FacetKind {
+    Link,
+    Mention,
+}
+
+/// A highlighted byte range of a post's text
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Facet {
+    pub start: usize,
+    pub end: usize,
+    pub kind: FacetKind,
+}
+
+/// Find `http://` and `https://` URLs in plain text, leaving off trailing punctuation
+pub fn detect_links(text: &str) -> Vec<Facet> {
+    let mut facets = Vec::new();
+    let mut offset = 0;
+
+    for token in text.split_inclusive(char::is_whitespace) {
+        let word = token.trim_end();
+        if let Some(pos) = word.find("http://").or_else(|| word.find("https://")) {
+            let url = word[pos..].trim_end_matches(['.', ',', ';', ':', '!', '?', ')', '"', '\'']);
+            if url.contains("://") && !url.ends_with("://") {
+                facets.push(Facet {
+                    start: offset + pos,
+                    end: offset + pos + url.len(),
+                    kind: FacetKind::Link,
+                });
+            }
+        }
+        offset += token.len();
+    }
+
+    facets
+}
+
+impl Post {
+    /// Placeholder for a post that exists in a thread but can't be shown
+    pub fn unavailable(id: String, kind: PostKind) -> Self {
+        let text = match kind {
+            PostKind::Blocked => "[blocked post]",
+            PostKind::NotFound => "[deleted post]",
+            PostKind::Normal => "[unavailable post]",
+        };
+        Self {
+            id,
+            text: Some(text.to_string()),
+            kind,
+            ..Default::default()
+        }
+    }
+
+    /// Whether this post can be viewed and interacted with
+    pub fn is_available(&self) -> bool {
+        self.kind == PostKind::Normal
+    }
+
+    /// Detail pane note on who may reply, when that's limited
+    pub fn reply_note(&self) -> Option<&'static str> {
+        match (self.reply_audience, self.reply_disabled) {
+            (Some(audience), _) => Some(audience.describe()),
+            (None, true) => Some("You can't reply to this post"),
+            (None, false) => None,
+        }
+    }
+
+    /// Ranges to highlight in the text: the platform's facets if it sent any,
+    /// otherwise URLs detected in the text
+    pub fn text_facets(&self) -> Vec<Facet> {
+        if !self.facets.is_empty() {
+            return self.facets.clone();
+        }
+        self.text.as_deref().map(detect_links).unwrap_or_default()
+    }
 }
 
 /// Platform-agnostic reply thread (recursive structure)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ReplyThread {
     pub post: Post,
     pub replies: Vec<ReplyThread>,
 }
 
+impl ReplyThread {
+    /// Posts of reply trees in reading order: each reply followed by its own replies
+    pub fn flatten(threads: Vec<ReplyThread>) -> Vec<Post> {
+        let mut posts = Vec::new();
+        for thread in threads {
+            posts.push(thread.post);
+            posts.extend(Self::flatten(thread.replies));
+        }
+        posts
+    }
+}
+
+/// The logged-in account; counts are `None` where the platform doesn't report them
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Profile {
+    pub handle: String,
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+    pub followers: Option<u64>,
+    pub following: Option<u64>,
+    pub posts: Option<u64>,
+}
+
+/// How long a fetched profile is reused before asking the API again
+pub const PROFILE_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// One value reused for `ttl` after it's fetched, shared by clones of a client
+pub struct TtlCache<T> {
+    entry: Arc<RwLock<Option<(Instant, T)>>>,
+    ttl: Duration,
+}
+
+impl<T> Clone for TtlCache<T> {
+    fn clone(&self) -> Self {
+        Self {
+            entry: Arc::clone(&self.entry),
+            ttl: self.ttl,
+        }
+    }
+}
+
+impl<T: Clone> TtlCache<T> {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            entry: Arc::default(),
+            ttl,
+        }
+    }
+
+    /// The cached value, fetching and storing a new one if it's missing or expired
+    pub async fn get_or_fetch<E, Fut>(&self, fetch: impl FnOnce() -> Fut) -> Result<T, E>
+    where
+        Fut: Future<Output = Result<T, E>>,
+    {
+        if let Some((fetched, value)) = &*self.entry.read().await
+            && fetched.elapsed() < self.ttl
+        {
+            return Ok(value.clone());
+        }
+
+        let value = fetch().await?;
+        *self.entry.write().await = Some((Instant::now(), value.clone()));
+        Ok(value)
+    }
+
+    /// Drop the cached value so the next lookup fetches
+    pub async fn clear(&self) {
+        *self.entry.write().await = None;
+    }
+}
+
+/// An optional action a platform may or may not support
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feature {
+    Profiles,
+    Follows,
+    Moderation,
+    AuthorDids,
+    Conversations,
+    Likes,
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Feature::Profiles => "Profiles",
+            Feature::Follows => "Following",
+            Feature::Moderation => "Muting and blocking",
+            Feature::AuthorDids => "Author DIDs",
+            Feature::Conversations => "Conversation view",
+            Feature::Likes => "Liking",
+        })
+    }
+}
+
+/// What a client supports beyond the required methods, so the UI can hide
+/// keys that would only answer "not supported"
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    pub profiles: bool,
+    pub follows: bool,
+    /// Mute and block
+    pub moderation: bool,
+    pub author_dids: bool,
+    pub conversations: bool,
+    pub likes: bool,
+}
+
+impl Capabilities {
+    pub fn supports(&self, feature: Feature) -> bool {
+        match feature {
+            Feature::Profiles => self.profiles,
+            Feature::Follows => self.follows,
+            Feature::Moderation => self.moderation,
+            Feature::AuthorDids => self.author_dids,
+            Feature::Conversations => self.conversations,
+            Feature::Likes => self.likes,
+        }
+    }
+}
+
 /// Common trait for all social media platform clients
 #[async_trait]
 pub trait SocialClient: Send + Sync {
-    /// Get the authenticated user's posts/timeline
+    /// Get the authenticated user's posts/timeline, up to `limit` posts even when
+    /// that spans several API pages
     async fn get_posts(&self, limit: Option<u32>) -> Result<Vec<Post>, PlatformError>;
 
+    /// Get up to `limit` posts starting at `cursor` (or the newest without one),
+    /// with the opaque cursor for the page after them, or `None` at the end
+    async fn get_posts_paginated(
+        &self,
+        _limit: Option<u32>,
+        _cursor: Option<&str>,
+    ) -> Result<(Vec<Post>, Option<String>), PlatformError> {
+        Err(PlatformError::NotImplemented(
+            "Loading more posts".to_string(),
+        ))
+    }
+
     /// Get replies to a specific post (with nested replies)
     async fn get_post_replies(
         &self,
@@ -62,11 +633,130 @@ pub trait SocialClient: Send + Sync {
         depth: u8,
     ) -> Result<Vec<ReplyThread>, PlatformError>;
 
-    /// Create a new post
-    async fn create_post(&self, text: &str) -> Result<(), PlatformError>;
+    /// Load a single post by id (Threads media id or Bluesky AT URI)
+    async fn get_post(&self, _id: &str) -> Result<Post, PlatformError> {
+        Err(PlatformError::NotImplemented(
+            "Loading a single post".to_string(),
+        ))
+    }
+
+    /// The whole discussion a post belongs to, flattened: ancestors, the post,
+    /// then every reply in order
+    async fn get_conversation(&self, _post_id: &str) -> Result<Vec<Post>, PlatformError> {
+        Err(PlatformError::NotImplemented(
+            "Conversation view".to_string(),
+        ))
+    }
+
+    /// Optional features this client implements (none by default)
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::default()
+    }
+
+    /// Whether `get_profile` is implemented, so the UI can hide profile
+    /// features instead of showing an error
+    fn supports_profiles(&self) -> bool {
+        self.capabilities().profiles
+    }
+
+    /// The logged-in account's profile
+    async fn get_profile(&self) -> Result<Profile, PlatformError> {
+        Err(PlatformError::NotImplemented("Profiles".to_string()))
+    }
 
-    /// Reply to a post
-    async fn reply_to_post(&self, post_id: &str, text: &str) -> Result<(), PlatformError>;
+    /// The logged-in account's profile, skipping any cached copy
+    async fn refresh_profile(&self) -> Result<Profile, PlatformError> {
+        self.get_profile().await
+    }
+
+    /// Stream the account's new posts as they're created, for platforms with
+    /// a push feed. The stream ends when the connection drops.
+    async fn subscribe(&self) -> Result<BoxStream<'static, Post>, PlatformError> {
+        Err(PlatformError::NotImplemented("Streaming".to_string()))
+    }
+
+    /// Create a new post, returning its id
+    async fn create_post(&self, text: &str) -> Result<String, PlatformError>;
+
+    /// Reply to a post, returning the reply's id
+    async fn reply_to_post(&self, post_id: &str, text: &str) -> Result<String, PlatformError>;
+
+    /// Post images (by public URL) with a caption, returning the post's id
+    async fn create_carousel(
+        &self,
+        _text: &str,
+        _images: &[ImageAttachment],
+    ) -> Result<String, PlatformError> {
+        Err(PlatformError::NotImplemented("Image posts".to_string()))
+    }
+
+    /// Post text with a link shown as a preview card. Platforms without link
+    /// cards get the URL appended to the text instead.
+    async fn create_link_post(&self, text: &str, link: &str) -> Result<String, PlatformError> {
+        let text = if text.is_empty() {
+            link.to_string()
+        } else {
+            format!("{}\n{}", text, link)
+        };
+        self.create_post(&text).await
+    }
+
+    /// Post segments as a chain where each one replies to the previous
+    async fn create_thread(&self, segments: &[String]) -> Result<(), ThreadError> {
+        let mut parent: Option<String> = None;
+        for (i, text) in segments.iter().enumerate() {
+            let result = match &parent {
+                None => self.create_post(text).await,
+                Some(id) => self.reply_to_post(id, text).await,
+            };
+            parent = Some(result.map_err(|source| ThreadError {
+                failed_segment: i + 1,
+                total: segments.len(),
+                source,
+            })?);
+        }
+        Ok(())
+    }
+
+    /// Delete one of the user's own posts
+    async fn delete_post(&self, _post_id: &str) -> Result<(), PlatformError> {
+        Err(PlatformError::NotImplemented("Deleting posts".to_string()))
+    }
+
+    /// Like a post; liking a post that's already liked is a no-op
+    async fn like_post(&self, _post_id: &str) -> Result<(), PlatformError> {
+        Err(PlatformError::NotImplemented("Liking".to_string()))
+    }
+
+    /// Remove the user's like from a post
+    async fn unlike_post(&self, _post_id: &str) -> Result<(), PlatformError> {
+        Err(PlatformError::NotImplemented("Liking".to_string()))
+    }
+
+    /// Repost a post to the user's own feed
+    async fn repost(&self, _post_id: &str) -> Result<(), PlatformError> {
+        Err(PlatformError::NotImplemented("Reposting".to_string()))
+    }
+
+    /// Follow an account (by DID or handle)
+    async fn follow(&self, _actor: &str) -> Result<(), PlatformError> {
+        Err(PlatformError::NotImplemented("Following".to_string()))
+    }
+
+    /// Unfollow an account (by DID or handle)
+    async fn unfollow(&self, _actor: &str) -> Result<(), PlatformError> {
+        Err(PlatformError::NotImplemented("Unfollowing".to_string()))
+    }
+
+    /// Mute an account (by DID or handle)
+    async fn mute(&self, _actor: &str) -> Result<(), PlatformError> {
+        Err(PlatformError::NotImplemented("Muting".to_string()))
+    }
+
+    /// Block an account (by DID or handle)
+    async fn block(&self, _actor: &str) -> Result<(), PlatformError> {
+        Err(PlatformError::NotImplemented("Blocking".to_string()))
+    }
 }
 
 // Helper to convert from platform-specific errors
@@ -81,6 +771,208 @@ impl From<crate::api::ApiError> for PlatformError {
         match err {
             crate::api::ApiError::Request(e) => PlatformError::Request(e.to_string()),
             crate::api::ApiError::Api(e) => PlatformError::Api(e),
+            e @ crate::api::ApiError::MissingScope(_) => PlatformError::Permission(e.to_string()),
+            crate::api::ApiError::NotFound(id) => PlatformError::NotFound(id),
+            e @ (crate::api::ApiError::Unauthorized(_)
+            | crate::api::ApiError::SessionExpired(_)) => PlatformError::Auth(e.to_string()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_thread_single() {
+        assert_eq!(split_thread("hello world"), vec!["hello world"]);
+    }
+
+    #[test]
+    fn test_split_thread_segments() {
+        assert_eq!(
+            split_thread("first post\n---\nsecond\n---\nthird"),
+            vec!["first post", "second", "third"]
+        );
+    }
+
+    #[test]
+    fn test_extract_images() {
+        let (text, images) =
+            extract_images("Trip photos @img:https://a.example/1.jpg @img:https://a.example/2.jpg");
+        assert_eq!(text, "Trip photos");
+        assert_eq!(
+            images,
+            vec!["https://a.example/1.jpg", "https://a.example/2.jpg"]
+        );
+    }
+
+    #[test]
+    fn test_extract_images_none() {
+        let (text, images) = extract_images("just  text @img:");
+        assert_eq!(text, "just  text @img:");
+        assert!(images.is_empty());
+    }
+
+    #[test]
+    fn test_extract_and_validate_links() {
+        let (text, links) = extract_links("Read this @link:https://example.com/a");
+        assert_eq!(text, "Read this");
+        assert_eq!(links, vec!["https://example.com/a"]);
+
+        assert_eq!(
+            validate_link("https://example.com/a").unwrap(),
+            "https://example.com/a"
+        );
+        assert!(validate_link("example.com").is_err());
+        assert!(validate_link("ftp://example.com/file").is_err());
+        assert!(validate_link("https://").is_err());
+    }
+
+    #[test]
+    fn test_parse_since() {
+        let now = DateTime::parse_from_rfc3339("2024-03-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let at = |s: &str| DateTime::parse_from_rfc3339(s).unwrap();
+
+        assert_eq!(parse_since("1h", now).unwrap(), at("2024-03-10T11:00:00Z"));
+        assert_eq!(parse_since("90s", now).unwrap(), at("2024-03-10T11:58:30Z"));
+        assert_eq!(parse_since("2d", now).unwrap(), at("2024-03-08T12:00:00Z"));
+        assert_eq!(
+            parse_since("2024-03-01", now).unwrap(),
+            at("2024-03-01T00:00:00Z")
+        );
+        assert_eq!(
+            parse_since("2024-03-09T08:30:00+02:00", now).unwrap(),
+            at("2024-03-09T06:30:00Z")
+        );
+        for bad in ["", "h", "1", "1y", "-1h", "yesterday"] {
+            assert!(parse_since(bad, now).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_post_length_counts_longest_segment() {
+        assert_eq!(post_length("héllo"), 5);
+        assert_eq!(post_length("short\n---\na longer one"), 12);
+        assert_eq!(post_length("caption @img:https://a.example/1.jpg"), 7);
+        assert_eq!(post_length(""), 0);
+    }
+
+    #[test]
+    fn test_split_thread_ignores_inline_dashes() {
+        assert_eq!(split_thread("a---b --- c"), vec!["a---b --- c"]);
+        assert_eq!(split_thread("---\nonly\n---"), vec!["only"]);
+        assert_eq!(
+            split_thread("keep\n--- this\n---\nnext"),
+            vec!["keep\n--- this", "next"]
+        );
+    }
+
+    #[test]
+    fn test_detect_links() {
+        let text = "see https://example.com/a, and (http://x.org).";
+        let links: Vec<&str> = detect_links(text)
+            .iter()
+            .map(|f| &text[f.start..f.end])
+            .collect();
+        assert_eq!(links, vec!["https://example.com/a", "http://x.org"]);
+    }
+
+    #[test]
+    fn test_detect_links_ignores_bare_scheme() {
+        assert!(detect_links("type https:// then a host").is_empty());
+        assert!(detect_links("no links here").is_empty());
+    }
+
+    #[test]
+    fn test_parse_bluesky_links() {
+        let uri = "at://did:plc:abc/app.bsky.feed.post/3kxyz";
+        assert_eq!(
+            parse_post_link(uri),
+            Some(PostLink {
+                platform: Platform::Bluesky,
+                id: uri.to_string(),
+            })
+        );
+        assert_eq!(
+            parse_post_link(" https://bsky.app/profile/alice.bsky.social/post/3kxyz?ref=x ")
+                .map(|l| l.id),
+            Some("at://alice.bsky.social/app.bsky.feed.post/3kxyz".to_string())
+        );
+        assert_eq!(
+            parse_post_link("at://did:plc:abc/app.bsky.graph.follow/1"),
+            None
+        );
+        assert_eq!(parse_post_link("https://bsky.app/profile/alice"), None);
+    }
+
+    #[test]
+    fn test_parse_threads_links() {
+        let link = parse_post_link("https://www.threads.net/@someone/post/C_b").unwrap();
+        assert_eq!(link.platform, Platform::Threads);
+        // C=2, _=63, b=27 in base 64
+        assert_eq!(link.id, (2 * 64 * 64 + 63 * 64 + 27).to_string());
+        assert_eq!(
+            parse_post_link("https://threads.com/t/C_b").map(|l| l.id),
+            Some(link.id)
+        );
+        assert_eq!(
+            parse_post_link("https://threads.net/@someone/post/bad!"),
+            None
+        );
+        assert_eq!(
+            parse_post_link("https://example.com/@someone/post/C_b"),
+            None
+        );
+        assert_eq!(parse_post_link("not a link"), None);
+    }
+
+    #[test]
+    fn test_flatten_replies() {
+        let leaf = |id: &str| ReplyThread {
+            post: Post {
+                id: id.to_string(),
+                ..Default::default()
+            },
+            replies: Vec::new(),
+        };
+        let mut a = leaf("a");
+        a.replies = vec![leaf("a1"), leaf("a2")];
+        let ids: Vec<String> = ReplyThread::flatten(vec![a, leaf("b")])
+            .into_iter()
+            .map(|p| p.id)
+            .collect();
+        assert_eq!(ids, vec!["a", "a1", "a2", "b"]);
+    }
+
+    #[tokio::test]
+    async fn test_ttl_cache_reuses_until_cleared() {
+        let cache = TtlCache::new(PROFILE_TTL);
+        let fetches = std::sync::atomic::AtomicUsize::new(0);
+        let fetch = || async {
+            let n = fetches.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok::<_, PlatformError>(n)
+        };
+
+        assert_eq!(cache.get_or_fetch(fetch).await.unwrap(), 0);
+        assert_eq!(cache.clone().get_or_fetch(fetch).await.unwrap(), 0);
+        cache.clear().await;
+        assert_eq!(cache.get_or_fetch(fetch).await.unwrap(), 1);
+
+        // An expired entry is fetched again
+        let expired = TtlCache::new(Duration::ZERO);
+        assert_eq!(expired.get_or_fetch(fetch).await.unwrap(), 2);
+        assert_eq!(expired.get_or_fetch(fetch).await.unwrap(), 3);
+    }
+
+    #[test]
+    fn test_image_attachment_with_alts() {
+        let urls = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let images = ImageAttachment::with_alts(urls, &["A cat".to_string(), "  ".to_string()]);
+        assert_eq!(images[0].alt.as_deref(), Some("A cat"));
+        assert_eq!(images[1].alt, None);
+        assert_eq!(images[2].alt, None);
+    }
+}
@@ -0,0 +1,375 @@
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::config::{Config, ConfigError, write_file};
+use crate::platform::Platform;
+
+/// Composer prefix that turns a post into a scheduled post
+pub const SCHEDULE_PREFIX: &str = "@at:";
+
+/// Posts sent more than this many seconds after their scheduled time are logged as late
+const LATE_THRESHOLD_SECS: u64 = 60;
+
+/// Sends a scheduled post gets before it's moved to the failed list
+pub const MAX_SEND_ATTEMPTS: u32 = 5;
+
+/// A claim older than this is taken to be from an ndl that stopped mid-send,
+/// and the post is sent again
+const CLAIM_TIMEOUT_SECS: u64 = 10 * 60;
+
+/// Serializes access to the queue file between the TUI and the send task.
+/// Other ndl processes are kept out by a lock on `scheduled.lock`.
+static QUEUE_LOCK: Mutex<()> = Mutex::new(());
+
+/// A post waiting to be sent at a future time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledPost {
+    pub text: String,
+    pub platform: Platform,
+    /// Unix timestamp (seconds since epoch) when the post should be sent
+    pub send_at: u64,
+    /// Alt text for each `@img:` attachment, in order
+    #[serde(default)]
+    pub alt_texts: Vec<String>,
+    /// Failed sends so far
+    #[serde(default)]
+    pub attempts: u32,
+    /// When an ndl process claimed the post to send it, so no other one does
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub claimed_at: Option<u64>,
+    /// Why the last send failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+impl ScheduledPost {
+    /// Seconds between the scheduled time and `now` (0 if not yet due)
+    pub fn seconds_late(&self, now: u64) -> u64 {
+        now.saturating_sub(self.send_at)
+    }
+
+    pub fn is_late(&self, now: u64) -> bool {
+        self.seconds_late(now) > LATE_THRESHOLD_SECS
+    }
+}
+
+/// Local queue of scheduled posts (~/.config/ndl/scheduled.json)
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ScheduleQueue {
+    pub posts: Vec<ScheduledPost>,
+    /// Posts that were given up on, kept so they aren't lost
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<ScheduledPost>,
+}
+
+impl ScheduleQueue {
+    /// Get the queue file path (~/.config/ndl/scheduled.json)
+    pub fn path() -> Result<PathBuf, ConfigError> {
+        Ok(Config::dir()?.join("scheduled.json"))
+    }
+
+    fn load() -> Result<Self, ConfigError> {
+        let path = Self::path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    fn save(&self) -> Result<(), ConfigError> {
+        let contents = serde_json::to_string_pretty(self)?;
+        write_file(&Self::path()?, &contents)
+    }
+
+    /// Run `f` holding both the in-process lock and the file lock that keeps
+    /// other ndl processes out of the queue
+    fn locked<T>(f: impl FnOnce() -> Result<T, ConfigError>) -> Result<T, ConfigError> {
+        let _guard = QUEUE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = Config::dir()?;
+        std::fs::create_dir_all(&dir)?;
+        let lock = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(dir.join("scheduled.lock"))?;
+        lock.lock()?;
+        f()
+    }
+
+    /// Append a post to the queue file
+    pub fn push(post: ScheduledPost) -> Result<(), ConfigError> {
+        Self::locked(|| {
+            let mut queue = Self::load()?;
+            queue.posts.push(post);
+            queue.save()
+        })
+    }
+
+    /// Claim the posts in the queue file that are due for any of the given
+    /// platforms and return them. They stay queued until
+    /// [`ScheduleQueue::remove_sent`] or [`ScheduleQueue::send_failed`] is
+    /// called, but no other ndl process picks them up meanwhile.
+    pub fn claim_due(platforms: &[Platform], now: u64) -> Result<Vec<ScheduledPost>, ConfigError> {
+        Self::locked(|| {
+            let mut queue = Self::load()?;
+            let claimed = queue.claim(platforms, now);
+            if !claimed.is_empty() {
+                queue.save()?;
+            }
+            Ok(claimed)
+        })
+    }
+
+    /// Drop a post from the queue file once it has been sent
+    pub fn remove_sent(post: &ScheduledPost) -> Result<(), ConfigError> {
+        Self::locked(|| {
+            let mut queue = Self::load()?;
+            if queue.remove(post) {
+                queue.save()?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Record a failed send of a claimed post. It's released for a retry,
+    /// unless the failure is `permanent` or it has used up its attempts; then
+    /// it moves to the failed list. Returns whether it was given up on.
+    pub fn send_failed(
+        post: &ScheduledPost,
+        error: &str,
+        permanent: bool,
+    ) -> Result<bool, ConfigError> {
+        Self::locked(|| {
+            let mut queue = Self::load()?;
+            let gave_up = queue.fail(post, error, permanent);
+            queue.save()?;
+            Ok(gave_up)
+        })
+    }
+
+    /// Posts due for any of the given platforms that no one is sending,
+    /// marked as claimed at `now`. Posts for platforms that aren't connected
+    /// stay queued until they are.
+    fn claim(&mut self, platforms: &[Platform], now: u64) -> Vec<ScheduledPost> {
+        self.posts
+            .iter_mut()
+            .filter(|p| p.send_at <= now && platforms.contains(&p.platform))
+            .filter(|p| {
+                p.claimed_at
+                    .is_none_or(|at| now.saturating_sub(at) > CLAIM_TIMEOUT_SECS)
+            })
+            .map(|p| {
+                p.claimed_at = Some(now);
+                p.clone()
+            })
+            .collect()
+    }
+
+    /// Release a claimed post after a failed send, or move it to the failed
+    /// list. Returns whether it was moved.
+    fn fail(&mut self, post: &ScheduledPost, error: &str, permanent: bool) -> bool {
+        let Some(idx) = self.posts.iter().position(|p| p == post) else {
+            return false;
+        };
+        let queued = &mut self.posts[idx];
+        queued.attempts += 1;
+        queued.claimed_at = None;
+        queued.last_error = Some(error.to_string());
+        if permanent || queued.attempts >= MAX_SEND_ATTEMPTS {
+            let failed = self.posts.remove(idx);
+            self.failed.push(failed);
+            return true;
+        }
+        false
+    }
+
+    /// Remove the first queued post equal to `post`
+    fn remove(&mut self, post: &ScheduledPost) -> bool {
+        match self.posts.iter().position(|p| p == post) {
+            Some(idx) => {
+                self.posts.remove(idx);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Split a composed post into its scheduled time and text, if it starts with `@at:<time>`.
+///
+/// Accepts local times (`2024-06-01T12:00`, `2024-06-01 12:00`, with optional seconds)
+/// or RFC 3339 timestamps with an offset. Returns `Ok(None)` for unscheduled text.
+pub fn parse_schedule_prefix(text: &str) -> Result<Option<(u64, String)>, String> {
+    let Some(rest) = text.strip_prefix(SCHEDULE_PREFIX) else {
+        return Ok(None);
+    };
+
+    let (when, body) = match rest.split_once(char::is_whitespace) {
+        Some((when, body)) => (when, body.trim_start()),
+        None => (rest, ""),
+    };
+
+    if body.is_empty() {
+        return Err("Scheduled post has no text".to_string());
+    }
+
+    let send_at = parse_time(when)
+        .ok_or_else(|| format!("Invalid schedule time '{}' (use YYYY-MM-DDTHH:MM)", when))?;
+
+    Ok(Some((send_at, body.to_string())))
+}
+
+fn parse_time(when: &str) -> Option<u64> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(when) {
+        return u64::try_from(dt.timestamp()).ok();
+    }
+
+    ["%Y-%m-%dT%H:%M", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M"]
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(when, fmt).ok())
+        .and_then(|naive| Local.from_local_datetime(&naive).earliest())
+        .and_then(|dt| u64::try_from(dt.timestamp()).ok())
+}
+
+/// Format a unix timestamp as local time for status messages
+pub fn format_local(ts: u64) -> String {
+    i64::try_from(ts)
+        .ok()
+        .and_then(|secs| Local.timestamp_opt(secs, 0).single())
+        .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| ts.to_string())
+}
+
+/// Current unix timestamp in seconds
+pub fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scheduled(text: &str, platform: Platform, send_at: u64) -> ScheduledPost {
+        ScheduledPost {
+            text: text.to_string(),
+            platform,
+            send_at,
+            alt_texts: Vec::new(),
+            attempts: 0,
+            claimed_at: None,
+            last_error: None,
+        }
+    }
+
+    fn queue(posts: Vec<ScheduledPost>) -> ScheduleQueue {
+        ScheduleQueue {
+            posts,
+            failed: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_claim_skips_future_and_disconnected_posts() {
+        let mut queue = queue(vec![
+            scheduled("due", Platform::Threads, 100),
+            scheduled("later", Platform::Threads, 300),
+            scheduled("offline", Platform::Bluesky, 100),
+        ]);
+        let due = queue.claim(&[Platform::Threads], 200);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].text, "due");
+        assert_eq!(due[0].claimed_at, Some(200));
+    }
+
+    #[test]
+    fn test_claimed_post_isnt_claimed_again() {
+        let mut queue = queue(vec![scheduled("hello", Platform::Threads, 100)]);
+        assert_eq!(queue.claim(&[Platform::Threads], 200).len(), 1);
+        assert!(queue.claim(&[Platform::Threads], 230).is_empty());
+
+        // A claim left by an ndl that died mid-send runs out
+        let later = 200 + CLAIM_TIMEOUT_SECS + 1;
+        assert_eq!(queue.claim(&[Platform::Threads], later).len(), 1);
+    }
+
+    #[test]
+    fn test_failed_send_stays_queued() {
+        let mut queue = queue(vec![scheduled("hello", Platform::Threads, 100)]);
+
+        // The send failed, so the post is released and due again
+        let due = queue.claim(&[Platform::Threads], 200);
+        assert!(!queue.fail(&due[0], "timed out", false));
+        let due = queue.claim(&[Platform::Threads], 230);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].attempts, 1);
+
+        // Once it goes through it's dropped
+        assert!(queue.remove(&due[0]));
+        assert!(queue.claim(&[Platform::Threads], 260).is_empty());
+        assert!(!queue.remove(&due[0]));
+    }
+
+    #[test]
+    fn test_send_given_up_after_max_attempts() {
+        let mut queue = queue(vec![scheduled("hello", Platform::Threads, 100)]);
+        for attempt in 1..=MAX_SEND_ATTEMPTS {
+            let due = queue.claim(&[Platform::Threads], 200);
+            assert_eq!(
+                queue.fail(&due[0], "server error", false),
+                attempt == MAX_SEND_ATTEMPTS
+            );
+        }
+        assert!(queue.posts.is_empty());
+        assert_eq!(queue.failed.len(), 1);
+        assert_eq!(queue.failed[0].last_error.as_deref(), Some("server error"));
+    }
+
+    #[test]
+    fn test_permanent_failure_given_up_at_once() {
+        let mut queue = queue(vec![scheduled("hello", Platform::Threads, 100)]);
+        let due = queue.claim(&[Platform::Threads], 200);
+        assert!(queue.fail(&due[0], "too long", true));
+        assert!(queue.claim(&[Platform::Threads], 230).is_empty());
+        assert_eq!(queue.failed.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_schedule_prefix_unscheduled() {
+        assert_eq!(parse_schedule_prefix("hello world"), Ok(None));
+    }
+
+    #[test]
+    fn test_parse_schedule_prefix_local_time() {
+        let (send_at, text) = parse_schedule_prefix("@at:2024-06-01T12:00 hello world")
+            .unwrap()
+            .unwrap();
+        let expected = Local
+            .with_ymd_and_hms(2024, 6, 1, 12, 0, 0)
+            .earliest()
+            .unwrap()
+            .timestamp() as u64;
+        assert_eq!(send_at, expected);
+        assert_eq!(text, "hello world");
+    }
+
+    #[test]
+    fn test_parse_schedule_prefix_rfc3339() {
+        let (send_at, _) = parse_schedule_prefix("@at:2024-06-01T12:00:00Z hi")
+            .unwrap()
+            .unwrap();
+        assert_eq!(send_at, 1717243200);
+    }
+
+    #[test]
+    fn test_parse_schedule_prefix_errors() {
+        assert!(parse_schedule_prefix("@at:tomorrow hi").is_err());
+        assert!(parse_schedule_prefix("@at:2024-06-01T12:00").is_err());
+    }
+}
@@ -1,21 +1,38 @@
-use crate::platform::{Platform, Post, ReplyThread, SocialClient};
+use crate::cache::PostCache;
+use crate::clipboard;
+use crate::config::Config;
+use crate::connect;
+use crate::list_format::{ListFormat, humanize_timestamp};
+use crate::platform::{
+    Facet, FacetKind, Feature, ImageAttachment, Platform, PlatformError, Post, PostLink, Profile,
+    ReplyAudience, ReplyThread, SocialClient, dedupe_posts, detect_links, extract_images,
+    extract_links, parse_post_link, parse_timestamp, post_length, split_thread, validate_link,
+};
+use crate::schedule::{self, ScheduleQueue, ScheduledPost};
+use crate::ui_state::UiState;
 use crossterm::{
     ExecutableCommand,
-    event::{self, Event, KeyCode, KeyEventKind},
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
+use futures::StreamExt;
+use futures::stream::FuturesUnordered;
 use ratatui::{
     DefaultTerminal, Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    text::Line,
+    text::{Line, Span, Text},
     widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
 };
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::io::{self, stdout};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
-use tracing::{debug, error, info};
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, warn};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Panel {
@@ -28,14 +45,371 @@ pub enum InputMode {
     Normal,
     Replying,
     Posting,
-    CrossPosting, // Post to all platforms
+    CrossPosting,  // Post to all platforms
+    Goto,          // Paste a post link to open
+    ConfirmDelete, // y/n in the status bar before deleting `pending_delete`
+}
+
+/// Account moderation actions that need confirmation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Moderation {
+    Mute,
+    Block,
+}
+
+/// A moderation action waiting for y/n confirmation
+#[derive(Debug, Clone)]
+pub struct PendingModeration {
+    pub action: Moderation,
+    /// DID or handle passed to the client
+    pub actor: String,
+    pub display: String,
+}
+
+/// Collects alt text for each image in a post before it's sent
+#[derive(Debug, Clone)]
+pub struct AltTextPrompt {
+    /// The composed post, restored once every image has alt text
+    pub text: String,
+    pub image_count: usize,
+    pub alts: Vec<String>,
+    pub keep_open: bool,
+}
+
+/// How often each platform's posts are refreshed in the background
+const REFRESH_INTERVAL: Duration = Duration::from_secs(11);
+
+/// Shortest `refresh_interval_secs` honored, to stay clear of rate limits
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Refresh intervals the settings overlay cycles through
+const REFRESH_INTERVALS: [Duration; 4] = [
+    REFRESH_INTERVAL,
+    Duration::from_secs(30),
+    Duration::from_secs(60),
+    Duration::from_secs(300),
+];
+
+/// Longest the refresh loop waits between attempts while requests keep failing
+const OFFLINE_REFRESH_INTERVAL: Duration = Duration::from_secs(120);
+
+/// Consecutive network failures before the TUI shows itself as offline
+const OFFLINE_AFTER_FAILURES: u32 = 3;
+
+/// Give up on a reply fetch that takes longer than this
+const REPLIES_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// File under the config dir that `J` writes the selected thread to
+const EXPORT_FILE: &str = "export.json";
+
+/// Status messages kept for the `E` log overlay
+const STATUS_LOG_LEN: usize = 200;
+
+/// How long to wait for every platform before showing a cross-post summary
+const CROSS_POST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Options the `,` settings overlay can change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Setting {
+    AutoRefresh,
+    RefreshInterval,
+    ConfirmBeforePost,
+    RequireAltText,
+    ReadOnly,
+}
+
+/// Setting values when the overlay opened, so closing it saves only changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SettingValues {
+    paused: bool,
+    refresh_interval: Duration,
+    confirm_before_post: bool,
+    require_alt_text: bool,
+    read_only: bool,
+}
+
+/// Order the loaded reply tree is shown in; `s` cycles it for the session
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum ReplySort {
+    /// The order the platform returned
+    #[default]
+    Api,
+    Newest,
+    Oldest,
+    /// Most likes, reposts, and replies first
+    Engaged,
+}
+
+impl ReplySort {
+    fn label(self) -> &'static str {
+        match self {
+            ReplySort::Api => "as loaded",
+            ReplySort::Newest => "newest first",
+            ReplySort::Oldest => "oldest first",
+            ReplySort::Engaged => "most engaged first",
+        }
+    }
+
+    /// The next order, skipping most-engaged when no reply has counts
+    fn next(self, has_counts: bool) -> Self {
+        match self {
+            ReplySort::Api => ReplySort::Newest,
+            ReplySort::Newest => ReplySort::Oldest,
+            ReplySort::Oldest if has_counts => ReplySort::Engaged,
+            ReplySort::Oldest | ReplySort::Engaged => ReplySort::Api,
+        }
+    }
+}
+
+/// Sort siblings at every level of a reply tree, keeping each reply's own
+/// replies under it. Replies without a timestamp go last.
+fn sort_replies(replies: &mut [ReplyThread], sort: ReplySort) {
+    fn time(reply: &ReplyThread) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        reply.post.timestamp.as_deref().and_then(parse_timestamp)
+    }
+    fn engagement(reply: &ReplyThread) -> u64 {
+        let post = &reply.post;
+        [post.like_count, post.repost_count, post.reply_count]
+            .iter()
+            .flatten()
+            .sum()
+    }
+
+    match sort {
+        ReplySort::Api => return,
+        ReplySort::Newest => replies.sort_by_key(|r| (time(r).is_none(), Reverse(time(r)))),
+        ReplySort::Oldest => replies.sort_by_key(|r| (time(r).is_none(), time(r))),
+        ReplySort::Engaged => replies.sort_by_key(|r| Reverse(engagement(r))),
+    }
+    for reply in replies {
+        sort_replies(&mut reply.replies, sort);
+    }
+}
+
+/// Settings overlay rows, in order
+const SETTINGS: [Setting; 5] = [
+    Setting::AutoRefresh,
+    Setting::RefreshInterval,
+    Setting::ConfirmBeforePost,
+    Setting::RequireAltText,
+    Setting::ReadOnly,
+];
+
+/// Results of one cross-post, collected for the summary popup
+pub struct CrossPostBatch {
+    pub id: u64,
+    pub platforms: Vec<Platform>,
+    pub results: HashMap<Platform, Result<(), String>>,
+    pub started: Instant,
+    /// Every platform reported back, or the timeout elapsed
+    pub complete: bool,
+}
+
+impl CrossPostBatch {
+    fn is_done(&self) -> bool {
+        self.platforms.iter().all(|p| self.results.contains_key(p))
+            || self.started.elapsed() >= CROSS_POST_TIMEOUT
+    }
+
+    /// One status bar line covering every platform, e.g.
+    /// "Posted: Bluesky ✓, Threads ✗ (rate limited)"
+    fn summary(&self) -> String {
+        let parts: Vec<String> = self
+            .platforms
+            .iter()
+            .map(|platform| match self.results.get(platform) {
+                Some(Ok(())) => format!("{} ✓", platform),
+                Some(Err(e)) => format!("{} ✗ ({})", platform, e),
+                None if self.complete => format!("{} ✗ (timed out)", platform),
+                None => format!("{} …", platform),
+            })
+            .collect();
+        let label = if self.complete {
+            "Posted"
+        } else {
+            "Cross-posting"
+        };
+        format!("{}: {}", label, parts.join(", "))
+    }
+}
+
+/// A post or reply as sent, kept after a failure so `.` can send it again
+#[derive(Debug, Clone, PartialEq)]
+pub struct Draft {
+    pub platform: Platform,
+    pub text: String,
+    pub alt_texts: Vec<String>,
+    /// The post being replied to; `None` for a new post
+    pub reply_to: Option<String>,
+}
+
+/// A status bar message kept for the log overlay
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub at: chrono::DateTime<chrono::Local>,
+    pub message: String,
+}
+
+/// Which author identifier to copy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthorField {
+    Handle,
+    Did,
+}
+
+/// Work a key press asks for that touches the network, disk, or terminal.
+/// `App::apply_key` only updates state; `App::perform` runs these.
+#[derive(Debug)]
+pub enum Action {
+    Refresh,
+    /// Send the composer contents, keeping the composer open if `keep_open`
+    Send {
+        keep_open: bool,
+    },
+    Reconnect,
+    Login(Platform),
+    /// Follow (`true`) or unfollow the selected author
+    Follow(bool),
+    /// Like (`true`) or unlike the post with this id
+    Like(String, bool),
+    /// Repost the post with this id
+    Repost(String),
+    Moderate(PendingModeration),
+    CopyAuthor(AuthorField),
+    /// Copy the selected post or reply's text
+    CopyText,
+    /// Send the last post or reply that failed again
+    RetrySend,
+    /// Open the selected post or reply on the web
+    OpenPermalink,
+    ExportThread,
+    /// Load the linked post and show it in the detail pane
+    OpenPost(PostLink),
+    /// Delete one of the user's own posts (by id) on the current platform
+    DeletePost(String),
+    /// Fetch the page of posts after the current platform's list
+    LoadMore,
+    /// Write the settings overlay's choices to the config file
+    SaveSettings,
+    /// Move the current platform's read marker past every loaded post
+    MarkAllRead,
+    /// Fetch the current platform's profile again, skipping the cache
+    RefreshProfile,
 }
 
 pub enum AppEvent {
     PostsUpdated(Platform, Vec<Post>),
-    ReplyResult(Platform, Result<(), String>),
-    PostResult(Platform, Result<(), String>),
-    RepliesLoaded(Platform, String, Result<Vec<ReplyThread>, String>),
+    /// A new post pushed by a platform's stream
+    PostStreamed(Platform, Post),
+    /// An older page of posts, with the cursor for the page after it
+    MorePostsLoaded(Platform, Result<(Vec<Post>, Option<String>), PlatformError>),
+    /// A background refresh couldn't reach the platform
+    RefreshFailed(Platform, String),
+    /// Outcome of sending a post or reply
+    SendResult(Draft, Result<(), String>),
+    /// A scheduled post couldn't be sent. It stays queued for the next
+    /// attempt unless it was given up on (`true`).
+    ScheduledSendFailed(Platform, String, bool),
+    /// One platform's result for the cross-post batch with this id
+    CrossPostResult(u64, Platform, Result<(), String>),
+    /// Replies for a post, tagged with the fetch generation that requested them
+    RepliesLoaded(
+        Platform,
+        u64,
+        String,
+        Result<Vec<ReplyThread>, PlatformError>,
+    ),
+    /// Outcome of a background account action, with the status to show on success
+    ActionResult(Platform, String, Result<(), String>),
+    /// Outcome of a mute/block, with the author (DID or handle) to hide on success
+    AuthorModerated(Platform, String, String, Result<(), String>),
+    /// Outcome of deleting the post with this id
+    PostDeleted(Platform, String, Result<(), String>),
+    /// Outcome of liking (`true`) or unliking the post with this id
+    LikeResult(Platform, String, bool, Result<(), String>),
+    /// Outcome of reposting the post with this id
+    Reposted(Platform, String, Result<(), String>),
+    /// Outcome of retrying a platform that failed to connect
+    Reconnected(Platform, Result<Box<dyn SocialClient>, String>),
+    /// The logged-in account on a platform
+    ProfileLoaded(Platform, Result<Profile, PlatformError>),
+    /// A post loaded from a pasted link
+    PostOpened(PostLink, Result<Post, PlatformError>),
+}
+
+/// Help popup lines, each with the feature the current platform needs for it
+const HELP_LINES: &[(&str, Option<Feature>)] = &[
+    ("j / Down     Move down (or select reply)", None),
+    ("k / Up       Move up (or select reply)", None),
+    ("gg / G       Jump to first / last post", None),
+    ("PgUp / PgDn  Scroll the detail panel", None),
+    ("h / Left     Focus left panel", None),
+    ("l / Right    Focus right panel", None),
+    ("t            Swap panel positions", None),
+    ("p            Create new post", None),
+    ("P            Cross-post to all platforms", None),
+    ("Ctrl+S       Send (Enter starts a new line)", None),
+    ("Ctrl+Enter   Send post and keep composing", None),
+    ("r            Reply to thread or reply", None),
+    (">            Reply quoting the post's text", None),
+    ("U            Jump to parent reply / thread root", None),
+    ("n            Jump to oldest unread post", None),
+    ("A            Mark all posts read", None),
+    ("y            Copy post text", None),
+    ("Y            Copy author handle", None),
+    ("D            Copy author DID", Some(Feature::AuthorDids)),
+    (
+        "+ / -        Follow / unfollow author",
+        Some(Feature::Follows),
+    ),
+    (
+        "M / X        Mute / block author",
+        Some(Feature::Moderation),
+    ),
+    ("f            Like / unlike post", Some(Feature::Likes)),
+    ("b / B        Repost / quote post", None),
+    ("i            Show your account", Some(Feature::Profiles)),
+    ("d            Delete your selected post", None),
+    (".            Retry a post or reply that failed", None),
+    ("o            Open post in browser", None),
+    ("J            Export thread as JSON", None),
+    ("O            Open a post from a pasted link", None),
+    (
+        "s            Sort replies (newest / oldest / most engaged)",
+        None,
+    ),
+    (
+        "c            Toggle replies / full conversation",
+        Some(Feature::Conversations),
+    ),
+    ("R            Refresh threads", None),
+    ("Ctrl+R       Pause / resume auto-refresh", None),
+    ("C            Reconnect failed platforms", None),
+    ("L            Log in to a platform", None),
+    ("] / Tab      Switch platform (multi-platform)", None),
+    ("u            Unified timeline (all platforms)", None),
+    ("Enter        Select item", None),
+    ("Esc          Back / Cancel / Deselect", None),
+    ("q            Quit", None),
+    (
+        "E            Message log (recent statuses and errors)",
+        None,
+    ),
+    (",            Settings", None),
+    ("?            Toggle help", None),
+];
+
+/// The optional feature a Normal-mode key needs, if any
+fn key_feature(key: KeyCode) -> Option<Feature> {
+    match key {
+        KeyCode::Char('D') => Some(Feature::AuthorDids),
+        KeyCode::Char('+' | '-') => Some(Feature::Follows),
+        KeyCode::Char('M' | 'X') => Some(Feature::Moderation),
+        KeyCode::Char('c') => Some(Feature::Conversations),
+        KeyCode::Char('f') => Some(Feature::Likes),
+        KeyCode::Char('i') => Some(Feature::Profiles),
+        _ => None,
+    }
 }
 
 /// Platform-specific state
@@ -43,8 +417,33 @@ pub struct PlatformState {
     pub posts: Vec<Post>,
     pub list_state: ListState,
     pub selected_replies: Vec<ReplyThread>,
+    /// `selected_replies` in the order the platform returned them
+    loaded_replies: Vec<ReplyThread>,
     pub loaded_replies_for: Option<String>,
     pub reply_selection: Option<usize>,
+    /// Post whose replies are being fetched right now
+    loading_replies_for: Option<String>,
+    /// Bumped for every reply fetch; results from older generations are stale
+    replies_generation: u64,
+    replies_task: Option<JoinHandle<()>>,
+    /// Posts found to be deleted while loading replies, dropped on the next refresh
+    unavailable: HashSet<String>,
+    /// Post opened from a link, kept at the top of the list across refreshes
+    opened: Option<Post>,
+    /// Posts came from the disk cache and haven't been refreshed yet
+    pub stale: bool,
+    /// A feed (fetched or cached) has been loaded, even if it was empty
+    pub fetched: bool,
+    /// The logged-in account, once loaded (never, where profiles aren't supported)
+    pub profile: Option<Profile>,
+    /// Older pages have been loaded, so refreshes keep them below the newest posts
+    paged: bool,
+    /// Cursor for the page after the loaded posts
+    next_cursor: Option<String>,
+    /// The feed has no more pages (or the platform can't page)
+    pub no_more: bool,
+    /// A page is being fetched right now
+    loading_more: bool,
 }
 
 impl PlatformState {
@@ -53,8 +452,229 @@ impl PlatformState {
             posts: Vec::new(),
             list_state: ListState::default(),
             selected_replies: Vec::new(),
+            loaded_replies: Vec::new(),
             loaded_replies_for: None,
             reply_selection: None,
+            loading_replies_for: None,
+            replies_generation: 0,
+            replies_task: None,
+            unavailable: HashSet::new(),
+            opened: None,
+            stale: false,
+            fetched: false,
+            profile: None,
+            paged: false,
+            next_cursor: None,
+            no_more: false,
+            loading_more: false,
+        }
+    }
+
+    /// Replace the post list, dropping posts found to be deleted or repeated
+    /// and keeping the selection in range
+    fn set_posts(&mut self, mut posts: Vec<Post>) {
+        if self.paged {
+            // Keep the older pages after the last post this update still has
+            let tail = posts
+                .iter()
+                .rev()
+                .find_map(|p| self.posts.iter().position(|old| old.id == p.id))
+                .map_or(0, |i| i + 1);
+            posts.extend(self.posts[tail..].iter().cloned());
+        }
+        dedupe_posts(&mut posts);
+
+        // Follow the selected post to its new index, since refreshes prepend
+        let selected_id = self
+            .list_state
+            .selected()
+            .and_then(|i| self.posts.get(i))
+            .map(|p| p.id.clone());
+
+        if !self.unavailable.is_empty() {
+            posts.retain(|p| !self.unavailable.contains(&p.id));
+            if let Some(opened) = &self.opened
+                && self.unavailable.contains(&opened.id)
+            {
+                self.opened = None;
+            }
+            self.unavailable.clear();
+        }
+        if let Some(opened) = &self.opened
+            && !posts.iter().any(|p| p.id == opened.id)
+        {
+            posts.insert(0, opened.clone());
+        }
+        self.posts = posts;
+        self.fetched = true;
+
+        let moved_to = selected_id.and_then(|id| self.posts.iter().position(|p| p.id == id));
+        match (moved_to, self.list_state.selected()) {
+            (Some(i), _) => self.list_state.select(Some(i)),
+            (None, None) if !self.posts.is_empty() => self.list_state.select(Some(0)),
+            // The post is gone; stay at the same position, within the list
+            (None, Some(i)) if i >= self.posts.len() => {
+                self.list_state.select(self.posts.len().checked_sub(1));
+            }
+            _ => {}
+        }
+    }
+
+    /// Record a like or unlike the platform confirmed, adjusting the post's
+    /// like count wherever it's shown. Copies already in that state (liked
+    /// in an earlier session, say) keep their count.
+    fn set_liked(&mut self, id: &str, liked: bool) {
+        fn adjust(post: &mut Post, liked: bool) {
+            if post.liked == liked {
+                return;
+            }
+            post.liked = liked;
+            post.like_count = post.like_count.map(|count| {
+                if liked {
+                    count + 1
+                } else {
+                    count.saturating_sub(1)
+                }
+            });
+        }
+        fn adjust_replies(replies: &mut [ReplyThread], id: &str, liked: bool) {
+            for reply in replies {
+                if reply.post.id == id {
+                    adjust(&mut reply.post, liked);
+                }
+                adjust_replies(&mut reply.replies, id, liked);
+            }
+        }
+
+        for post in self.posts.iter_mut().filter(|p| p.id == id) {
+            adjust(post, liked);
+        }
+        adjust_replies(&mut self.selected_replies, id, liked);
+        adjust_replies(&mut self.loaded_replies, id, liked);
+    }
+
+    /// Add the next page of older posts below the loaded ones
+    fn append_page(&mut self, page: Vec<Post>, next: Option<String>) {
+        let mut posts = self.posts.clone();
+        posts.extend(page);
+        self.paged = true;
+        self.no_more = next.is_none();
+        self.next_cursor = next;
+        self.set_posts(posts);
+    }
+
+    /// Add a streamed post to the top of the list unless it's already there
+    fn prepend_post(&mut self, post: Post) {
+        if self.posts.iter().any(|p| p.id == post.id) {
+            return;
+        }
+        let mut posts = self.posts.clone();
+        posts.insert(0, post);
+        self.set_posts(posts);
+    }
+
+    /// The post `r` replies to: the selected reply, or else the selected post
+    fn reply_target(&self) -> Option<&Post> {
+        match self.reply_selection {
+            Some(idx) => App::get_reply_at_index(&self.selected_replies, idx).map(|r| &r.post),
+            None => self
+                .list_state
+                .selected()
+                .and_then(|idx| self.posts.get(idx)),
+        }
+    }
+
+    /// Select a post opened from a link, adding it to the top of the list if
+    /// the feed doesn't have it
+    fn open_post(&mut self, post: Post) {
+        let idx = match self.posts.iter().position(|p| p.id == post.id) {
+            Some(idx) => idx,
+            None => {
+                self.posts.insert(0, post.clone());
+                0
+            }
+        };
+        self.opened = Some(post);
+        self.list_state.select(Some(idx));
+        self.reply_selection = None;
+    }
+
+    /// Abort any in-flight reply fetch so its result is ignored if it still arrives
+    fn cancel_replies(&mut self) {
+        if let Some(task) = self.replies_task.take() {
+            task.abort();
+        }
+        self.replies_generation += 1;
+        self.loading_replies_for = None;
+    }
+
+    /// Show the loaded replies in `sort` order, keeping the selected reply
+    /// selected
+    fn sort_replies(&mut self, sort: ReplySort) {
+        let selected_id = self
+            .reply_selection
+            .and_then(|i| App::get_reply_at_index(&self.selected_replies, i))
+            .map(|r| r.post.id.clone());
+        let mut replies = self.loaded_replies.clone();
+        sort_replies(&mut replies, sort);
+        self.selected_replies = replies;
+        self.reply_selection =
+            selected_id.and_then(|id| App::reply_index_of(&self.selected_replies, &id));
+    }
+
+    /// Drop an author's posts and replies, matching on DID or handle
+    fn remove_author(&mut self, actor: &str) {
+        fn by_author(post: &Post, actor: &str) -> bool {
+            post.author_did.as_deref() == Some(actor)
+                || post.author_handle.as_deref() == Some(actor)
+        }
+
+        fn prune(replies: &mut Vec<ReplyThread>, actor: &str) {
+            replies.retain(|r| !by_author(&r.post, actor));
+            for reply in replies {
+                prune(&mut reply.replies, actor);
+            }
+        }
+
+        self.posts.retain(|p| !by_author(p, actor));
+        prune(&mut self.selected_replies, actor);
+        prune(&mut self.loaded_replies, actor);
+        self.reply_selection = None;
+        self.clamp_selection();
+    }
+
+    /// Drop a deleted post from the list and any loaded replies. It's also
+    /// marked unavailable so a refresh racing the delete doesn't bring it back.
+    fn remove_post(&mut self, id: &str) {
+        fn prune(replies: &mut Vec<ReplyThread>, id: &str) {
+            replies.retain(|r| r.post.id != id);
+            for reply in replies {
+                prune(&mut reply.replies, id);
+            }
+        }
+
+        self.posts.retain(|p| p.id != id);
+        if self.opened.as_ref().is_some_and(|p| p.id == id) {
+            self.opened = None;
+        }
+        if self.loaded_replies_for.as_deref() == Some(id) {
+            self.selected_replies.clear();
+            self.loaded_replies.clear();
+            self.loaded_replies_for = None;
+        } else {
+            prune(&mut self.selected_replies, id);
+            prune(&mut self.loaded_replies, id);
+        }
+        self.unavailable.insert(id.to_string());
+        self.reply_selection = None;
+        self.clamp_selection();
+    }
+
+    /// Keep the list selection on a post after posts are removed
+    fn clamp_selection(&mut self) {
+        if let Some(selected) = self.list_state.selected() {
+            let last = self.posts.len().checked_sub(1);
+            self.list_state.select(last.map(|last| selected.min(last)));
         }
     }
 }
@@ -63,19 +683,160 @@ pub struct App {
     pub running: bool,
     pub active_panel: Panel,
     pub show_help: bool,
+    /// Showing the status log overlay (`E`)
+    pub show_log: bool,
+    /// Showing the logged-in account's profile (`i`)
+    pub show_profile: bool,
+    /// The list merges every platform's posts, newest first (`u`)
+    pub unified_view: bool,
+    /// Scroll position of the merged list; the selection lives in the platforms' states
+    unified_list_state: ListState,
+    /// Order replies are shown in (`s`)
+    reply_sort: ReplySort,
+    /// Showing the settings overlay (`,`), with the highlighted row
+    pub show_settings: bool,
+    settings_selection: usize,
+    settings_opened_with: Option<SettingValues>,
+    /// How long background refreshes wait between fetches
+    pub refresh_interval: Duration,
+    /// Recent status and error messages, oldest first
+    pub status_log: VecDeque<LogEntry>,
+    /// Last status message added to (or deliberately kept out of) the log
+    logged_status: Option<String>,
+    /// Scroll offset of the open overlay popup (help or cross-post results)
+    pub popup_scroll: u16,
+    /// Rows the detail panel is scrolled down, reset when another post is shown
+    pub detail_scroll: u16,
+    /// Post `detail_scroll` applies to
+    detail_scroll_post: Option<String>,
+    /// Rows visible in the detail panel at the last draw, for paging
+    detail_height: u16,
     pub swapped_layout: bool,
     pub input_mode: InputMode,
     pub input_buffer: String,
+    /// Cursor position in `input_buffer`, in chars (not bytes)
+    pub input_cursor: usize,
+    /// Require a preview + second Enter before sending posts
+    pub confirm_before_post: bool,
+    /// Whether the post preview popup is showing
+    pub previewing: bool,
+    /// Reopen an empty composer after the previewed post is sent
+    pub keep_composing: bool,
+    /// Optional cap on list row length (otherwise the panel width is used)
+    pub list_preview_chars: Option<usize>,
+    /// Custom list row template (otherwise just the post text)
+    pub list_format: Option<ListFormat>,
+    /// Detail view shows the whole conversation instead of replies to the post
+    pub show_conversation: bool,
+    /// Refuse to send images without alt text
+    pub require_alt_text: bool,
+    /// Browse only: posting, replying, and account actions are disabled
+    pub read_only: bool,
+    /// Follow Bluesky through Jetstream instead of polling (`NDL_BSKY_STREAM=1`)
+    pub stream_bluesky: bool,
+    /// Alt text being collected for the images in a post
+    pub alt_prompt: Option<AltTextPrompt>,
+    /// Alt texts for the post being sent, consumed by the send functions
+    alt_texts: Vec<String>,
+    /// Mute/block awaiting confirmation in the status bar
+    pub pending_moderation: Option<PendingModeration>,
+    /// Last post or reply that failed to send, for `.` to retry
+    pub failed_send: Option<Draft>,
+    /// Post to delete once `InputMode::ConfirmDelete` is confirmed
+    pub pending_delete: Option<String>,
+    /// Waiting for the user to pick a platform to log in to
+    pub pending_login: bool,
+    /// First `g` of `gg` was pressed
+    pending_g: bool,
+    /// Network failures in a row; reset by any request that gets an answer
+    network_failures: u32,
+    /// Too many network failures: cached posts stay browsable, posting pauses
+    pub offline: bool,
+    /// Latest cross-post, shown as a summary popup once complete
+    pub cross_post: Option<CrossPostBatch>,
+    next_batch_id: u64,
+    /// The terminal was handed to a child process and needs a full redraw
+    pub needs_clear: bool,
+    /// Background refresh loop per platform, so a reconnect can replace it
+    refresh_tasks: HashMap<Platform, JoinHandle<()>>,
+    /// Background refresh loops skip their fetches while this is set (Ctrl+R)
+    refresh_paused: Arc<AtomicBool>,
+    /// `clients` as the scheduled-post task sees it, updated on reconnect
+    schedule_clients: Arc<Mutex<HashMap<Platform, Arc<Box<dyn SocialClient>>>>>,
     pub status_message: Option<String>,
     pub event_rx: mpsc::Receiver<AppEvent>,
     pub event_tx: mpsc::Sender<AppEvent>,
     pub current_platform: Platform,
     pub clients: HashMap<Platform, Arc<Box<dyn SocialClient>>>,
     pub platform_states: HashMap<Platform, PlatformState>,
+    /// Read markers saved between runs; `None` keeps nothing on disk
+    pub ui_state: Option<UiState>,
+    /// Posts saved for the next startup; `None` disables the cache
+    pub post_cache: Option<PostCache>,
+}
+
+/// The tunables an `App` starts with: read from the config file by
+/// `from_config`, or filled in directly by tests and embedders
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    pub refresh_interval: Duration,
+    /// Start with background refreshes paused
+    pub refresh_paused: bool,
+    pub read_only: bool,
+    pub confirm_before_post: bool,
+    pub require_alt_text: bool,
+    pub list_preview_chars: Option<usize>,
+    pub list_format: Option<ListFormat>,
+    pub stream_bluesky: bool,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            refresh_interval: REFRESH_INTERVAL,
+            refresh_paused: false,
+            read_only: false,
+            confirm_before_post: false,
+            require_alt_text: false,
+            list_preview_chars: None,
+            list_format: None,
+            stream_bluesky: false,
+        }
+    }
+}
+
+impl AppConfig {
+    /// The TUI preferences in `config`. An invalid `list_format` is logged
+    /// and ignored, and the refresh interval is kept above
+    /// `MIN_REFRESH_INTERVAL`. An interval of 0 starts with auto-refresh paused.
+    pub fn from_config(config: &Config) -> Self {
+        let list_format = config.list_format.as_deref().and_then(|template| {
+            ListFormat::parse(template)
+                .inspect_err(|e| warn!("Ignoring list_format: {}", e))
+                .ok()
+        });
+        Self {
+            refresh_interval: match config.refresh_interval_secs {
+                None | Some(0) => REFRESH_INTERVAL,
+                Some(secs) => Duration::from_secs(secs).max(MIN_REFRESH_INTERVAL),
+            },
+            refresh_paused: config.pause_auto_refresh || config.refresh_interval_secs == Some(0),
+            read_only: config.read_only,
+            confirm_before_post: config.confirm_before_post,
+            require_alt_text: config.require_alt_text,
+            list_preview_chars: config.list_preview_chars,
+            list_format,
+            stream_bluesky: false,
+        }
+    }
 }
 
 impl App {
-    pub fn new(clients: HashMap<Platform, Box<dyn SocialClient>>) -> Self {
+    /// An app over `clients` starting with `config`'s tunables
+    pub fn with_config(
+        clients: HashMap<Platform, Box<dyn SocialClient>>,
+        config: AppConfig,
+    ) -> Self {
         let (event_tx, event_rx) = mpsc::channel(32);
 
         let mut platform_states = HashMap::new();
@@ -98,16 +859,131 @@ impl App {
             running: true,
             active_panel: Panel::Threads,
             show_help: false,
+            show_profile: false,
+            unified_view: false,
+            unified_list_state: ListState::default(),
+            show_log: false,
+            reply_sort: ReplySort::default(),
+            show_settings: false,
+            settings_selection: 0,
+            settings_opened_with: None,
+            refresh_interval: config.refresh_interval,
+            status_log: VecDeque::new(),
+            logged_status: None,
+            popup_scroll: 0,
+            detail_scroll: 0,
+            detail_scroll_post: None,
+            detail_height: 0,
             swapped_layout: false,
             input_mode: InputMode::Normal,
             input_buffer: String::new(),
+            input_cursor: 0,
+            confirm_before_post: config.confirm_before_post,
+            previewing: false,
+            keep_composing: false,
+            list_preview_chars: config.list_preview_chars,
+            list_format: config.list_format,
+            show_conversation: false,
+            require_alt_text: config.require_alt_text,
+            read_only: config.read_only,
+            stream_bluesky: config.stream_bluesky,
+            alt_prompt: None,
+            alt_texts: Vec::new(),
+            pending_moderation: None,
+            pending_delete: None,
+            pending_login: false,
+            pending_g: false,
+            failed_send: None,
+            network_failures: 0,
+            offline: false,
+            cross_post: None,
+            next_batch_id: 0,
+            needs_clear: false,
+            refresh_tasks: HashMap::new(),
+            refresh_paused: Arc::new(AtomicBool::new(config.refresh_paused)),
+            schedule_clients: Arc::new(Mutex::new(clients_arc.clone())),
             status_message: None,
             event_rx,
             event_tx,
             current_platform,
             clients: clients_arc,
             platform_states,
+            ui_state: None,
+            post_cache: None,
+        }
+    }
+
+    /// Every connected platform's posts as `(platform, index)`, newest first.
+    /// Posts without a readable timestamp go last.
+    fn unified_entries(&self) -> Vec<(Platform, usize)> {
+        let mut entries: Vec<(Platform, usize)> = [Platform::Threads, Platform::Bluesky]
+            .into_iter()
+            .filter(|platform| self.clients.contains_key(platform))
+            .filter_map(|platform| Some((platform, self.platform_states.get(&platform)?)))
+            .flat_map(|(platform, state)| (0..state.posts.len()).map(move |i| (platform, i)))
+            .collect();
+        entries.sort_by_cached_key(|&(platform, i)| {
+            Reverse(
+                self.platform_states[&platform].posts[i]
+                    .timestamp
+                    .as_deref()
+                    .and_then(parse_timestamp),
+            )
+        });
+        entries
+    }
+
+    /// Where the current platform's selected post sits in the merged list
+    fn unified_position(&self, entries: &[(Platform, usize)]) -> Option<usize> {
+        let selected = self
+            .platform_states
+            .get(&self.current_platform)?
+            .list_state
+            .selected()?;
+        entries
+            .iter()
+            .position(|&entry| entry == (self.current_platform, selected))
+    }
+
+    /// Move through the merged list, wrapping like the single-platform list
+    fn step_unified(&mut self, down: bool) {
+        let entries = self.unified_entries();
+        if entries.is_empty() {
+            return;
+        }
+        let next = match self.unified_position(&entries) {
+            Some(i) if down => (i + 1) % entries.len(),
+            Some(i) => (i + entries.len() - 1) % entries.len(),
+            None => 0,
+        };
+        self.select_unified(entries[next]);
+    }
+
+    /// Select a merged-list post, switching to its platform so replies and
+    /// actions go to the right client
+    fn select_unified(&mut self, (platform, index): (Platform, usize)) {
+        if platform != self.current_platform {
+            if let Some(state) = self.platform_states.get_mut(&self.current_platform) {
+                state.cancel_replies();
+            }
+            self.current_platform = platform;
+        }
+        if let Some(state) = self.platform_states.get_mut(&platform) {
+            state.list_state.select(Some(index));
+        }
+    }
+
+    fn toggle_unified_view(&mut self) {
+        if !self.unified_view && self.clients.len() < 2 {
+            self.status_message = Some("Unified view needs more than one platform".to_string());
+            return;
         }
+        self.unified_view = !self.unified_view;
+        self.status_message = Some(if self.unified_view {
+            "Showing all platforms".to_string()
+        } else {
+            format!("Showing {}", self.current_platform)
+        });
     }
 
     /// Toggle to the next platform
@@ -122,6 +998,11 @@ impl App {
             .position(|p| *p == self.current_platform)
             .unwrap_or(0);
         let next_idx = (current_idx + 1) % platforms.len();
+
+        // Results for the platform we're leaving would only update hidden state
+        if let Some(state) = self.platform_states.get_mut(&self.current_platform) {
+            state.cancel_replies();
+        }
         self.current_platform = platforms[next_idx];
 
         self.status_message = Some(format!("Switched to {}", self.current_platform));
@@ -134,12 +1015,32 @@ impl App {
         let mut terminal = ratatui::init();
         terminal.clear()?;
 
-        // Fetch initial data for all platforms
-        self.fetch_initial_data().await;
+        let clients: Vec<_> = self
+            .clients
+            .iter()
+            .map(|(platform, client)| (*platform, client.clone()))
+            .collect();
+
+        // Show cached posts right away and fetch in the background, or wait
+        // for the network when there's nothing cached
+        if self.load_cached_posts() {
+            for (platform, client) in &clients {
+                self.spawn_fetch(*platform, client.clone());
+            }
+        } else {
+            self.fetch_initial_data().await;
+        }
+
+        for (platform, client) in clients {
+            self.spawn_profile(platform, client, false);
+        }
 
         // Start background refresh
         self.start_refresh_task();
 
+        // Send scheduled posts (including any that came due while ndl wasn't running)
+        self.start_schedule_task();
+
         let result = self.main_loop(&mut terminal).await;
 
         stdout().execute(LeaveAlternateScreen)?;
@@ -151,18 +1052,29 @@ impl App {
     async fn fetch_initial_data(&mut self) {
         self.status_message = Some("Loading...".to_string());
 
-        for (platform, client) in &self.clients {
-            let platform = *platform;
-            debug!("Fetching initial data for {}", platform);
-            match client.get_posts(Some(25)).await {
+        // Fetch every platform at once, filling each list as its posts arrive
+        let clients: Vec<_> = self
+            .clients
+            .iter()
+            .map(|(platform, client)| (*platform, client.clone()))
+            .collect();
+        let mut pending: FuturesUnordered<_> = clients
+            .into_iter()
+            .map(|(platform, client)| async move {
+                debug!("Fetching initial data for {}", platform);
+                (platform, client.get_posts(Some(25)).await)
+            })
+            .collect();
+
+        let mut fetched = Vec::new();
+        while let Some((platform, result)) = pending.next().await {
+            match result {
                 Ok(posts) => {
                     debug!("Initial fetch: {} posts for {}", posts.len(), platform);
                     if let Some(state) = self.platform_states.get_mut(&platform) {
-                        state.posts = posts;
-                        if !state.posts.is_empty() {
-                            state.list_state.select(Some(0));
-                        }
+                        state.set_posts(posts);
                     }
+                    fetched.push(platform);
                 }
                 Err(e) => {
                     error!("Failed to fetch initial data for {}: {}", platform, e);
@@ -170,30 +1082,218 @@ impl App {
             }
         }
 
+        for platform in fetched {
+            self.cache_posts(platform);
+        }
         self.status_message = None;
     }
 
-    fn start_refresh_task(&self) {
-        for (platform, client) in &self.clients {
-            let platform = *platform;
-            let client = client.clone();
-            let tx = self.event_tx.clone();
+    /// Fill each connected platform's list from the disk cache, marking it
+    /// stale. Returns whether any platform had cached posts.
+    fn load_cached_posts(&mut self) -> bool {
+        let Some(cache) = self.post_cache.as_ref() else {
+            return false;
+        };
 
-            tokio::spawn(async move {
-                loop {
-                    // this goes to 11
-                    tokio::time::sleep(std::time::Duration::from_secs(11)).await;
+        let mut loaded = false;
+        for platform in self.clients.keys() {
+            if let Some(posts) = cache.posts.get(platform).filter(|p| !p.is_empty())
+                && let Some(state) = self.platform_states.get_mut(platform)
+            {
+                debug!("Showing {} cached posts for {}", posts.len(), platform);
+                state.set_posts(posts.clone());
+                state.stale = true;
+                loaded = true;
+            }
+        }
+        loaded
+    }
+
+    /// Save a platform's current posts to the disk cache
+    fn cache_posts(&mut self, platform: Platform) {
+        let (Some(cache), Some(state)) = (
+            self.post_cache.as_mut(),
+            self.platform_states.get_mut(&platform),
+        ) else {
+            return;
+        };
+
+        state.stale = false;
+        cache.posts.insert(platform, state.posts.clone());
+        if let Err(e) = cache.save() {
+            warn!("Failed to save post cache: {}", e);
+        }
+    }
+
+    /// Fetch a platform's posts once in the background
+    fn spawn_fetch(&self, platform: Platform, client: Arc<Box<dyn SocialClient>>) {
+        let tx = self.event_tx.clone();
+        tokio::spawn(async move {
+            if let Ok(posts) = client.get_posts(Some(25)).await {
+                let _ = tx.send(AppEvent::PostsUpdated(platform, posts)).await;
+            }
+        });
+    }
+
+    /// Look up the logged-in account in the background, if the platform can.
+    /// `refresh` skips the client's cached profile.
+    fn spawn_profile(&self, platform: Platform, client: Arc<Box<dyn SocialClient>>, refresh: bool) {
+        if !client.supports_profiles() {
+            return;
+        }
+        let tx = self.event_tx.clone();
+        tokio::spawn(async move {
+            let result = if refresh {
+                client.refresh_profile().await
+            } else {
+                client.get_profile().await
+            };
+            let _ = tx.send(AppEvent::ProfileLoaded(platform, result)).await;
+        });
+    }
+
+    fn start_refresh_task(&mut self) {
+        let clients: Vec<_> = self
+            .clients
+            .iter()
+            .map(|(platform, client)| (*platform, client.clone()))
+            .collect();
+        for (platform, client) in clients {
+            self.spawn_refresh(platform, client);
+        }
+    }
+
+    /// Periodically refresh one platform's posts in the background,
+    /// replacing any refresh loop that platform already had
+    fn spawn_refresh(&mut self, platform: Platform, client: Arc<Box<dyn SocialClient>>) {
+        let tx = self.event_tx.clone();
+        let paused = self.refresh_paused.clone();
+        let stream = self.stream_bluesky && platform == Platform::Bluesky;
+        let interval = self.refresh_interval;
+
+        let handle = tokio::spawn(async move {
+            if stream {
+                match client.subscribe().await {
+                    Ok(mut posts) => {
+                        info!("Streaming new {} posts", platform);
+                        while let Some(post) = posts.next().await {
+                            if !paused.load(Ordering::Relaxed) {
+                                let _ = tx.send(AppEvent::PostStreamed(platform, post)).await;
+                            }
+                        }
+                        warn!("{} stream closed, falling back to polling", platform);
+                    }
+                    Err(e) => warn!("Couldn't stream {}, polling instead: {}", platform, e),
+                }
+            }
+
+            // this goes to 11
+            let mut delay = interval;
+            loop {
+                tokio::time::sleep(delay).await;
+                if paused.load(Ordering::Relaxed) {
+                    continue;
+                }
 
-                    if let Ok(posts) = client.get_posts(Some(25)).await {
+                match client.get_posts(Some(25)).await {
+                    Ok(posts) => {
+                        delay = interval;
                         let _ = tx.send(AppEvent::PostsUpdated(platform, posts)).await;
                     }
+                    Err(e) if e.is_network() => {
+                        // Back off while the connection is down instead of churning
+                        delay = (delay * 2).min(OFFLINE_REFRESH_INTERVAL);
+                        let _ = tx
+                            .send(AppEvent::RefreshFailed(platform, e.to_string()))
+                            .await;
+                    }
+                    Err(e) => debug!("Background refresh failed for {}: {}", platform, e),
                 }
-            });
+            }
+        });
+        if let Some(old) = self.refresh_tasks.insert(platform, handle) {
+            old.abort();
         }
     }
 
+    fn start_schedule_task(&self) {
+        let shared_clients = self.schedule_clients.clone();
+        let tx = self.event_tx.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(30));
+            loop {
+                interval.tick().await;
+
+                // Read each tick so platforms connected since startup get their posts
+                let clients = shared_clients
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .clone();
+                let platforms: Vec<Platform> = clients.keys().copied().collect();
+                let now = schedule::now();
+                let due = match ScheduleQueue::claim_due(&platforms, now) {
+                    Ok(due) => due,
+                    Err(e) => {
+                        error!("Failed to read scheduled posts: {}", e);
+                        continue;
+                    }
+                };
+
+                for post in due {
+                    let Some(client) = clients.get(&post.platform) else {
+                        continue;
+                    };
+                    if post.is_late(now) {
+                        warn!(
+                            "Sending scheduled {} post {}s late (was due {})",
+                            post.platform,
+                            post.seconds_late(now),
+                            schedule::format_local(post.send_at)
+                        );
+                    } else {
+                        info!("Sending scheduled {} post", post.platform);
+                    }
+                    // Problems no retry fixes fail the post at once; other
+                    // failures are retried on later ticks up to a limit
+                    let result = match unsendable(post.platform, &post.text) {
+                        Some(problem) => Err((problem, true)),
+                        None => publish(client, &post.text, &post.alt_texts)
+                            .await
+                            .map_err(|e| (e, false)),
+                    };
+                    if let Err((e, permanent)) = result {
+                        let gave_up = ScheduleQueue::send_failed(&post, &e, permanent)
+                            .unwrap_or_else(|err| {
+                                error!("Failed to record scheduled post failure: {}", err);
+                                false
+                            });
+                        let _ = tx
+                            .send(AppEvent::ScheduledSendFailed(post.platform, e, gave_up))
+                            .await;
+                        continue;
+                    }
+                    if let Err(e) = ScheduleQueue::remove_sent(&post) {
+                        error!("Failed to remove sent scheduled post: {}", e);
+                    }
+                    let draft = Draft {
+                        platform: post.platform,
+                        text: post.text,
+                        alt_texts: post.alt_texts,
+                        reply_to: None,
+                    };
+                    let _ = tx.send(AppEvent::SendResult(draft, Ok(()))).await;
+                }
+            }
+        });
+    }
+
     async fn main_loop(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
         while self.running {
+            if self.needs_clear {
+                terminal.clear()?;
+                self.needs_clear = false;
+            }
             terminal.draw(|frame| self.draw(frame))?;
             self.handle_events().await?;
         }
@@ -206,6 +1306,18 @@ impl App {
             .constraints([Constraint::Min(0), Constraint::Length(3)])
             .split(frame.area());
 
+        if self.clients.is_empty() {
+            self.draw_disconnected(frame, main_chunks[0]);
+            self.draw_status_bar(frame, main_chunks[1]);
+            if self.show_help {
+                self.draw_help(frame);
+            }
+            if self.show_log {
+                self.draw_status_log(frame);
+            }
+            return;
+        }
+
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
@@ -224,21 +1336,58 @@ impl App {
         if self.show_help {
             self.draw_help(frame);
         }
+        if self.show_log {
+            self.draw_status_log(frame);
+        }
+        if self.show_settings {
+            self.draw_settings(frame);
+        }
+        if self.show_profile {
+            self.draw_profile(frame);
+        }
 
-        if self.input_mode == InputMode::Replying
-            || self.input_mode == InputMode::Posting
-            || self.input_mode == InputMode::CrossPosting
-        {
+        if self.cross_post.as_ref().is_some_and(|b| b.complete) {
+            self.draw_cross_post_summary(frame);
+        }
+
+        if self.previewing {
+            self.draw_preview(frame);
+        } else if !matches!(
+            self.input_mode,
+            InputMode::Normal | InputMode::ConfirmDelete
+        ) {
             self.draw_input(frame);
         }
     }
 
+    /// Empty state shown when no platform is connected
+    fn draw_disconnected(&self, frame: &mut Frame, area: Rect) {
+        let text = "No connected platforms\n\nPress L to log in, or C to retry connecting";
+        let paragraph = Paragraph::new(text)
+            .alignment(Alignment::Center)
+            .block(Block::default().title(" ndl ").borders(Borders::ALL));
+
+        // Roughly center the message vertically
+        let inner_top = area.height.saturating_sub(5) / 2;
+        let area = Rect {
+            y: area.y + inner_top.min(area.height),
+            height: area.height.saturating_sub(inner_top).min(5),
+            ..area
+        };
+        frame.render_widget(paragraph, area);
+    }
+
     fn draw_status_bar(&self, frame: &mut Frame, area: Rect) {
-        let mut status = self
-            .status_message
-            .as_deref()
-            .unwrap_or("? for help | p to post | r to reply | R to refresh")
-            .to_string();
+        let hint = if self.offline {
+            "showing cached posts | R to retry"
+        } else if self.read_only {
+            "read-only | ? for help | R to refresh"
+        } else if self.reply_restriction().is_some() {
+            "? for help | p to post | replies restricted | R to refresh"
+        } else {
+            "? for help | p to post | r to reply | R to refresh"
+        };
+        let mut status = self.status_message.as_deref().unwrap_or(hint).to_string();
 
         // Add platform indicator if multi-platform mode is active
         if !self.clients.is_empty() {
@@ -246,18 +1395,34 @@ impl App {
                 .clients
                 .keys()
                 .map(|p| {
-                    if *p == self.current_platform {
+                    let mut label = if *p == self.current_platform {
                         format!("[{}]", p) // Active platform in brackets
                     } else {
                         p.to_string()
+                    };
+                    let unread = match (&self.ui_state, self.platform_states.get(p)) {
+                        (Some(ui_state), Some(state)) => ui_state.unread_count(*p, &state.posts),
+                        _ => 0,
+                    };
+                    if unread > 0 {
+                        label.push_str(&format!(" ({} new)", unread));
                     }
+                    label
                 })
                 .collect();
             let platform_str = platforms.join(" ");
             status = format!("{} | {}", platform_str, status);
         }
+        if self.refresh_paused.load(Ordering::Relaxed) {
+            status = format!("PAUSED | {}", status);
+        }
+        if self.offline {
+            status = format!("OFFLINE | {}", status);
+        }
 
-        let style = if self.status_message.is_some() {
+        let style = if self.offline {
+            Style::default().fg(Color::Red)
+        } else if self.status_message.is_some() {
             Style::default().fg(Color::Yellow)
         } else {
             Style::default().fg(Color::DarkGray)
@@ -273,7 +1438,13 @@ impl App {
     fn draw_input(&self, frame: &mut Frame) {
         let area = frame.area();
         let popup_width = 60.min(area.width.saturating_sub(4));
-        let popup_height = 5;
+        // Grow with the text, scrolling once it's taller than the cap
+        let inner_width = popup_width.saturating_sub(2).max(1) as usize;
+        let (rows, (cursor_row, cursor_col)) =
+            layout_input(&self.input_buffer, self.input_cursor, inner_width);
+        let text_rows = rows.len().max(cursor_row + 1);
+        let popup_height =
+            (text_rows.clamp(MIN_INPUT_ROWS, MAX_INPUT_ROWS) as u16 + 2).min(area.height);
         let popup_area = Rect {
             x: area.width.saturating_sub(popup_width) / 2,
             y: area.height.saturating_sub(popup_height) / 2,
@@ -283,29 +1454,376 @@ impl App {
 
         frame.render_widget(Clear, popup_area);
 
-        let title = match self.input_mode {
-            InputMode::Replying => " Reply (Enter to send, Esc to cancel) ",
-            InputMode::Posting => " New Post (Enter to send, Esc to cancel) ",
-            InputMode::CrossPosting => " Cross-Post to All (Enter to send, Esc to cancel) ",
-            InputMode::Normal => "",
+        const SEND_HINT: &str = "Ctrl+S to send, Esc to cancel";
+        let (label, hint) = match (&self.alt_prompt, self.input_mode) {
+            (Some(prompt), _) => (
+                format!(
+                    " Alt text for image {}/{}",
+                    prompt.alts.len() + 1,
+                    prompt.image_count
+                ),
+                "Enter to continue, Esc to edit post",
+            ),
+            (None, InputMode::Replying) => {
+                let handle = self
+                    .platform_states
+                    .get(&self.current_platform)
+                    .and_then(|state| state.reply_target())
+                    .and_then(|post| post.author_handle.as_deref());
+                match handle {
+                    Some(handle) => (format!(" Reply to @{}", handle), SEND_HINT),
+                    None => (" Reply".to_string(), SEND_HINT),
+                }
+            }
+            (None, InputMode::Posting) => (" New Post".to_string(), SEND_HINT),
+            (None, InputMode::CrossPosting) => (" Cross-Post to All".to_string(), SEND_HINT),
+            (None, InputMode::Goto) => (
+                " Open post: paste a link".to_string(),
+                "Enter to open, Esc to cancel",
+            ),
+            (None, InputMode::Normal | InputMode::ConfirmDelete) => (String::new(), ""),
         };
+        let mut title = vec![Span::raw(label)];
+        if let Some((count, limit)) = self.compose_count() {
+            let style = if count > limit {
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            title.push(Span::raw(" "));
+            title.push(Span::styled(format!("{}/{}", count, limit), style));
+        }
+        title.push(Span::raw(format!(" ({}) ", hint)));
 
-        let input = Paragraph::new(self.input_buffer.as_str())
+        // Wrapped by `layout_input` rather than the paragraph so the cursor
+        // lands where the text does
+        let inner_height = popup_area.height.saturating_sub(2).max(1) as usize;
+        let scroll = (cursor_row + 1).saturating_sub(inner_height);
+
+        let input = Paragraph::new(rows.join("\n"))
             .block(
                 Block::default()
-                    .title(title)
+                    .title(Line::from(title))
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(Color::Green)),
             )
-            .wrap(Wrap { trim: false });
+            .scroll((scroll as u16, 0));
 
         frame.render_widget(input, popup_area);
+        frame.set_cursor_position((
+            popup_area.x + 1 + cursor_col as u16,
+            popup_area.y + 1 + (cursor_row - scroll) as u16,
+        ));
+    }
+
+    fn draw_preview(&self, frame: &mut Frame) {
+        let area = frame.area();
+        let popup_width = 60.min(area.width.saturating_sub(4));
+        let popup_height = 12.min(area.height);
+        let popup_area = Rect {
+            x: area.width.saturating_sub(popup_width) / 2,
+            y: area.height.saturating_sub(popup_height) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let target = match self.input_mode {
+            InputMode::CrossPosting => {
+                let platforms: Vec<String> = self.clients.keys().map(|p| p.to_string()).collect();
+                platforms.join(", ")
+            }
+            _ => self.current_platform.to_string(),
+        };
+
+        let content = match self.compose_count() {
+            Some((count, limit)) => format!(
+                "{}\n\n--- {}/{} chars -> {} ---",
+                self.input_buffer, count, limit, target
+            ),
+            None => format!("{}\n\n--- {} ---", self.input_buffer, target),
+        };
+
+        let preview = Paragraph::new(content)
+            .block(
+                Block::default()
+                    .title(" Preview (Enter to send, Esc to edit) ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Yellow)),
+            )
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(preview, popup_area);
+    }
+
+    /// Overlay listing recent status messages, newest first, with full error text
+    fn draw_status_log(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+        let popup_area = Rect {
+            x: area.width / 10,
+            y: area.height / 10,
+            width: area.width - area.width / 5,
+            height: area.height - area.height / 5,
+        };
+
+        let text = if self.status_log.is_empty() {
+            "No messages yet".to_string()
+        } else {
+            self.status_log
+                .iter()
+                .rev()
+                .map(|entry| format!("{}  {}", entry.at.format("%H:%M:%S"), entry.message))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        self.clamp_popup_scroll(&text, popup_area);
+
+        frame.render_widget(Clear, popup_area);
+        let log = Paragraph::new(text)
+            .block(
+                Block::default()
+                    .title(" Message log (j/k to scroll, Esc to close) ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Yellow)),
+            )
+            .wrap(Wrap { trim: false })
+            .scroll((self.popup_scroll, 0));
+
+        frame.render_widget(log, popup_area);
+    }
+
+    fn draw_settings(&self, frame: &mut Frame) {
+        let area = frame.area();
+        let popup_width = 44;
+        let popup_height = SETTINGS.len() as u16 + 4;
+        let popup_area = Rect {
+            x: area.width.saturating_sub(popup_width) / 2,
+            y: area.height.saturating_sub(popup_height) / 2,
+            width: popup_width.min(area.width),
+            height: popup_height.min(area.height),
+        };
+
+        let lines: Vec<Line> = SETTINGS
+            .iter()
+            .enumerate()
+            .map(|(i, setting)| {
+                let text = format!(" {}", self.setting_label(*setting));
+                if i == self.settings_selection {
+                    Line::styled(
+                        text,
+                        Style::default()
+                            .fg(Color::Black)
+                            .bg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    Line::raw(text)
+                }
+            })
+            .chain([
+                Line::raw(""),
+                Line::styled(
+                    " Enter/Space change, Esc save & close",
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])
+            .collect();
+
+        frame.render_widget(Clear, popup_area);
+        let settings = Paragraph::new(lines).block(
+            Block::default()
+                .title(" Settings ")
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow)),
+        );
+        frame.render_widget(settings, popup_area);
+    }
+
+    /// A settings overlay row: the option and its current value
+    fn setting_label(&self, setting: Setting) -> String {
+        let check = |on: bool| if on { "[x]" } else { "[ ]" };
+        match setting {
+            Setting::AutoRefresh => format!(
+                "{} Auto-refresh",
+                check(!self.refresh_paused.load(Ordering::Relaxed))
+            ),
+            Setting::RefreshInterval => {
+                format!("    Refresh every < {}s >", self.refresh_interval.as_secs())
+            }
+            Setting::ConfirmBeforePost => {
+                format!("{} Confirm before posting", check(self.confirm_before_post))
+            }
+            Setting::RequireAltText => {
+                format!("{} Require alt text", check(self.require_alt_text))
+            }
+            Setting::ReadOnly => format!("{} Read-only mode", check(self.read_only)),
+        }
+    }
+
+    /// Flip a setting, or step the refresh interval to the next choice
+    fn change_setting(&mut self, setting: Setting) {
+        match setting {
+            Setting::AutoRefresh => {
+                let paused = self.refresh_paused.load(Ordering::Relaxed);
+                self.refresh_paused.store(!paused, Ordering::Relaxed);
+            }
+            Setting::RefreshInterval => {
+                let next = REFRESH_INTERVALS
+                    .iter()
+                    .position(|d| *d == self.refresh_interval)
+                    .map_or(0, |i| (i + 1) % REFRESH_INTERVALS.len());
+                self.refresh_interval = REFRESH_INTERVALS[next];
+            }
+            Setting::ConfirmBeforePost => self.confirm_before_post = !self.confirm_before_post,
+            Setting::RequireAltText => self.require_alt_text = !self.require_alt_text,
+            Setting::ReadOnly => self.read_only = !self.read_only,
+        }
+    }
+
+    fn handle_settings_key(&mut self, key: KeyCode) -> Option<Action> {
+        match key {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.settings_selection = (self.settings_selection + 1) % SETTINGS.len();
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.settings_selection =
+                    (self.settings_selection + SETTINGS.len() - 1) % SETTINGS.len();
+            }
+            KeyCode::Enter | KeyCode::Char(' ') | KeyCode::Char('l') | KeyCode::Right => {
+                self.change_setting(SETTINGS[self.settings_selection]);
+            }
+            KeyCode::Esc | KeyCode::Char(',') | KeyCode::Char('q') => {
+                self.show_settings = false;
+                return Some(Action::SaveSettings);
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn setting_values(&self) -> SettingValues {
+        SettingValues {
+            paused: self.refresh_paused.load(Ordering::Relaxed),
+            refresh_interval: self.refresh_interval,
+            confirm_before_post: self.confirm_before_post,
+            require_alt_text: self.require_alt_text,
+            read_only: self.read_only,
+        }
+    }
+
+    /// Persist the settings the overlay changed, restarting the refresh loops
+    /// if the interval changed. Untouched settings keep their config values,
+    /// so e.g. `--read-only` isn't written back.
+    fn save_settings(&mut self) {
+        let now = self.setting_values();
+        let Some(before) = self.settings_opened_with.take() else {
+            return;
+        };
+        if now == before {
+            return;
+        }
+        let mut config = match Config::load() {
+            Ok(config) => config,
+            Err(e) => {
+                self.status_message = Some(format!("Couldn't load config: {}", e));
+                return;
+            }
+        };
+        if now.paused != before.paused {
+            config.pause_auto_refresh = now.paused;
+            // Otherwise a saved interval of 0 would pause it again next start
+            if !now.paused && config.refresh_interval_secs == Some(0) {
+                config.refresh_interval_secs = None;
+            }
+        }
+        if now.refresh_interval != before.refresh_interval {
+            config.refresh_interval_secs = Some(now.refresh_interval.as_secs());
+        }
+        if now.confirm_before_post != before.confirm_before_post {
+            config.confirm_before_post = now.confirm_before_post;
+        }
+        if now.require_alt_text != before.require_alt_text {
+            config.require_alt_text = now.require_alt_text;
+        }
+        if now.read_only != before.read_only {
+            config.read_only = now.read_only;
+        }
+
+        self.status_message = Some(match config.save() {
+            Ok(()) => "Settings saved".to_string(),
+            Err(e) => format!("Couldn't save settings: {}", e),
+        });
+        if now.refresh_interval != before.refresh_interval {
+            self.start_refresh_task();
+        }
+    }
+
+    /// Add the current status message to the log if it's new
+    fn log_status(&mut self) {
+        let Some(message) = &self.status_message else {
+            return;
+        };
+        if self.logged_status.as_ref() == Some(message) {
+            return;
+        }
+        self.logged_status = Some(message.clone());
+        if self.status_log.len() == STATUS_LOG_LEN {
+            self.status_log.pop_front();
+        }
+        self.status_log.push_back(LogEntry {
+            at: chrono::Local::now(),
+            message: message.clone(),
+        });
+    }
+
+    fn draw_cross_post_summary(&mut self, frame: &mut Frame) {
+        let Some(batch) = &self.cross_post else {
+            return;
+        };
+
+        let lines: Vec<String> = batch
+            .platforms
+            .iter()
+            .map(|platform| match batch.results.get(platform) {
+                Some(Ok(())) => format!("✓ {}", platform),
+                Some(Err(e)) => format!("✗ {}: {}", platform, e),
+                None => format!("✗ {}: no response (timed out)", platform),
+            })
+            .collect();
+
+        let area = frame.area();
+        let popup_width = 60.min(area.width.saturating_sub(4));
+        let popup_height = (lines.len() as u16 + 2).max(6).min(area.height);
+        let popup_area = Rect {
+            x: area.width.saturating_sub(popup_width) / 2,
+            y: area.height.saturating_sub(popup_height) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        let text = lines.join("\n");
+        self.clamp_popup_scroll(&text, popup_area);
+
+        frame.render_widget(Clear, popup_area);
+        let summary = Paragraph::new(text)
+            .block(
+                Block::default()
+                    .title(" Cross-post results (j/k to scroll, Esc to close) ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Yellow)),
+            )
+            .wrap(Wrap { trim: false })
+            .scroll((self.popup_scroll, 0));
+
+        frame.render_widget(summary, popup_area);
     }
 
-    fn draw_help(&self, frame: &mut Frame) {
+    fn draw_help(&mut self, frame: &mut Frame) {
         let area = frame.area();
         let popup_width = 48;
-        let popup_height = 19;
+        let popup_height = 46;
         let popup_area = Rect {
             x: area.width.saturating_sub(popup_width) / 2,
             y: area.height.saturating_sub(popup_height) / 2,
@@ -313,36 +1831,93 @@ impl App {
             height: popup_height.min(area.height),
         };
 
-        let help_text = "\
-j / Down     Move down (or select reply)
-k / Up       Move up (or select reply)
-h / Left     Focus left panel
-l / Right    Focus right panel
-t            Swap panel positions
-p            Create new post
-P            Cross-post to all platforms
-r            Reply to thread or reply
-R            Refresh threads
-] / Tab      Switch platform (multi-platform)
-Enter        Select item
-Esc          Back / Cancel / Deselect
-q            Quit
-?            Toggle help";
+        // Keys the current platform can't use are left out
+        let help_text = HELP_LINES
+            .iter()
+            .filter(|(_, feature)| feature.is_none_or(|f| self.supports(f)))
+            .map(|(line, _)| *line)
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.clamp_popup_scroll(&help_text, popup_area);
 
         frame.render_widget(Clear, popup_area);
         let help = Paragraph::new(help_text)
             .block(
                 Block::default()
-                    .title(" Help ")
+                    .title(" Help (j/k to scroll, Esc to close) ")
                     .title_alignment(Alignment::Center)
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(Color::Yellow)),
             )
-            .alignment(Alignment::Left);
+            .alignment(Alignment::Left)
+            .scroll((self.popup_scroll, 0));
 
         frame.render_widget(help, popup_area);
     }
 
+    /// The current platform's account, so it's easy to check which one is active
+    fn draw_profile(&self, frame: &mut Frame) {
+        let area = frame.area();
+        let popup_width = 56;
+        let popup_height = 14;
+        let popup_area = Rect {
+            x: area.width.saturating_sub(popup_width) / 2,
+            y: area.height.saturating_sub(popup_height) / 2,
+            width: popup_width.min(area.width),
+            height: popup_height.min(area.height),
+        };
+
+        let profile = self
+            .platform_states
+            .get(&self.current_platform)
+            .and_then(|state| state.profile.as_ref());
+
+        frame.render_widget(Clear, popup_area);
+        let popup = Paragraph::new(profile_text(profile))
+            .block(
+                Block::default()
+                    .title(format!(
+                        " {} account (r to refresh, Esc to close) ",
+                        self.current_platform
+                    ))
+                    .title_alignment(Alignment::Center)
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Yellow)),
+            )
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(popup, popup_area);
+    }
+
+    /// Keep the popup scroll within the text shown in a bordered `area`
+    fn clamp_popup_scroll(&mut self, text: &str, area: Rect) {
+        let inner_width = area.width.saturating_sub(2).max(1) as usize;
+        let rows: usize = text
+            .lines()
+            .map(|line| line.chars().count().div_ceil(inner_width).max(1))
+            .sum();
+        let max_scroll = rows.saturating_sub(area.height.saturating_sub(2) as usize);
+        self.popup_scroll = self.popup_scroll.min(max_scroll as u16);
+    }
+
+    /// Scroll an overlay popup with j/k; returns true when the key closes it
+    fn handle_popup_key(&mut self, key: KeyCode) -> bool {
+        match key {
+            KeyCode::Esc | KeyCode::Char('?') => {
+                self.popup_scroll = 0;
+                return true;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.popup_scroll = self.popup_scroll.saturating_add(1);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.popup_scroll = self.popup_scroll.saturating_sub(1);
+            }
+            _ => {}
+        }
+        false
+    }
+
     fn draw_threads_list(&mut self, frame: &mut Frame, area: Rect) {
         let is_active = self.active_panel == Panel::Threads;
         let border_style = if is_active {
@@ -351,6 +1926,46 @@ q            Quit
             Style::default().fg(Color::DarkGray)
         };
 
+        // Fit rows to the panel (minus borders and highlight symbol), capped by config
+        let available = usize::from(area.width.saturating_sub(4));
+        let max_chars = self
+            .list_preview_chars
+            .map_or(available, |cap| cap.min(available));
+        let now = chrono::Utc::now();
+
+        if self.unified_view {
+            let entries = self.unified_entries();
+            let items: Vec<ListItem> = entries
+                .iter()
+                .map(|&(platform, i)| {
+                    let post = &self.platform_states[&platform].posts[i];
+                    let row = format!(
+                        "[{}] {}",
+                        platform.tag(),
+                        self.list_row(post, platform, now)
+                    );
+                    ListItem::new(Line::from(truncate_preview(&row, max_chars)))
+                })
+                .collect();
+            let list = List::new(items)
+                .block(
+                    Block::default()
+                        .title(format!(" All platforms ({}) ", entries.len()))
+                        .borders(Borders::ALL)
+                        .border_style(border_style),
+                )
+                .highlight_style(
+                    Style::default()
+                        .bg(Color::DarkGray)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .highlight_symbol("> ");
+            self.unified_list_state
+                .select(self.unified_position(&entries));
+            frame.render_stateful_widget(list, area, &mut self.unified_list_state);
+            return;
+        }
+
         let Some(state) = self.platform_states.get(&self.current_platform) else {
             return;
         };
@@ -359,29 +1974,31 @@ q            Quit
             .posts
             .iter()
             .map(|p| {
-                let display = if let Some(text) = p.text.as_deref() {
-                    let truncated: String = text.chars().take(50).collect();
-                    if text.len() > 50 {
-                        format!("{}...", truncated)
-                    } else {
-                        truncated
-                    }
-                } else {
-                    // No text - show media type indicator
-                    match p.media_type.as_deref() {
-                        Some("REPOST_FACADE") => "[repost]".to_string(),
-                        Some("IMAGE") => "[image]".to_string(),
-                        Some("VIDEO") => "[video]".to_string(),
-                        Some("CAROUSEL_ALBUM") => "[carousel]".to_string(),
-                        Some(other) => format!("[{}]", other.to_lowercase()),
-                        None => "[no text]".to_string(),
-                    }
-                };
-                ListItem::new(Line::from(display))
+                let row = self.list_row(p, self.current_platform, now);
+                ListItem::new(Line::from(truncate_preview(&row, max_chars)))
             })
             .collect();
 
-        let title = format!(" {} ({}) ", self.current_platform, state.posts.len());
+        let account = state
+            .profile
+            .as_ref()
+            .map(|p| format!(" @{}", p.handle))
+            .unwrap_or_default();
+        let title = if state.stale {
+            format!(
+                " {}{} ({}, cached) ",
+                self.current_platform,
+                account,
+                state.posts.len()
+            )
+        } else {
+            format!(
+                " {}{} ({}) ",
+                self.current_platform,
+                account,
+                state.posts.len()
+            )
+        };
         let list = List::new(items)
             .block(
                 Block::default()
@@ -401,7 +2018,34 @@ q            Quit
         }
     }
 
-    fn draw_detail(&self, frame: &mut Frame, area: Rect) {
+    /// A post's list row before truncation: its text (or a media placeholder),
+    /// run through the `list_format` template if there is one
+    fn list_row(
+        &self,
+        post: &Post,
+        platform: Platform,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> String {
+        let display = if let Some(text) = post.text.as_deref() {
+            text.to_string()
+        } else {
+            // No text - show media type indicator
+            match post.media_type.as_deref() {
+                Some("REPOST_FACADE") => "[repost]".to_string(),
+                Some("IMAGE") => "[image]".to_string(),
+                Some("VIDEO") => "[video]".to_string(),
+                Some("CAROUSEL_ALBUM") => "[carousel]".to_string(),
+                Some(other) => format!("[{}]", other.to_lowercase()),
+                None => "[no text]".to_string(),
+            }
+        };
+        match &self.list_format {
+            Some(format) => format.render(post, platform, &display, now),
+            None => display,
+        }
+    }
+
+    fn draw_detail(&mut self, frame: &mut Frame, area: Rect) {
         let is_active = self.active_panel == Panel::Detail;
         let border_style = if is_active {
             Style::default().fg(Color::Cyan)
@@ -413,12 +2057,27 @@ q            Quit
             if let Some(idx) = state.list_state.selected() {
                 if let Some(post) = state.posts.get(idx) {
                     let author = post.author_handle.as_deref().unwrap_or("unknown");
-                    let timestamp = post.timestamp.as_deref().unwrap_or("");
-                    let text = if let Some(t) = post.text.as_deref() {
-                        t.to_string()
+                    let timestamp = post
+                        .timestamp
+                        .as_deref()
+                        .map(|ts| humanize_timestamp(ts, chrono::Utc::now()))
+                        .unwrap_or_default();
+
+                    let mut content = StyledText::default();
+                    content.push_plain(&format!("@{}\n{}", author, timestamp));
+                    if let Some(engagement) = format_engagement(post) {
+                        content.push_plain(&format!("\n{}", engagement));
+                    }
+                    if let Some(note) = post.reply_note() {
+                        content.push_plain(&format!("\n{}", note));
+                    }
+                    content.push_plain("\n\n");
+
+                    if let Some(t) = post.text.as_deref() {
+                        content.push_post_text(t, &post.text_facets());
                     } else {
                         // No text - show media type indicator with permalink
-                        match post.media_type.as_deref() {
+                        let text = match post.media_type.as_deref() {
                             Some("REPOST_FACADE") => {
                                 let link = post.permalink.as_deref().unwrap_or("");
                                 format!("[Repost]\n{}", link)
@@ -428,19 +2087,32 @@ q            Quit
                             Some("CAROUSEL_ALBUM") => "[Carousel post]".to_string(),
                             Some(other) => format!("[{} post]", other),
                             None => "[No text]".to_string(),
-                        }
-                    };
-
-                    let mut content = format!("@{}\n{}\n\n{}", author, timestamp, text);
+                        };
+                        content.push_post_text(&text, &detect_links(&text));
+                    }
 
                     // Add replies section
-                    if !state.selected_replies.is_empty() {
-                        content.push_str("\n\n--- Replies (j/k to select, r to reply) ---\n");
+                    if state.unavailable.contains(&post.id) {
+                        content.push_plain("\n\n--- This post is no longer available ---");
+                    } else if !state.selected_replies.is_empty() {
+                        let heading = if self.show_conversation {
+                            "Conversation"
+                        } else {
+                            "Replies"
+                        };
+                        let order = match self.reply_sort {
+                            ReplySort::Api => String::new(),
+                            sort => format!(", {}", sort.label()),
+                        };
+                        content.push_plain(&format!(
+                            "\n\n--- {}{} (j/k to select, r to reply, s to sort) ---\n",
+                            heading, order
+                        ));
                         let selected_idx = state.reply_selection;
                         fn format_replies(
                             replies: &[ReplyThread],
                             indent: usize,
-                            out: &mut String,
+                            out: &mut StyledText,
                             counter: &mut usize,
                             selected: Option<usize>,
                         ) {
@@ -453,10 +2125,14 @@ q            Quit
                                 } else {
                                     "  "
                                 };
-                                out.push_str(&format!(
-                                    "\n{}{}@{}: {}\n",
-                                    marker, prefix, user, text
-                                ));
+                                if reply.post.is_available() {
+                                    out.push_plain(&format!("\n{}{}@{}: ", marker, prefix, user));
+                                    out.push_post_text(text, &reply.post.text_facets());
+                                    out.push_plain("\n");
+                                } else {
+                                    // Blocked/deleted placeholder: no author to show
+                                    out.push_plain(&format!("\n{}{}{}\n", marker, prefix, text));
+                                }
                                 *counter += 1;
                                 if !reply.replies.is_empty() {
                                     format_replies(
@@ -478,34 +2154,75 @@ q            Quit
                             selected_idx,
                         );
                     } else if state.loaded_replies_for.as_ref() == Some(&post.id) {
-                        content.push_str("\n\n--- No replies ---");
+                        content.push_plain("\n\n--- No replies ---");
                     } else {
-                        content.push_str("\n\n--- Loading replies... ---");
+                        content.push_plain("\n\n--- Loading replies... ---");
                     }
 
-                    content
+                    content.into_text()
                 } else {
-                    "No post selected".to_string()
+                    Text::from(self.empty_detail(state))
                 }
             } else {
-                "No post selected".to_string()
+                Text::from(self.empty_detail(state))
             }
         } else {
-            "No post selected".to_string()
+            Text::from("No post selected")
         };
 
+        // Start each post at the top, and keep the offset within its text
+        let shown = self
+            .platform_states
+            .get(&self.current_platform)
+            .and_then(|state| state.list_state.selected().and_then(|i| state.posts.get(i)))
+            .map(|post| post.id.clone());
+        if shown != self.detail_scroll_post {
+            self.detail_scroll = 0;
+            self.detail_scroll_post = shown;
+        }
+        let inner_width = area.width.saturating_sub(2).max(1) as usize;
+        let rows: usize = content
+            .lines
+            .iter()
+            .map(|line| line.width().div_ceil(inner_width).max(1))
+            .sum();
+        self.detail_height = area.height.saturating_sub(2);
+        let max_scroll = rows.saturating_sub(self.detail_height as usize);
+        self.detail_scroll = self.detail_scroll.min(max_scroll as u16);
+
+        let title = if max_scroll > 0 {
+            format!(" Detail ({}/{}) ", self.detail_scroll, max_scroll)
+        } else {
+            " Detail ".to_string()
+        };
         let paragraph = Paragraph::new(content)
             .block(
                 Block::default()
-                    .title(" Detail ")
+                    .title(title)
                     .borders(Borders::ALL)
                     .border_style(border_style),
             )
-            .wrap(Wrap { trim: false });
+            .wrap(Wrap { trim: false })
+            .scroll((self.detail_scroll, 0));
 
         frame.render_widget(paragraph, area);
     }
 
+    /// What the detail pane says with no post to show: still loading, an
+    /// empty feed, or just nothing selected
+    fn empty_detail(&self, state: &PlatformState) -> String {
+        let platform = self.current_platform;
+        if !state.posts.is_empty() {
+            "No post selected".to_string()
+        } else if !state.fetched {
+            format!("Loading {} posts...", platform)
+        } else if self.read_only || self.offline {
+            format!("No {} posts yet", platform)
+        } else {
+            format!("No {} posts yet — press p to create one", platform)
+        }
+    }
+
     async fn handle_events(&mut self) -> io::Result<()> {
         // Check for app events (refresh, reply results)
         while let Ok(event) = self.event_rx.try_recv() {
@@ -513,37 +2230,192 @@ q            Quit
                 AppEvent::PostsUpdated(platform, posts) => {
                     debug!("Received {} posts for {}", posts.len(), platform);
                     if let Some(state) = self.platform_states.get_mut(&platform) {
-                        state.posts = posts;
-                        if state.list_state.selected().is_none() && !state.posts.is_empty() {
-                            state.list_state.select(Some(0));
-                        }
+                        state.set_posts(posts);
                     }
+                    self.cache_posts(platform);
                     if platform == self.current_platform {
-                        self.status_message = Some(format!("{} refreshed", platform));
+                        // Routine; keep it out of the log
+                        let status = format!("{} refreshed", platform);
+                        self.logged_status = Some(status.clone());
+                        self.status_message = Some(status);
                     }
+                    self.record_network(true);
                 }
-                AppEvent::PostResult(platform, result) => match result {
-                    Ok(()) => {
-                        info!("Post sent successfully to {}", platform);
-                        self.status_message = Some(format!("Posted to {}!", platform));
+                AppEvent::PostStreamed(platform, post) => {
+                    debug!("Streamed post {} for {}", post.id, platform);
+                    if let Some(state) = self.platform_states.get_mut(&platform) {
+                        state.prepend_post(post);
                     }
-                    Err(ref e) => {
-                        error!("Post to {} failed: {}", platform, e);
-                        self.status_message = Some(format!("{} error: {}", platform, e));
+                    self.cache_posts(platform);
+                }
+                AppEvent::MorePostsLoaded(platform, result) => {
+                    let Some(state) = self.platform_states.get_mut(&platform) else {
+                        continue;
+                    };
+                    state.loading_more = false;
+                    match result {
+                        Ok((posts, next)) => {
+                            debug!("Loaded {} more posts for {}", posts.len(), platform);
+                            let before = state.posts.len();
+                            state.append_page(posts, next);
+                            let added = state.posts.len() - before;
+                            self.status_message = Some(if added == 0 {
+                                "No more posts".to_string()
+                            } else {
+                                format!("Loaded {} more posts", added)
+                            });
+                            self.record_network(true);
+                        }
+                        Err(PlatformError::NotImplemented(_)) => state.no_more = true,
+                        Err(e) => {
+                            error!("Loading more {} posts failed: {}", platform, e);
+                            self.status_message = Some(format!("Couldn't load more: {}", e));
+                            if e.is_network() {
+                                self.record_network(false);
+                            }
+                        }
+                    }
+                }
+                AppEvent::RefreshFailed(platform, e) => {
+                    debug!("Background refresh failed for {}: {}", platform, e);
+                    self.record_network(false);
+                }
+                AppEvent::SendResult(draft, result) => self.send_finished(draft, result),
+                AppEvent::PostOpened(link, result) => self.post_opened(link, result),
+                AppEvent::ScheduledSendFailed(platform, e, gave_up) => {
+                    error!("Scheduled post to {} failed: {}", platform, e);
+                    self.status_message = Some(if gave_up {
+                        format!(
+                            "Scheduled {} post failed, gave up (kept in scheduled.json): {}",
+                            platform, e
+                        )
+                    } else {
+                        format!("Scheduled {} post failed, will retry: {}", platform, e)
+                    });
+                }
+                AppEvent::CrossPostResult(id, platform, result) => {
+                    match &result {
+                        Ok(()) => info!("Cross-post sent successfully to {}", platform),
+                        Err(e) => error!("Cross-post to {} failed: {}", platform, e),
+                    }
+                    // Results from an older batch are only logged
+                    if let Some(batch) = self.cross_post.as_mut().filter(|b| b.id == id) {
+                        batch.results.insert(platform, result);
+                        self.status_message = Some(batch.summary());
+                    }
+                }
+                AppEvent::ActionResult(platform, success, result) => match result {
+                    Ok(()) => {
+                        info!("{} on {}", success, platform);
+                        self.status_message = Some(success);
+                    }
+                    Err(ref e) => {
+                        error!("Action on {} failed: {}", platform, e);
+                        self.status_message = Some(format!("{} error: {}", platform, e));
+                    }
+                },
+                AppEvent::AuthorModerated(platform, actor, success, result) => match result {
+                    Ok(()) => {
+                        info!("{} on {}", success, platform);
+                        if let Some(state) = self.platform_states.get_mut(&platform) {
+                            state.remove_author(&actor);
+                        }
+                        self.status_message = Some(success);
+                    }
+                    Err(ref e) => {
+                        error!("Moderation on {} failed: {}", platform, e);
+                        self.status_message = Some(format!("{} error: {}", platform, e));
+                    }
+                },
+                AppEvent::LikeResult(platform, id, liked, result) => match result {
+                    Ok(()) => {
+                        if let Some(state) = self.platform_states.get_mut(&platform) {
+                            state.set_liked(&id, liked);
+                        }
+                        self.status_message =
+                            Some(if liked { "Liked" } else { "Unliked" }.to_string());
+                    }
+                    Err(ref e) => {
+                        error!("Like on {} failed: {}", platform, e);
+                        self.status_message = Some(format!("{} error: {}", platform, e));
+                    }
+                },
+                AppEvent::Reposted(platform, id, result) => match result {
+                    Ok(()) => {
+                        info!("Reposted {} on {}", id, platform);
+                        self.status_message = Some("Reposted".to_string());
+                    }
+                    Err(ref e) => {
+                        error!("Repost on {} failed: {}", platform, e);
+                        self.status_message = Some(format!("{} error: {}", platform, e));
                     }
                 },
-                AppEvent::ReplyResult(platform, result) => match result {
+                AppEvent::PostDeleted(platform, id, result) => match result {
                     Ok(()) => {
-                        info!("Reply sent successfully to {}", platform);
-                        self.status_message = Some(format!("Replied on {}!", platform));
+                        info!("Deleted post {} on {}", id, platform);
+                        if let Some(state) = self.platform_states.get_mut(&platform) {
+                            state.remove_post(&id);
+                        }
+                        self.status_message = Some("Post deleted".to_string());
                     }
                     Err(ref e) => {
-                        error!("Reply to {} failed: {}", platform, e);
+                        error!("Deleting post {} on {} failed: {}", id, platform, e);
                         self.status_message = Some(format!("{} error: {}", platform, e));
                     }
                 },
-                AppEvent::RepliesLoaded(platform, post_id, result) => {
+                AppEvent::Reconnected(platform, result) => match result {
+                    Ok(client) => {
+                        info!("Reconnected to {}", platform);
+                        if !self.clients.contains_key(&self.current_platform) {
+                            self.current_platform = platform;
+                        }
+                        let client = Arc::new(client);
+                        self.clients.insert(platform, client.clone());
+                        self.schedule_clients
+                            .lock()
+                            .unwrap_or_else(|e| e.into_inner())
+                            .insert(platform, client.clone());
+                        self.platform_states
+                            .entry(platform)
+                            .or_insert_with(PlatformState::new);
+
+                        // Load posts now rather than waiting for the first refresh tick
+                        self.spawn_fetch(platform, client.clone());
+                        self.spawn_profile(platform, client.clone(), false);
+                        self.spawn_refresh(platform, client);
+
+                        self.status_message = Some(format!("Reconnected to {}", platform));
+                    }
+                    Err(ref e) => {
+                        error!("Reconnect to {} failed: {}", platform, e);
+                        self.status_message = Some(format!("{} reconnect failed: {}", platform, e));
+                    }
+                },
+                AppEvent::ProfileLoaded(platform, result) => match result {
+                    Ok(profile) => {
+                        if let Some(state) = self.platform_states.get_mut(&platform) {
+                            state.profile = Some(profile);
+                        }
+                    }
+                    // Profile features just stay hidden for this platform
+                    Err(PlatformError::NotImplemented(_)) => {
+                        debug!("{} doesn't support profiles", platform)
+                    }
+                    Err(e) => warn!("Failed to load {} profile: {}", platform, e),
+                },
+                AppEvent::RepliesLoaded(platform, generation, post_id, result) => {
+                    match &result {
+                        Err(e) if e.is_network() => self.record_network(false),
+                        Err(_) => {}
+                        Ok(_) => self.record_network(true),
+                    }
                     if let Some(state) = self.platform_states.get_mut(&platform) {
+                        if generation != state.replies_generation {
+                            debug!("Ignoring stale replies for {} post {}", platform, post_id);
+                            continue;
+                        }
+                        state.replies_task = None;
+                        state.loading_replies_for = None;
                         state.loaded_replies_for = Some(post_id.clone());
                         match result {
                             Ok(replies) => {
@@ -553,13 +2425,21 @@ q            Quit
                                     platform,
                                     post_id
                                 );
-                                state.selected_replies = replies;
+                                state.loaded_replies = replies;
+                                state.sort_replies(self.reply_sort);
+                            }
+                            Err(PlatformError::NotFound(_)) => {
+                                info!("{} post {} is no longer available", platform, post_id);
+                                state.unavailable.insert(post_id);
+                                state.loaded_replies = Vec::new();
+                                state.selected_replies = Vec::new();
                             }
                             Err(ref e) => {
                                 error!(
                                     "Failed to load replies for {} post {}: {}",
                                     platform, post_id, e
                                 );
+                                state.loaded_replies = Vec::new();
                                 state.selected_replies = Vec::new();
                                 self.status_message = Some(format!("Replies: {}", e));
                             }
@@ -567,97 +2447,647 @@ q            Quit
                     }
                 }
             }
+            // Several events can land in one tick; log each one's message
+            self.log_status();
+        }
+
+        // Show the cross-post summary once every platform answered (or time ran out)
+        if let Some(batch) = self.cross_post.as_mut()
+            && !batch.complete
+            && batch.is_done()
+        {
+            batch.complete = true;
+            self.status_message = Some(batch.summary());
         }
 
         // Check if we need to load replies for current selection
         self.maybe_load_replies();
+        self.mark_selected_read();
 
         // Handle keyboard
         if event::poll(std::time::Duration::from_millis(16))?
             && let Event::Key(key) = event::read()?
             && key.kind == KeyEventKind::Press
+            && let Some(action) = self.apply_key(key.code, key.modifiers)
         {
-            // Clear status on any key
-            self.status_message = None;
+            self.perform(action).await;
+        }
+        self.log_status();
+        Ok(())
+    }
 
-            match self.input_mode {
-                InputMode::Replying | InputMode::Posting | InputMode::CrossPosting => {
-                    self.handle_input_mode(key.code).await
-                }
-                InputMode::Normal => self.handle_normal_input(key.code).await,
+    /// Update state for a key press and return any I/O it asks for.
+    /// Nothing here awaits or spawns, so tests can drive it without a terminal.
+    pub fn apply_key(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        // Clear status on any key
+        self.status_message = None;
+
+        // The cross-post summary takes keys first, even over the composer
+        if self.cross_post.as_ref().is_some_and(|b| b.complete) {
+            if self.handle_popup_key(key) {
+                self.cross_post = None;
             }
+            return None;
+        }
+
+        match self.input_mode {
+            InputMode::Replying
+            | InputMode::Posting
+            | InputMode::CrossPosting
+            | InputMode::Goto => self.handle_input_mode(key, modifiers),
+            InputMode::ConfirmDelete => self.handle_confirm_delete(key),
+            InputMode::Normal => self.handle_normal_input(key, modifiers),
+        }
+    }
+
+    /// Run the I/O requested by `apply_key`
+    async fn perform(&mut self, action: Action) {
+        match action {
+            Action::Refresh => self.refresh_threads().await,
+            Action::Send { keep_open } => self.send_input(keep_open).await,
+            Action::Reconnect => self.reconnect(),
+            Action::Login(platform) => self.login(platform),
+            Action::Follow(follow) => self.follow_author(follow),
+            Action::Like(id, like) => self.like_post(id, like),
+            Action::Repost(id) => self.repost(id),
+            Action::Moderate(pending) => self.moderate(pending),
+            Action::CopyAuthor(field) => self.copy_author(field),
+            Action::CopyText => self.copy_text(),
+            Action::RetrySend => self.retry_send(),
+            Action::OpenPermalink => self.open_permalink(),
+            Action::ExportThread => self.export_thread(),
+            Action::OpenPost(link) => self.open_post(link),
+            Action::DeletePost(id) => self.delete_post(id),
+            Action::LoadMore => self.load_more(),
+            Action::SaveSettings => self.save_settings(),
+            Action::MarkAllRead => self.mark_all_read(),
+            Action::RefreshProfile => self.refresh_profile(),
         }
-        Ok(())
     }
 
-    async fn handle_input_mode(&mut self, key: KeyCode) {
+    fn handle_input_mode(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        if self.previewing {
+            match key {
+                KeyCode::Enter => {
+                    self.previewing = false;
+                    return self.submit_input(self.keep_composing);
+                }
+                KeyCode::Esc => self.previewing = false, // Back to editing
+                _ => {}
+            }
+            return None;
+        }
+
+        if self.alt_prompt.is_some() {
+            return self.handle_alt_text_input(key);
+        }
+
+        // Enter starts a new line in posts and replies; Ctrl+S sends, and so
+        // does Ctrl+Enter (many terminals only report Alt+Enter)
+        let held = modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        let send = match key {
+            KeyCode::Enter => held || self.input_mode == InputMode::Goto,
+            KeyCode::Char('s') => modifiers.contains(KeyModifiers::CONTROL),
+            _ => false,
+        };
+
         match key {
-            KeyCode::Enter => {
-                if !self.input_buffer.is_empty() {
-                    match self.input_mode {
-                        InputMode::Replying => self.send_reply().await,
-                        InputMode::Posting => self.send_post().await,
-                        InputMode::CrossPosting => self.send_cross_post().await,
-                        InputMode::Normal => {}
-                    }
+            _ if send => {
+                // Catch overlong posts here rather than as an API error
+                if let Some((count, limit)) = self.compose_count()
+                    && count > limit
+                {
+                    self.status_message = Some(format!(
+                        "Too long to send: {} characters, the limit is {}",
+                        count, limit
+                    ));
+                    return None;
                 }
-                self.input_mode = InputMode::Normal;
-                self.input_buffer.clear();
+                let is_post = matches!(
+                    self.input_mode,
+                    InputMode::Posting | InputMode::CrossPosting
+                );
+                // Ctrl+Enter on a new post sends it and keeps composing
+                let keep_open = is_post && key == KeyCode::Enter && held;
+                if self.confirm_before_post && is_post && !self.input_buffer.is_empty() {
+                    self.previewing = true;
+                    self.keep_composing = keep_open;
+                } else {
+                    return self.submit_input(keep_open);
+                }
+            }
+            KeyCode::Enter => {
+                self.edit_input(KeyCode::Char('\n'));
             }
             KeyCode::Esc => {
                 self.input_mode = InputMode::Normal;
-                self.input_buffer.clear();
+                self.clear_input();
+            }
+            _ => {
+                self.edit_input(key);
+            }
+        }
+        None
+    }
+
+    /// Characters typed and the limit they're held to, while composing a
+    /// post or reply. Cross-posts use the strictest connected platform's limit.
+    fn compose_count(&self) -> Option<(usize, usize)> {
+        if self.alt_prompt.is_some() {
+            return None;
+        }
+        let limit = match self.input_mode {
+            InputMode::Replying | InputMode::Posting => self.current_platform.char_limit(),
+            InputMode::CrossPosting => self.clients.keys().map(|p| p.char_limit()).min()?,
+            InputMode::Normal | InputMode::Goto | InputMode::ConfirmDelete => return None,
+        };
+        let count = if self.input_mode == InputMode::Replying {
+            self.input_buffer.trim().chars().count()
+        } else {
+            // A schedule prefix isn't part of the post
+            match schedule::parse_schedule_prefix(&self.input_buffer) {
+                Ok(Some((_, body))) => post_length(&body),
+                _ => post_length(&self.input_buffer),
+            }
+        };
+        Some((count, limit))
+    }
+
+    /// Cursor movement and editing shared by the composer and the alt text
+    /// prompt. Returns false for keys that don't edit.
+    fn edit_input(&mut self, key: KeyCode) -> bool {
+        let len = self.input_buffer.chars().count();
+        self.input_cursor = self.input_cursor.min(len);
+        match key {
+            KeyCode::Left => self.input_cursor = self.input_cursor.saturating_sub(1),
+            KeyCode::Right => self.input_cursor = (self.input_cursor + 1).min(len),
+            // Home and End stay on the cursor's line
+            KeyCode::Home => {
+                let before: Vec<char> = self.input_buffer.chars().take(self.input_cursor).collect();
+                self.input_cursor = before.iter().rposition(|&c| c == '\n').map_or(0, |i| i + 1);
+            }
+            KeyCode::End => {
+                let after = self.input_buffer.chars().skip(self.input_cursor);
+                self.input_cursor += after.take_while(|&c| c != '\n').count();
             }
             KeyCode::Backspace => {
-                self.input_buffer.pop();
+                if self.input_cursor > 0 {
+                    self.input_cursor -= 1;
+                    let at = self.cursor_byte();
+                    self.input_buffer.remove(at);
+                }
+            }
+            KeyCode::Delete => {
+                if self.input_cursor < len {
+                    let at = self.cursor_byte();
+                    self.input_buffer.remove(at);
+                }
             }
             KeyCode::Char(c) => {
-                self.input_buffer.push(c);
+                let at = self.cursor_byte();
+                self.input_buffer.insert(at, c);
+                self.input_cursor += 1;
             }
-            _ => {}
+            _ => return false,
+        }
+        true
+    }
+
+    /// Byte offset of `input_cursor`, so edits never split a multibyte char
+    fn cursor_byte(&self) -> usize {
+        self.input_buffer
+            .char_indices()
+            .nth(self.input_cursor)
+            .map_or(self.input_buffer.len(), |(i, _)| i)
+    }
+
+    /// Replace the input with `text`, leaving the cursor at the end
+    fn set_input(&mut self, text: String) {
+        self.input_cursor = text.chars().count();
+        self.input_buffer = text;
+    }
+
+    fn clear_input(&mut self) {
+        self.input_buffer.clear();
+        self.input_cursor = 0;
+    }
+
+    /// Typing alt text for one image of the post being sent
+    fn handle_alt_text_input(&mut self, key: KeyCode) -> Option<Action> {
+        let prompt = self.alt_prompt.as_mut()?;
+
+        match key {
+            KeyCode::Enter => {
+                if self.require_alt_text && self.input_buffer.trim().is_empty() {
+                    self.status_message = Some("Alt text is required for images".to_string());
+                    return None;
+                }
+                prompt.alts.push(std::mem::take(&mut self.input_buffer));
+                self.input_cursor = 0;
+                if prompt.alts.len() < prompt.image_count {
+                    return None;
+                }
+
+                // Every image has alt text (possibly blank): send the original post
+                let prompt = self.alt_prompt.take()?;
+                self.set_input(prompt.text);
+                self.alt_texts = prompt.alts;
+                return self.submit_input(prompt.keep_open);
+            }
+            KeyCode::Esc => {
+                // Back to editing the post itself
+                if let Some(prompt) = self.alt_prompt.take() {
+                    self.set_input(prompt.text);
+                }
+            }
+            _ => {
+                self.edit_input(key);
+            }
+        }
+        None
+    }
+
+    /// Check the composed text and ask for anything still missing (a valid
+    /// schedule time, alt text), returning the send once it's ready
+    fn submit_input(&mut self, keep_open: bool) -> Option<Action> {
+        let is_post = matches!(
+            self.input_mode,
+            InputMode::Posting | InputMode::CrossPosting
+        );
+        if is_post && let Err(e) = schedule::parse_schedule_prefix(&self.input_buffer) {
+            // Keep the composer open so the schedule time can be fixed
+            self.status_message = Some(e);
+            return None;
+        }
+
+        // Ask for alt text per image before sending (alt_texts is set once answered)
+        if is_post && self.alt_texts.is_empty() {
+            let (_, images) = extract_images(&self.input_buffer);
+            if !images.is_empty() {
+                self.alt_prompt = Some(AltTextPrompt {
+                    text: std::mem::take(&mut self.input_buffer),
+                    image_count: images.len(),
+                    alts: Vec::new(),
+                    keep_open,
+                });
+                self.input_cursor = 0;
+                return None;
+            }
+        }
+
+        if self.input_buffer.trim().is_empty() {
+            // Nothing to send: just close the composer
+            if !keep_open {
+                self.input_mode = InputMode::Normal;
+            }
+            self.clear_input();
+            self.alt_texts.clear();
+            return None;
+        }
+
+        if self.input_mode == InputMode::Goto {
+            // Keep the prompt open on a bad link so it can be corrected
+            let link = parse_post_link(&self.input_buffer);
+            if link.is_none() {
+                self.status_message = Some(
+                    "Unrecognized link: paste a threads.net, bsky.app, or at:// post link"
+                        .to_string(),
+                );
+            } else {
+                self.input_mode = InputMode::Normal;
+                self.clear_input();
+            }
+            return link.map(Action::OpenPost);
+        }
+        Some(Action::Send { keep_open })
+    }
+
+    /// Send the composed text for the current input mode and close the popup,
+    /// or clear it and stay in the same mode when `keep_open` is set
+    async fn send_input(&mut self, keep_open: bool) {
+        let missing_alt = self
+            .alt_texts
+            .iter()
+            .filter(|a| a.trim().is_empty())
+            .count();
+
+        match self.input_mode {
+            InputMode::Replying => self.send_reply().await,
+            InputMode::Posting => self.send_post().await,
+            InputMode::CrossPosting => self.send_cross_post().await,
+            InputMode::Normal | InputMode::Goto | InputMode::ConfirmDelete => {}
         }
+
+        if missing_alt > 0 {
+            let status = self.status_message.take().unwrap_or_default();
+            self.status_message = Some(format!(
+                "{} (warning: {} image(s) without alt text)",
+                status, missing_alt
+            ));
+        }
+        if !keep_open {
+            self.input_mode = InputMode::Normal;
+        }
+        self.clear_input();
+        self.alt_texts.clear();
     }
 
-    async fn handle_normal_input(&mut self, key: KeyCode) {
+    fn handle_normal_input(&mut self, key: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
         if self.show_help {
-            self.show_help = false;
-            return;
+            if self.handle_popup_key(key) {
+                self.show_help = false;
+            }
+            return None;
+        }
+
+        if self.show_log {
+            if key == KeyCode::Char('E') || self.handle_popup_key(key) {
+                self.popup_scroll = 0;
+                self.show_log = false;
+            }
+            return None;
+        }
+
+        if self.show_settings {
+            return self.handle_settings_key(key);
+        }
+
+        if self.show_profile {
+            match key {
+                KeyCode::Esc | KeyCode::Char('i' | 'q') => self.show_profile = false,
+                KeyCode::Char('r') => return Some(Action::RefreshProfile),
+                _ => {}
+            }
+            return None;
+        }
+
+        if self.pending_login {
+            self.pending_login = false;
+            match key {
+                KeyCode::Char('t') => return Some(Action::Login(Platform::Threads)),
+                KeyCode::Char('b') => return Some(Action::Login(Platform::Bluesky)),
+                _ => self.status_message = Some("Cancelled".to_string()),
+            }
+            return None;
+        }
+
+        if let Some(pending) = self.pending_moderation.take() {
+            if key == KeyCode::Char('y') {
+                return Some(Action::Moderate(pending));
+            }
+            self.status_message = Some("Cancelled".to_string());
+            return None;
+        }
+
+        if std::mem::take(&mut self.pending_g) && key == KeyCode::Char('g') {
+            self.jump_to_edge(false);
+            return None;
+        }
+
+        // Checked before plain `r`, which replies
+        if key == KeyCode::Char('r') && modifiers.contains(KeyModifiers::CONTROL) {
+            self.toggle_refresh_pause();
+            return None;
+        }
+
+        if (self.read_only || self.offline)
+            && matches!(
+                key,
+                KeyCode::Char(
+                    'r' | '>' | 'p' | 'P' | '+' | '-' | 'M' | 'X' | 'd' | 'f' | 'b' | 'B' | '.'
+                )
+            )
+        {
+            self.status_message = Some(if self.offline {
+                "Offline: posting is paused until the connection returns".to_string()
+            } else {
+                "Read-only mode: posting is disabled".to_string()
+            });
+            return None;
+        }
+
+        if let Some(feature) = key_feature(key)
+            && !self.supports(feature)
+        {
+            self.status_message = Some(format!(
+                "{} isn't available on {}",
+                feature, self.current_platform
+            ));
+            return None;
         }
 
         match key {
             KeyCode::Char('q') => self.running = false,
             KeyCode::Char('?') => self.show_help = true,
+            KeyCode::Char('E') => self.show_log = true,
+            KeyCode::Char('i') => self.show_profile = true,
+            KeyCode::Char('u') => self.toggle_unified_view(),
+            KeyCode::Char('.') => return self.start_retry(),
+            KeyCode::Char(',') => {
+                self.settings_opened_with = Some(self.setting_values());
+                self.show_settings = true;
+            }
             KeyCode::Char('t') => self.toggle_panel(),
             KeyCode::Char('r') => self.start_reply(),
+            KeyCode::Char('>') => self.start_quote_reply(),
             KeyCode::Char('p') => self.start_post(),
             KeyCode::Char('P') => self.start_cross_post(), // Shift+P for cross-post
-            KeyCode::Char('R') => self.refresh_threads().await,
+            KeyCode::Char('R') => return Some(Action::Refresh),
+            KeyCode::Char('C') => return Some(Action::Reconnect),
+            KeyCode::Char('L') => {
+                self.pending_login = true;
+                self.status_message = Some("Log in to: (t)hreads / (b)luesky".to_string());
+            }
             KeyCode::Tab | KeyCode::Char(']') => self.toggle_platform(),
+            KeyCode::Char('j') | KeyCode::Down | KeyCode::PageDown if self.at_list_end() => {
+                return self.start_load_more();
+            }
+            KeyCode::PageDown => self.page_detail(true),
+            KeyCode::PageUp => self.page_detail(false),
             KeyCode::Char('j') | KeyCode::Down => self.move_down(),
             KeyCode::Char('k') | KeyCode::Up => self.move_up(),
+            KeyCode::Char('g') => self.pending_g = true,
+            KeyCode::Char('G') => self.jump_to_edge(true),
             KeyCode::Char('h') | KeyCode::Left => self.move_left(),
             KeyCode::Char('l') | KeyCode::Right => self.move_right(),
+            KeyCode::Char('U') => return self.jump_to_parent(),
+            KeyCode::Char('n') => self.jump_to_unread(),
+            KeyCode::Char('A') => return Some(Action::MarkAllRead),
+            KeyCode::Char('y') => return Some(Action::CopyText),
+            KeyCode::Char('Y') => return Some(Action::CopyAuthor(AuthorField::Handle)),
+            KeyCode::Char('D') => return Some(Action::CopyAuthor(AuthorField::Did)),
+            KeyCode::Char('J') => return Some(Action::ExportThread),
+            KeyCode::Char('o') => return Some(Action::OpenPermalink),
+            KeyCode::Char('O') => self.start_goto(),
+            KeyCode::Char('c') => self.toggle_conversation(),
+            KeyCode::Char('s') => self.cycle_reply_sort(),
+            KeyCode::Char('+') => return Some(Action::Follow(true)),
+            KeyCode::Char('-') => return Some(Action::Follow(false)),
+            KeyCode::Char('M') => self.confirm_moderation(Moderation::Mute),
+            KeyCode::Char('X') => self.confirm_moderation(Moderation::Block),
+            KeyCode::Char('d') => self.confirm_delete(),
+            KeyCode::Char('f') => return self.toggle_like(),
+            KeyCode::Char('b') => return self.start_repost(),
+            KeyCode::Char('B') => self.start_quote_post(),
             KeyCode::Enter => self.select_item(),
             KeyCode::Esc => self.deselect(),
             _ => {}
         }
+        None
     }
 
-    fn start_reply(&mut self) {
-        let has_selection = self
-            .platform_states
+    /// Stop or restart background refreshes; `R` still refreshes while paused
+    fn toggle_refresh_pause(&mut self) {
+        let paused = !self.refresh_paused.load(Ordering::Relaxed);
+        self.refresh_paused.store(paused, Ordering::Relaxed);
+        self.status_message = Some(if paused {
+            "Auto-refresh paused (Ctrl+R to resume, R to refresh now)".to_string()
+        } else {
+            "Auto-refresh resumed".to_string()
+        });
+    }
+
+    /// Whether the current platform's client supports `feature`. Platforms
+    /// that aren't connected don't hide anything.
+    fn supports(&self, feature: Feature) -> bool {
+        self.clients
             .get(&self.current_platform)
-            .is_some_and(|state| state.list_state.selected().is_some());
+            .is_none_or(|client| client.capabilities().supports(feature))
+    }
+
+    fn start_reply(&mut self) {
+        let Some(state) = self.platform_states.get(&self.current_platform) else {
+            return;
+        };
+        let has_selection = state.list_state.selected().is_some();
+
+        let post_gone = state
+            .list_state
+            .selected()
+            .and_then(|idx| state.posts.get(idx))
+            .is_some_and(|post| state.unavailable.contains(&post.id));
+        let target_unavailable = post_gone
+            || state.reply_selection.is_some_and(|idx| {
+                Self::get_reply_at_index(&state.selected_replies, idx)
+                    .is_some_and(|r| !r.post.is_available())
+            });
+        if target_unavailable {
+            self.status_message = Some("Can't reply to a blocked or deleted post".to_string());
+            return;
+        }
+        if let Some(reason) = self.reply_restriction() {
+            self.status_message = Some(format!("Replies restricted: {}", reason));
+            return;
+        }
 
         if has_selection {
             self.input_mode = InputMode::Replying;
-            self.input_buffer.clear();
+            self.clear_input();
+        }
+    }
+
+    /// Why the reply target won't accept a reply from this account, when its
+    /// metadata says so. Follower-based limits on Threads can't be checked up
+    /// front, so those are left for the server to decide.
+    fn reply_restriction(&self) -> Option<&'static str> {
+        let state = self.platform_states.get(&self.current_platform)?;
+        let target = state.reply_target()?;
+        if target.reply_disabled {
+            return target.reply_note();
+        }
+
+        let audience = target.reply_audience?;
+        let me = state.profile.as_ref()?.handle.as_str();
+        if target.author_handle.as_deref() == Some(me) {
+            return None;
+        }
+        let restricted = match audience {
+            ReplyAudience::Author | ReplyAudience::Nobody => true,
+            ReplyAudience::Mentioned => !target
+                .text
+                .as_deref()
+                .is_some_and(|text| text.contains(&format!("@{}", me))),
+            ReplyAudience::Following | ReplyAudience::Followers | ReplyAudience::List => false,
+        };
+        restricted.then(|| audience.describe())
+    }
+
+    /// Start a reply with the target's text quoted at the top
+    fn start_quote_reply(&mut self) {
+        self.start_reply();
+        if self.input_mode != InputMode::Replying {
+            return;
+        }
+        if let Some(text) = self
+            .platform_states
+            .get(&self.current_platform)
+            .and_then(|state| state.reply_target())
+            .and_then(|post| post.text.as_deref())
+        {
+            self.set_input(quote_text(text));
         }
     }
 
     fn start_post(&mut self) {
         self.input_mode = InputMode::Posting;
-        self.input_buffer.clear();
+        self.clear_input();
+    }
+
+    /// Start a new post that links to the selected one, with the cursor above the link
+    fn start_quote_post(&mut self) {
+        let Some(post) = self.selected_post() else {
+            self.status_message = Some("No post selected".to_string());
+            return;
+        };
+        let Some(link) = post.permalink.clone() else {
+            self.status_message = Some("No link for this post".to_string());
+            return;
+        };
+        self.start_post();
+        self.set_input(format!("\n\n{}", link));
+        self.input_cursor = 0;
+    }
+
+    /// Step to the next reply order and re-sort every platform's loaded replies
+    fn cycle_reply_sort(&mut self) {
+        fn has_counts(replies: &[ReplyThread]) -> bool {
+            replies.iter().any(|r| {
+                r.post.like_count.is_some()
+                    || r.post.repost_count.is_some()
+                    || r.post.reply_count.is_some()
+                    || has_counts(&r.replies)
+            })
+        }
+
+        let counts = self
+            .platform_states
+            .get(&self.current_platform)
+            .is_some_and(|state| has_counts(&state.loaded_replies));
+        self.reply_sort = self.reply_sort.next(counts);
+        for state in self.platform_states.values_mut() {
+            state.sort_replies(self.reply_sort);
+        }
+        self.status_message = Some(format!("Replies sorted {}", self.reply_sort.label()));
+    }
+
+    /// Switch the detail view between replies to the post and its whole conversation
+    fn toggle_conversation(&mut self) {
+        self.show_conversation = !self.show_conversation;
+        if let Some(state) = self.platform_states.get_mut(&self.current_platform) {
+            // Reload the selected post in the new mode
+            state.cancel_replies();
+            state.loaded_replies_for = None;
+        }
+        self.status_message = Some(if self.show_conversation {
+            "Showing full conversation".to_string()
+        } else {
+            "Showing replies only".to_string()
+        });
+    }
+
+    fn start_goto(&mut self) {
+        self.input_mode = InputMode::Goto;
+        self.clear_input();
     }
 
     fn start_cross_post(&mut self) {
@@ -673,74 +3103,163 @@ q            Quit
         }
 
         self.input_mode = InputMode::CrossPosting;
-        self.input_buffer.clear();
+        self.clear_input();
     }
 
     async fn send_reply(&mut self) {
-        let tx = self.event_tx.clone();
         let text = self.input_buffer.clone();
 
         let Some(state) = self.platform_states.get(&self.current_platform) else {
             return;
         };
 
-        // Get the post ID to reply to: selected reply or main post
-        let reply_to_id = if let Some(reply_idx) = state.reply_selection {
-            Self::get_reply_id_at_index(&state.selected_replies, reply_idx)
-        } else if let Some(idx) = state.list_state.selected() {
-            state.posts.get(idx).map(|p| p.id.clone())
-        } else {
-            None
-        };
-
-        if let Some(post_id) = reply_to_id
-            && let Some(client) = self.clients.get(&self.current_platform)
-        {
-            let client = client.clone();
-            let platform = self.current_platform;
-
-            info!("Sending reply to {} on {}", post_id, platform);
-            self.status_message = Some(format!("Replying on {}...", platform));
+        let reply_to_id = state.reply_target().map(|p| p.id.clone());
 
-            tokio::spawn(async move {
-                let result = client.reply_to_post(&post_id, &text).await;
-                let _ = tx
-                    .send(AppEvent::ReplyResult(
-                        platform,
-                        result.map_err(|e| e.to_string()),
-                    ))
-                    .await;
+        if let Some(post_id) = reply_to_id {
+            self.spawn_send(Draft {
+                platform: self.current_platform,
+                text,
+                alt_texts: Vec::new(),
+                reply_to: Some(post_id),
             });
         }
     }
 
-    async fn send_post(&mut self) {
-        let text = self.input_buffer.clone();
-        info!("Sending new post to {}", self.current_platform);
-        let tx = self.event_tx.clone();
-
-        self.status_message = Some(format!("Posting to {}...", self.current_platform));
-
-        let Some(client) = self.clients.get(&self.current_platform) else {
+    /// Send a post or reply in the background; the result comes back as
+    /// `AppEvent::SendResult`
+    fn spawn_send(&mut self, draft: Draft) {
+        let Some(client) = self.clients.get(&draft.platform).cloned() else {
             self.status_message = Some("No client available".to_string());
             return;
         };
+        let tx = self.event_tx.clone();
+
+        self.status_message = Some(match &draft.reply_to {
+            Some(post_id) => {
+                info!("Sending reply to {} on {}", post_id, draft.platform);
+                format!("Replying on {}...", draft.platform)
+            }
+            None => {
+                info!("Sending new post to {}", draft.platform);
+                format!("Posting to {}...", draft.platform)
+            }
+        });
 
-        let client = client.clone();
-        let platform = self.current_platform;
         tokio::spawn(async move {
-            let result = client.create_post(&text).await;
-            let _ = tx
-                .send(AppEvent::PostResult(
-                    platform,
-                    result.map_err(|e| e.to_string()),
-                ))
-                .await;
+            let result = match &draft.reply_to {
+                Some(post_id) => client
+                    .reply_to_post(post_id, &draft.text)
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| e.to_string()),
+                None => publish(&client, &draft.text, &draft.alt_texts).await,
+            };
+            let _ = tx.send(AppEvent::SendResult(draft, result)).await;
+        });
+    }
+
+    /// Report a sent post or reply, keeping it for `.` if it failed
+    fn send_finished(&mut self, draft: Draft, result: Result<(), String>) {
+        let platform = draft.platform;
+        let what = if draft.reply_to.is_some() {
+            "Reply"
+        } else {
+            "Post"
+        };
+        match result {
+            Ok(()) => {
+                info!("{} sent successfully to {}", what, platform);
+                if self.failed_send.as_ref() == Some(&draft) {
+                    self.failed_send = None;
+                }
+                self.status_message = Some(if draft.reply_to.is_some() {
+                    format!("Replied on {}!", platform)
+                } else {
+                    format!("Posted to {}!", platform)
+                });
+            }
+            Err(e) => {
+                error!("{} to {} failed: {}", what, platform, e);
+                self.status_message = Some(format!("{} error: {} (. to retry)", platform, e));
+                self.failed_send = Some(draft);
+            }
+        }
+    }
+
+    /// Reload the profile shown in the profile popup
+    fn refresh_profile(&mut self) {
+        if let Some(client) = self.clients.get(&self.current_platform).cloned() {
+            self.spawn_profile(self.current_platform, client, true);
+        }
+    }
+
+    /// Ask to resend the last failed post or reply
+    fn start_retry(&mut self) -> Option<Action> {
+        if self.failed_send.is_none() {
+            self.status_message = Some("Nothing to retry".to_string());
+            return None;
+        }
+        Some(Action::RetrySend)
+    }
+
+    fn retry_send(&mut self) {
+        // Taken so a second `.` can't send it twice; a new failure stores it again
+        if let Some(draft) = self.failed_send.take() {
+            self.spawn_send(draft);
+        }
+    }
+
+    /// Queue the text instead of sending it if it starts with `@at:<time>`.
+    /// Returns true if the post was scheduled.
+    fn maybe_schedule(&mut self, text: &str, platforms: &[Platform]) -> bool {
+        let Ok(Some((send_at, body))) = schedule::parse_schedule_prefix(text) else {
+            return false;
+        };
+
+        for platform in platforms {
+            let post = ScheduledPost {
+                text: body.clone(),
+                platform: *platform,
+                send_at,
+                alt_texts: self.alt_texts.clone(),
+                attempts: 0,
+                claimed_at: None,
+                last_error: None,
+            };
+            if let Err(e) = ScheduleQueue::push(post) {
+                error!("Failed to schedule post: {}", e);
+                self.status_message = Some(format!("Failed to schedule post: {}", e));
+                return true;
+            }
+        }
+
+        let when = schedule::format_local(send_at);
+        info!(
+            "Scheduled post for {} on {} platform(s)",
+            when,
+            platforms.len()
+        );
+        self.status_message = Some(format!("Scheduled for {}", when));
+        true
+    }
+
+    async fn send_post(&mut self) {
+        let text = self.input_buffer.clone();
+        let alt_texts = self.alt_texts.clone();
+        if self.maybe_schedule(&text, &[self.current_platform]) {
+            return;
+        }
+        self.spawn_send(Draft {
+            platform: self.current_platform,
+            text,
+            alt_texts,
+            reply_to: None,
         });
     }
 
     async fn send_cross_post(&mut self) {
         let text = self.input_buffer.clone();
+        let alt_texts = self.alt_texts.clone();
         info!("Cross-posting to all platforms");
 
         let tx = self.event_tx.clone();
@@ -751,21 +3270,163 @@ q            Quit
             return;
         }
 
+        let platforms: Vec<Platform> = clients.keys().copied().collect();
+        if self.maybe_schedule(&text, &platforms) {
+            return;
+        }
+
         self.status_message = Some(format!("Cross-posting to {} platforms...", clients.len()));
 
-        tokio::spawn(async move {
-            for (platform, client) in clients.iter() {
-                let result = client.create_post(&text).await;
+        let id = self.next_batch_id;
+        self.next_batch_id += 1;
+        self.cross_post = Some(CrossPostBatch {
+            id,
+            platforms,
+            results: HashMap::new(),
+            started: Instant::now(),
+            complete: false,
+        });
+
+        for (platform, client) in clients {
+            let tx = tx.clone();
+            let text = text.clone();
+            let alt_texts = alt_texts.clone();
+            tokio::spawn(async move {
+                let result = publish(&client, &text, &alt_texts).await;
                 let _ = tx
-                    .send(AppEvent::PostResult(
-                        *platform,
-                        result.map_err(|e| e.to_string()),
-                    ))
+                    .send(AppEvent::CrossPostResult(id, platform, result))
                     .await;
+            });
+        }
+    }
+
+    /// Retry configured platforms that aren't connected (e.g. failed at startup)
+    fn reconnect(&mut self) {
+        let config = match Config::load() {
+            Ok(config) => config,
+            Err(e) => {
+                self.status_message = Some(format!("Failed to load config: {}", e));
+                return;
+            }
+        };
+
+        let missing: Vec<Platform> = [
+            (Platform::Threads, config.has_threads()),
+            (Platform::Bluesky, config.has_bluesky()),
+        ]
+        .into_iter()
+        .filter(|(platform, configured)| *configured && !self.clients.contains_key(platform))
+        .map(|(platform, _)| platform)
+        .collect();
+
+        if missing.is_empty() {
+            self.status_message = Some("All configured platforms are connected".to_string());
+            return;
+        }
+
+        self.connect_platforms(missing, config);
+    }
+
+    /// Connect platforms in the background; results arrive as `Reconnected` events
+    fn connect_platforms(&mut self, platforms: Vec<Platform>, config: Config) {
+        let names: Vec<String> = platforms.iter().map(|p| p.to_string()).collect();
+        info!("Connecting to {}", names.join(", "));
+        self.status_message = Some(format!("Connecting to {}...", names.join(", ")));
+
+        let config = Arc::new(config);
+        for platform in platforms {
+            let tx = self.event_tx.clone();
+            let config = config.clone();
+            tokio::spawn(async move {
+                let result = connect::platform(platform, &config)
+                    .await
+                    .map_err(|e| e.to_string());
+                let _ = tx.send(AppEvent::Reconnected(platform, result)).await;
+            });
+        }
+    }
+
+    /// Suspend the TUI, run `ndl login <platform>` in the terminal, then connect
+    fn login(&mut self, platform: Platform) {
+        let arg = match platform {
+            Platform::Threads => "threads",
+            Platform::Bluesky => "bluesky",
+        };
+        info!("Running login for {} from the TUI", platform);
+
+        let status = (|| -> io::Result<std::process::ExitStatus> {
+            disable_raw_mode()?;
+            stdout().execute(LeaveAlternateScreen)?;
+
+            let exe = std::env::current_exe()?;
+            // The login flow prompts on stdin and may open a browser; block until it's done
+            let mut command = std::process::Command::new(exe);
+            command.args(["login", arg]);
+            if self.read_only {
+                command.arg("--read-only");
+            }
+            let status = tokio::task::block_in_place(|| command.status());
+
+            stdout().execute(EnterAlternateScreen)?;
+            enable_raw_mode()?;
+            status
+        })();
+        self.needs_clear = true;
+
+        match status {
+            // Connect with the fresh credentials, replacing any existing client
+            Ok(status) if status.success() => match Config::load() {
+                Ok(config) => self.connect_platforms(vec![platform], config),
+                Err(e) => self.status_message = Some(format!("Failed to load config: {}", e)),
+            },
+            Ok(_) => self.status_message = Some(format!("{} login failed", platform)),
+            Err(e) => {
+                error!("Failed to run login: {}", e);
+                self.status_message = Some(format!("Failed to run login: {}", e));
             }
+        }
+    }
+
+    /// Load a post from a pasted link in the background
+    fn open_post(&mut self, link: PostLink) {
+        let Some(client) = self.clients.get(&link.platform).cloned() else {
+            self.status_message = Some(format!("{} is not connected", link.platform));
+            return;
+        };
+
+        let tx = self.event_tx.clone();
+        self.status_message = Some(format!("Opening {} post...", link.platform));
+
+        tokio::spawn(async move {
+            let result = client.get_post(&link.id).await;
+            let _ = tx.send(AppEvent::PostOpened(link, result)).await;
         });
     }
 
+    /// Show a post loaded from a pasted link, switching to its platform
+    fn post_opened(&mut self, link: PostLink, result: Result<Post, PlatformError>) {
+        match result {
+            Ok(post) => {
+                debug!("Opened {} post {}", link.platform, post.id);
+                if link.platform != self.current_platform {
+                    if let Some(state) = self.platform_states.get_mut(&self.current_platform) {
+                        state.cancel_replies();
+                    }
+                    self.current_platform = link.platform;
+                }
+                if let Some(state) = self.platform_states.get_mut(&link.platform) {
+                    state.open_post(post);
+                }
+                self.active_panel = Panel::Detail;
+                self.status_message = Some(format!("Opened {} post", link.platform));
+            }
+            Err(e) => {
+                error!("Failed to open {} post {}: {}", link.platform, link.id, e);
+                self.status_message = Some(format!("Couldn't open post: {}", e));
+            }
+        }
+    }
+
     async fn refresh_threads(&mut self) {
         debug!("Refreshing {}", self.current_platform);
         self.status_message = Some("Refreshing...".to_string());
@@ -784,18 +3445,101 @@ q            Quit
                     self.current_platform
                 );
                 if let Some(state) = self.platform_states.get_mut(&self.current_platform) {
-                    state.posts = posts;
-                    if state.list_state.selected().is_none() && !state.posts.is_empty() {
-                        state.list_state.select(Some(0));
-                    }
+                    state.set_posts(posts);
                 }
+                self.cache_posts(self.current_platform);
                 self.status_message = Some(format!("{} refreshed", self.current_platform));
+                self.record_network(true);
             }
             Err(e) => {
                 error!("Refresh failed for {}: {}", self.current_platform, e);
                 self.status_message = Some(format!("Refresh failed: {}", e));
+                if e.is_network() {
+                    self.record_network(false);
+                }
+            }
+        }
+    }
+
+    /// Track whether requests are getting through, switching the offline
+    /// indicator on after a run of failures and off at the first success
+    fn record_network(&mut self, ok: bool) {
+        if ok {
+            if self.offline {
+                info!("Back online");
+                self.status_message = Some("Back online".to_string());
+            }
+            self.network_failures = 0;
+            self.offline = false;
+            return;
+        }
+
+        self.network_failures += 1;
+        if !self.offline && self.network_failures >= OFFLINE_AFTER_FAILURES {
+            warn!(
+                "Offline after {} network failures in a row",
+                self.network_failures
+            );
+            self.offline = true;
+            self.status_message = None;
+        }
+    }
+
+    /// Move the current platform's read marker up to the selected post,
+    /// saving it when it changes
+    fn mark_selected_read(&mut self) {
+        let Some(ui_state) = self.ui_state.as_mut() else {
+            return;
+        };
+        let Some(post) = self
+            .platform_states
+            .get(&self.current_platform)
+            .and_then(|state| state.list_state.selected().and_then(|i| state.posts.get(i)))
+        else {
+            return;
+        };
+
+        if ui_state.mark_read(self.current_platform, post)
+            && let Err(e) = ui_state.save()
+        {
+            warn!("Failed to save UI state: {}", e);
+        }
+    }
+
+    /// Select the oldest unread post; marking it read as it's selected
+    /// leaves the newer ones unread to read upward through
+    fn jump_to_unread(&mut self) {
+        let (Some(ui_state), Some(state)) = (
+            self.ui_state.as_ref(),
+            self.platform_states.get_mut(&self.current_platform),
+        ) else {
+            return;
+        };
+        match ui_state.oldest_unread(self.current_platform, &state.posts) {
+            Some(idx) => {
+                state.list_state.select(Some(idx));
+                self.active_panel = Panel::Threads;
             }
+            None => self.status_message = Some("No unread posts".to_string()),
+        }
+    }
+
+    /// Mark every loaded post on the current platform read, saving the marker
+    fn mark_all_read(&mut self) {
+        let (Some(ui_state), Some(state)) = (
+            self.ui_state.as_mut(),
+            self.platform_states.get(&self.current_platform),
+        ) else {
+            return;
+        };
+        if !ui_state.mark_all_read(self.current_platform, &state.posts) {
+            self.status_message = Some("No unread posts".to_string());
+            return;
         }
+        self.status_message = Some(match ui_state.save() {
+            Ok(()) => format!("Marked all {} posts read", self.current_platform),
+            Err(e) => format!("Couldn't save read marker: {}", e),
+        });
     }
 
     fn maybe_load_replies(&mut self) {
@@ -811,8 +3555,10 @@ q            Quit
             return;
         };
 
-        // Check if we already loaded replies for this post
-        if state.loaded_replies_for.as_ref() == Some(&post.id) {
+        // Check if we already loaded (or are loading) replies for this post
+        if state.loaded_replies_for.as_ref() == Some(&post.id)
+            || state.loading_replies_for.as_ref() == Some(&post.id)
+        {
             return;
         }
 
@@ -824,27 +3570,55 @@ q            Quit
         let tx = self.event_tx.clone();
         let platform = self.current_platform;
         let client = client.clone();
+        let conversation = self.show_conversation && self.supports(Feature::Conversations);
 
-        // Clear old replies in state
-        if let Some(state) = self.platform_states.get_mut(&self.current_platform) {
-            state.selected_replies.clear();
-            state.loaded_replies_for = None;
-            state.reply_selection = None;
-        }
+        let Some(state) = self.platform_states.get_mut(&self.current_platform) else {
+            return;
+        };
 
-        tokio::spawn(async move {
-            let result = client
-                .get_post_replies(&post_id, 2)
-                .await
-                .map_err(|e| e.to_string());
+        // Clear old replies and supersede any fetch for the previous selection
+        state.cancel_replies();
+        state.selected_replies.clear();
+        state.loaded_replies.clear();
+        state.loaded_replies_for = None;
+        state.reply_selection = None;
+        state.loading_replies_for = Some(post_id.clone());
+        let generation = state.replies_generation;
+
+        state.replies_task = Some(tokio::spawn(async move {
+            let fetch = async {
+                if conversation {
+                    // Shown flat, one level, in the same list as replies
+                    let posts = client.get_conversation(&post_id).await?;
+                    Ok(posts
+                        .into_iter()
+                        .map(|post| ReplyThread {
+                            post,
+                            replies: Vec::new(),
+                        })
+                        .collect())
+                } else {
+                    client.get_post_replies(&post_id, 2).await
+                }
+            };
+            let result = match tokio::time::timeout(REPLIES_TIMEOUT, fetch).await {
+                Ok(result) => result,
+                Err(_) => Err(PlatformError::Request(format!(
+                    "timed out after {}s",
+                    REPLIES_TIMEOUT.as_secs()
+                ))),
+            };
             let _ = tx
-                .send(AppEvent::RepliesLoaded(platform, post_id, result))
+                .send(AppEvent::RepliesLoaded(
+                    platform, generation, post_id, result,
+                ))
                 .await;
-        });
+        }));
     }
 
     fn move_down(&mut self) {
         match self.active_panel {
+            Panel::Threads if self.unified_view => self.step_unified(true),
             Panel::Threads => {
                 let Some(state) = self.platform_states.get_mut(&self.current_platform) else {
                     return;
@@ -864,12 +3638,110 @@ q            Quit
                 };
                 state.list_state.select(Some(i));
             }
-            Panel::Detail => self.reply_move_down(),
+            Panel::Detail if self.has_replies() => self.reply_move_down(),
+            Panel::Detail => self.scroll_detail(1),
+        }
+    }
+
+    /// Select the first post, or the last with `bottom`
+    fn jump_to_edge(&mut self, bottom: bool) {
+        if self.active_panel != Panel::Threads {
+            return;
+        }
+        if self.unified_view {
+            let entries = self.unified_entries();
+            let edge = if bottom {
+                entries.last()
+            } else {
+                entries.first()
+            };
+            if let Some(&entry) = edge {
+                self.select_unified(entry);
+            }
+            return;
+        }
+        let Some(state) = self.platform_states.get_mut(&self.current_platform) else {
+            return;
+        };
+        if state.posts.is_empty() {
+            return;
+        }
+        let i = if bottom { state.posts.len() - 1 } else { 0 };
+        state.list_state.select(Some(i));
+    }
+
+    /// Whether the list has focus with its last post selected and more to load
+    fn at_list_end(&self) -> bool {
+        // Older pages are per platform, so the merged list doesn't page
+        !self.unified_view
+            && self.active_panel == Panel::Threads
+            && self
+                .platform_states
+                .get(&self.current_platform)
+                .is_some_and(|state| {
+                    !state.no_more
+                        && !state.posts.is_empty()
+                        && state.list_state.selected() == Some(state.posts.len() - 1)
+                })
+    }
+
+    fn start_load_more(&mut self) -> Option<Action> {
+        let state = self.platform_states.get_mut(&self.current_platform)?;
+        if state.loading_more {
+            return None;
         }
+        state.loading_more = true;
+        self.status_message = Some("Loading more posts...".to_string());
+        Some(Action::LoadMore)
+    }
+
+    /// Fetch the page after the current platform's posts. The first time,
+    /// there's no cursor yet, so the loaded posts are fetched again along
+    /// with the next page to get one.
+    fn load_more(&mut self) {
+        let platform = self.current_platform;
+        let (Some(client), Some(state)) = (
+            self.clients.get(&platform).cloned(),
+            self.platform_states.get(&platform),
+        ) else {
+            return;
+        };
+        let (limit, cursor) = match &state.next_cursor {
+            Some(cursor) => (LOAD_MORE_PAGE_SIZE, Some(cursor.clone())),
+            None => (state.posts.len() as u32 + LOAD_MORE_PAGE_SIZE, None),
+        };
+
+        let tx = self.event_tx.clone();
+        tokio::spawn(async move {
+            let result = client
+                .get_posts_paginated(Some(limit), cursor.as_deref())
+                .await;
+            let _ = tx.send(AppEvent::MorePostsLoaded(platform, result)).await;
+        });
+    }
+
+    /// Whether the shown post has replies for j/k to select
+    fn has_replies(&self) -> bool {
+        self.platform_states
+            .get(&self.current_platform)
+            .is_some_and(|state| !state.selected_replies.is_empty())
+    }
+
+    /// Scroll the detail panel by `rows` (negative scrolls up); drawing
+    /// clamps it to the end of the text
+    fn scroll_detail(&mut self, rows: i32) {
+        self.detail_scroll = self.detail_scroll.saturating_add_signed(rows as i16);
+    }
+
+    /// Scroll the detail panel by most of its height
+    fn page_detail(&mut self, down: bool) {
+        let page = i32::from(self.detail_height.saturating_sub(1).max(1));
+        self.scroll_detail(if down { page } else { -page });
     }
 
     fn move_up(&mut self) {
         match self.active_panel {
+            Panel::Threads if self.unified_view => self.step_unified(false),
             Panel::Threads => {
                 let Some(state) = self.platform_states.get_mut(&self.current_platform) else {
                     return;
@@ -889,89 +3761,1845 @@ q            Quit
                 };
                 state.list_state.select(Some(i));
             }
-            Panel::Detail => self.reply_move_up(),
+            Panel::Detail if self.has_replies() => self.reply_move_up(),
+            Panel::Detail => self.scroll_detail(-1),
         }
     }
 
-    fn move_left(&mut self) {
-        self.active_panel = Panel::Threads;
-    }
-
-    fn move_right(&mut self) {
-        self.active_panel = Panel::Detail;
+    /// The post the user is looking at: the selected reply, or else the selected post
+    fn selected_post(&self) -> Option<&Post> {
+        let state = self.platform_states.get(&self.current_platform)?;
+        if let Some(reply_idx) = state.reply_selection {
+            return Self::get_reply_at_index(&state.selected_replies, reply_idx).map(|r| &r.post);
+        }
+        state.posts.get(state.list_state.selected()?)
     }
 
-    fn toggle_panel(&mut self) {
-        self.swapped_layout = !self.swapped_layout;
-    }
+    /// Copy the selected author's handle or DID to the clipboard
+    fn copy_author(&mut self, field: AuthorField) {
+        let Some(post) = self.selected_post() else {
+            self.status_message = Some("No post selected".to_string());
+            return;
+        };
 
-    fn select_item(&mut self) {
-        self.active_panel = Panel::Detail;
-    }
+        let value = match field {
+            AuthorField::Handle => post.author_handle.clone(),
+            AuthorField::Did => post.author_did.clone(),
+        };
+        let Some(value) = value else {
+            self.status_message = Some(match field {
+                AuthorField::Handle => "No handle for this author".to_string(),
+                AuthorField::Did => format!("{} has no DID for authors", self.current_platform),
+            });
+            return;
+        };
 
-    fn deselect(&mut self) {
-        if let Some(state) = self.platform_states.get_mut(&self.current_platform) {
-            if state.reply_selection.is_some() {
-                state.reply_selection = None;
-            } else {
-                self.active_panel = Panel::Threads;
-            }
-        } else {
-            self.active_panel = Panel::Threads;
-        }
+        self.status_message = Some(match clipboard::copy(&value) {
+            Ok(()) => format!("Copied {}", value),
+            Err(e) => format!("Copy failed: {}", e),
+        });
     }
 
-    /// Count total flattened replies
-    fn count_replies(replies: &[ReplyThread]) -> usize {
-        replies
-            .iter()
-            .fold(0, |acc, r| acc + 1 + Self::count_replies(&r.replies))
-    }
+    /// Copy the selected post or reply's text to the clipboard
+    fn copy_text(&mut self) {
+        let Some(post) = self.selected_post() else {
+            self.status_message = Some("No post selected".to_string());
+            return;
+        };
+        let Some(text) = post.text.clone().filter(|t| !t.is_empty()) else {
+            self.status_message = Some("This post has no text".to_string());
+            return;
+        };
 
-    /// Get the reply ID at the given flattened index
-    fn get_reply_id_at_index(replies: &[ReplyThread], target: usize) -> Option<String> {
-        let mut current = 0;
-        fn find(replies: &[ReplyThread], target: usize, current: &mut usize) -> Option<String> {
-            for reply in replies {
-                if *current == target {
-                    return Some(reply.post.id.clone());
-                }
-                *current += 1;
-                if let Some(id) = find(&reply.replies, target, current) {
-                    return Some(id);
-                }
-            }
-            None
-        }
-        find(replies, target, &mut current)
+        self.status_message = Some(match clipboard::copy(&text) {
+            Ok(()) => "Copied".to_string(),
+            Err(e) => format!("Copy failed: {}", e),
+        });
     }
 
-    fn reply_move_down(&mut self) {
-        let Some(state) = self.platform_states.get_mut(&self.current_platform) else {
+    /// Open the selected post or reply's permalink in the default browser
+    fn open_permalink(&mut self) {
+        let Some(post) = self.selected_post() else {
+            self.status_message = Some("No post selected".to_string());
             return;
         };
-        let count = Self::count_replies(&state.selected_replies);
-        if count == 0 {
+        let Some(url) = post.permalink.clone() else {
+            self.status_message = Some("No link for this post".to_string());
             return;
-        }
-        state.reply_selection = Some(match state.reply_selection {
-            Some(i) if i >= count - 1 => 0,
-            Some(i) => i + 1,
-            None => 0,
+        };
+
+        // Detached so a slow browser launch doesn't stall the UI
+        self.status_message = Some(match open::that_detached(&url) {
+            Ok(()) => format!("Opened {}", url),
+            Err(e) => format!("Couldn't open browser: {}", e),
         });
     }
 
-    fn reply_move_up(&mut self) {
-        let Some(state) = self.platform_states.get_mut(&self.current_platform) else {
+    /// Write the selected post and its loaded replies to ~/.config/ndl/export.json
+    fn export_thread(&mut self) {
+        let Some(state) = self.platform_states.get(&self.current_platform) else {
+            self.status_message = Some("No post selected".to_string());
             return;
         };
-        let count = Self::count_replies(&state.selected_replies);
-        if count == 0 {
+
+        // A selected reply exports its own subtree; otherwise the whole thread
+        let thread = if let Some(reply_idx) = state.reply_selection {
+            Self::get_reply_at_index(&state.selected_replies, reply_idx).cloned()
+        } else {
+            state
+                .list_state
+                .selected()
+                .and_then(|idx| state.posts.get(idx))
+                .map(|post| ReplyThread {
+                    post: post.clone(),
+                    replies: if state.loaded_replies_for.as_ref() == Some(&post.id) {
+                        state.selected_replies.clone()
+                    } else {
+                        Vec::new()
+                    },
+                })
+        };
+        let Some(thread) = thread else {
+            self.status_message = Some("No post selected".to_string());
             return;
-        }
-        state.reply_selection = Some(match state.reply_selection {
-            Some(0) | None => count.saturating_sub(1),
-            Some(i) => i - 1,
+        };
+
+        let export = serde_json::json!({
+            "platform": self.current_platform,
+            "thread": thread,
+        });
+        let result = Config::dir()
+            .map(|dir| dir.join(EXPORT_FILE))
+            .and_then(|path| {
+                std::fs::create_dir_all(path.parent().unwrap_or(&path))?;
+                std::fs::write(&path, serde_json::to_string_pretty(&export)?)?;
+                Ok(path)
+            });
+
+        self.status_message = Some(match result {
+            Ok(path) => format!("Exported thread to {}", path.display()),
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
+    /// The selected post's author as (identifier for API calls, name for display)
+    fn selected_author(&mut self) -> Option<(String, String)> {
+        let Some(post) = self.selected_post() else {
+            self.status_message = Some("No post selected".to_string());
+            return None;
+        };
+        let handle = post.author_handle.clone();
+        let Some(actor) = post.author_did.clone().or_else(|| handle.clone()) else {
+            self.status_message = Some("No author for this post".to_string());
+            return None;
+        };
+        let display = handle
+            .map(|h| format!("@{}", h))
+            .unwrap_or_else(|| actor.clone());
+        Some((actor, display))
+    }
+
+    /// Follow or unfollow the selected post's author
+    fn follow_author(&mut self, follow: bool) {
+        let Some((actor, display)) = self.selected_author() else {
+            return;
+        };
+        let Some(client) = self.clients.get(&self.current_platform).cloned() else {
+            self.status_message = Some("No client available".to_string());
+            return;
+        };
+
+        let platform = self.current_platform;
+        let tx = self.event_tx.clone();
+        let (pending, done) = if follow {
+            ("Following", "Followed")
+        } else {
+            ("Unfollowing", "Unfollowed")
+        };
+        self.status_message = Some(format!("{} {}...", pending, display));
+
+        tokio::spawn(async move {
+            let result = if follow {
+                client.follow(&actor).await
+            } else {
+                client.unfollow(&actor).await
+            };
+            let _ = tx
+                .send(AppEvent::ActionResult(
+                    platform,
+                    format!("{} {}", done, display),
+                    result.map_err(|e| e.to_string()),
+                ))
+                .await;
+        });
+    }
+
+    /// Like the selected post or reply, or unlike it if it's already liked
+    fn toggle_like(&mut self) -> Option<Action> {
+        let Some(post) = self.selected_post() else {
+            self.status_message = Some("No post selected".to_string());
+            return None;
+        };
+        if !post.is_available() {
+            self.status_message = Some("Can't like a blocked or deleted post".to_string());
+            return None;
+        }
+        Some(Action::Like(post.id.clone(), !post.liked))
+    }
+
+    /// Repost the selected post or reply
+    fn start_repost(&mut self) -> Option<Action> {
+        let Some(post) = self.selected_post() else {
+            self.status_message = Some("No post selected".to_string());
+            return None;
+        };
+        if !post.is_available() {
+            self.status_message = Some("Can't repost a blocked or deleted post".to_string());
+            return None;
+        }
+        Some(Action::Repost(post.id.clone()))
+    }
+
+    fn repost(&mut self, id: String) {
+        let Some(client) = self.clients.get(&self.current_platform).cloned() else {
+            self.status_message = Some("No client available".to_string());
+            return;
+        };
+
+        let platform = self.current_platform;
+        let tx = self.event_tx.clone();
+        self.status_message = Some("Reposting...".to_string());
+
+        tokio::spawn(async move {
+            let result = client.repost(&id).await.map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::Reposted(platform, id, result)).await;
+        });
+    }
+
+    fn like_post(&mut self, id: String, like: bool) {
+        let Some(client) = self.clients.get(&self.current_platform).cloned() else {
+            self.status_message = Some("No client available".to_string());
+            return;
+        };
+
+        let platform = self.current_platform;
+        let tx = self.event_tx.clone();
+        self.status_message = Some(if like { "Liking..." } else { "Unliking..." }.to_string());
+
+        tokio::spawn(async move {
+            let result = if like {
+                client.like_post(&id).await
+            } else {
+                client.unlike_post(&id).await
+            };
+            let _ = tx
+                .send(AppEvent::LikeResult(
+                    platform,
+                    id,
+                    like,
+                    result.map_err(|e| e.to_string()),
+                ))
+                .await;
+        });
+    }
+
+    /// Ask for confirmation before muting or blocking the selected author
+    fn confirm_moderation(&mut self, action: Moderation) {
+        let Some((actor, display)) = self.selected_author() else {
+            return;
+        };
+        let verb = match action {
+            Moderation::Mute => "Mute",
+            Moderation::Block => "Block",
+        };
+        self.status_message = Some(format!("{} {}? (y/n)", verb, display));
+        self.pending_moderation = Some(PendingModeration {
+            action,
+            actor,
+            display,
+        });
+    }
+
+    /// Ask for confirmation before deleting the selected post or reply
+    fn confirm_delete(&mut self) {
+        let Some(post) = self.selected_post() else {
+            self.status_message = Some("No post selected".to_string());
+            return;
+        };
+        // Other people's posts can't be deleted; the platform would refuse anyway
+        let own_handle = self
+            .platform_states
+            .get(&self.current_platform)
+            .and_then(|state| state.profile.as_ref())
+            .map(|profile| profile.handle.as_str());
+        if let (Some(own), Some(author)) = (own_handle, post.author_handle.as_deref())
+            && own != author
+        {
+            self.status_message = Some("You can only delete your own posts".to_string());
+            return;
+        }
+
+        let id = post.id.clone();
+        self.status_message = Some("Delete this post? (y/n)".to_string());
+        self.pending_delete = Some(id);
+        self.input_mode = InputMode::ConfirmDelete;
+    }
+
+    fn handle_confirm_delete(&mut self, key: KeyCode) -> Option<Action> {
+        self.input_mode = InputMode::Normal;
+        let id = self.pending_delete.take()?;
+        if key == KeyCode::Char('y') {
+            return Some(Action::DeletePost(id));
+        }
+        self.status_message = Some("Cancelled".to_string());
+        None
+    }
+
+    /// Delete a post, removing it from the list once the platform confirms
+    fn delete_post(&mut self, id: String) {
+        let Some(client) = self.clients.get(&self.current_platform).cloned() else {
+            self.status_message = Some("No client available".to_string());
+            return;
+        };
+
+        let platform = self.current_platform;
+        let tx = self.event_tx.clone();
+        self.status_message = Some("Deleting post...".to_string());
+
+        tokio::spawn(async move {
+            let result = client.delete_post(&id).await.map_err(|e| e.to_string());
+            let _ = tx.send(AppEvent::PostDeleted(platform, id, result)).await;
+        });
+    }
+
+    /// Mute or block an author, hiding their posts once the platform confirms
+    fn moderate(&mut self, pending: PendingModeration) {
+        let Some(client) = self.clients.get(&self.current_platform).cloned() else {
+            self.status_message = Some("No client available".to_string());
+            return;
+        };
+
+        let platform = self.current_platform;
+        let tx = self.event_tx.clone();
+        let PendingModeration {
+            action,
+            actor,
+            display,
+        } = pending;
+        let (pending, done) = match action {
+            Moderation::Mute => ("Muting", "Muted"),
+            Moderation::Block => ("Blocking", "Blocked"),
+        };
+        self.status_message = Some(format!("{} {}...", pending, display));
+
+        tokio::spawn(async move {
+            let result = match action {
+                Moderation::Mute => client.mute(&actor).await,
+                Moderation::Block => client.block(&actor).await,
+            };
+            let _ = tx
+                .send(AppEvent::AuthorModerated(
+                    platform,
+                    actor,
+                    format!("{} {}", done, display),
+                    result.map_err(|e| e.to_string()),
+                ))
+                .await;
+        });
+    }
+
+    fn move_left(&mut self) {
+        self.active_panel = Panel::Threads;
+    }
+
+    fn move_right(&mut self) {
+        self.active_panel = Panel::Detail;
+    }
+
+    fn toggle_panel(&mut self) {
+        self.swapped_layout = !self.swapped_layout;
+    }
+
+    fn select_item(&mut self) {
+        self.active_panel = Panel::Detail;
+    }
+
+    fn deselect(&mut self) {
+        if let Some(state) = self.platform_states.get_mut(&self.current_platform) {
+            if state.reply_selection.is_some() {
+                state.reply_selection = None;
+            } else {
+                self.active_panel = Panel::Threads;
+            }
+        } else {
+            self.active_panel = Panel::Threads;
+        }
+    }
+
+    /// Move from the selected reply to its parent, or from a reply post to its
+    /// thread root, asking to fetch the root when it isn't loaded
+    fn jump_to_parent(&mut self) -> Option<Action> {
+        let state = self.platform_states.get_mut(&self.current_platform)?;
+
+        if let Some(reply_idx) = state.reply_selection {
+            // Top-level replies go back to the post the replies were loaded for
+            state.reply_selection =
+                Self::get_parent_index(&state.selected_replies, reply_idx).flatten();
+            return None;
+        }
+
+        let root_id = state
+            .list_state
+            .selected()
+            .and_then(|idx| state.posts.get(idx))
+            .and_then(|post| post.root_id.clone());
+        let Some(root_id) = root_id else {
+            self.status_message = Some("Already at the top of the thread".to_string());
+            return None;
+        };
+
+        match state.posts.iter().position(|p| p.id == root_id) {
+            Some(idx) => {
+                state.list_state.select(Some(idx));
+                self.active_panel = Panel::Threads;
+                None
+            }
+            // Fetch the root and show it like a pasted link
+            None => Some(Action::OpenPost(PostLink {
+                platform: self.current_platform,
+                id: root_id,
+            })),
+        }
+    }
+
+    /// Get the flattened index of a reply's parent (`Some(None)` for top-level replies)
+    fn get_parent_index(replies: &[ReplyThread], target: usize) -> Option<Option<usize>> {
+        fn find(
+            replies: &[ReplyThread],
+            target: usize,
+            parent: Option<usize>,
+            current: &mut usize,
+        ) -> Option<Option<usize>> {
+            for reply in replies {
+                let idx = *current;
+                if idx == target {
+                    return Some(parent);
+                }
+                *current += 1;
+                if let Some(found) = find(&reply.replies, target, Some(idx), current) {
+                    return Some(found);
+                }
+            }
+            None
+        }
+        find(replies, target, None, &mut 0)
+    }
+
+    /// Count total flattened replies
+    fn count_replies(replies: &[ReplyThread]) -> usize {
+        replies
+            .iter()
+            .fold(0, |acc, r| acc + 1 + Self::count_replies(&r.replies))
+    }
+
+    /// The flattened index of the reply with this post id
+    fn reply_index_of(replies: &[ReplyThread], id: &str) -> Option<usize> {
+        fn find(replies: &[ReplyThread], id: &str, current: &mut usize) -> Option<usize> {
+            for reply in replies {
+                if reply.post.id == id {
+                    return Some(*current);
+                }
+                *current += 1;
+                if let Some(found) = find(&reply.replies, id, current) {
+                    return Some(found);
+                }
+            }
+            None
+        }
+        find(replies, id, &mut 0)
+    }
+
+    /// Get the reply at the given flattened index
+    fn get_reply_at_index(replies: &[ReplyThread], target: usize) -> Option<&ReplyThread> {
+        let mut current = 0;
+        fn find<'a>(
+            replies: &'a [ReplyThread],
+            target: usize,
+            current: &mut usize,
+        ) -> Option<&'a ReplyThread> {
+            for reply in replies {
+                if *current == target {
+                    return Some(reply);
+                }
+                *current += 1;
+                if let Some(found) = find(&reply.replies, target, current) {
+                    return Some(found);
+                }
+            }
+            None
+        }
+        find(replies, target, &mut current)
+    }
+
+    fn reply_move_down(&mut self) {
+        let Some(state) = self.platform_states.get_mut(&self.current_platform) else {
+            return;
+        };
+        let count = Self::count_replies(&state.selected_replies);
+        if count == 0 {
+            return;
+        }
+        state.reply_selection = Some(match state.reply_selection {
+            Some(i) if i >= count - 1 => 0,
+            Some(i) => i + 1,
+            None => 0,
+        });
+    }
+
+    fn reply_move_up(&mut self) {
+        let Some(state) = self.platform_states.get_mut(&self.current_platform) else {
+            return;
+        };
+        let count = Self::count_replies(&state.selected_replies);
+        if count == 0 {
+            return;
+        }
+        state.reply_selection = Some(match state.reply_selection {
+            Some(0) | None => count.saturating_sub(1),
+            Some(i) => i - 1,
+        });
+    }
+}
+
+/// Posts fetched per "load more" page
+const LOAD_MORE_PAGE_SIZE: u32 = 25;
+
+/// Text rows the input popup shows before it starts growing
+const MIN_INPUT_ROWS: usize = 3;
+/// Tallest the input popup grows; longer text scrolls to follow the cursor
+const MAX_INPUT_ROWS: usize = 12;
+
+/// Break composer text into rows of at most `width` columns, wrapping long
+/// lines at any char, and find the (row, column) of the char at `cursor`
+fn layout_input(text: &str, cursor: usize, width: usize) -> (Vec<String>, (usize, usize)) {
+    let mut rows = vec![String::new()];
+    let mut col = 0;
+    let mut at = None;
+    for (i, c) in text.chars().enumerate() {
+        if c == '\n' {
+            if i == cursor {
+                at = Some((rows.len() - 1, col));
+            }
+            rows.push(String::new());
+            col = 0;
+            continue;
+        }
+        let char_width = Span::raw(c.to_string()).width();
+        if col > 0 && col + char_width > width {
+            rows.push(String::new());
+            col = 0;
+        }
+        if i == cursor {
+            at = Some((rows.len() - 1, col));
+        }
+        rows.last_mut().unwrap().push(c);
+        col += char_width;
+    }
+
+    let (row, col) = at.unwrap_or((rows.len() - 1, col));
+    // A cursor past the end of a full row goes to the start of the next
+    let at = if col >= width {
+        (row + 1, 0)
+    } else {
+        (row, col)
+    };
+    (rows, at)
+}
+
+/// Prefix each line with `> ` and leave a blank line to type below
+fn quote_text(text: &str) -> String {
+    let quoted: Vec<String> = text
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                ">".to_string()
+            } else {
+                format!("> {}", line)
+            }
+        })
+        .collect();
+    format!("{}\n\n", quoted.join("\n"))
+}
+
+/// The profile popup's text: who's logged in and their counts
+fn profile_text(profile: Option<&Profile>) -> String {
+    let Some(profile) = profile else {
+        return "Profile not loaded yet".to_string();
+    };
+    let count = |n: Option<u64>| n.map_or_else(|| "-".to_string(), |n| n.to_string());
+
+    let mut lines = vec![format!("@{}", profile.handle)];
+    if let Some(name) = &profile.display_name {
+        lines.push(name.clone());
+    }
+    if let Some(bio) = profile.bio.as_deref().filter(|bio| !bio.trim().is_empty()) {
+        lines.push(String::new());
+        lines.push(bio.to_string());
+    }
+    lines.push(String::new());
+    lines.push(format!(
+        "Followers: {}  Following: {}  Posts: {}",
+        count(profile.followers),
+        count(profile.following),
+        count(profile.posts)
+    ));
+    lines.join("\n")
+}
+
+/// Truncate text to at most `max_chars` characters, ending with "..." when cut
+fn truncate_preview(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let keep = max_chars.saturating_sub(3);
+    let truncated: String = text.chars().take(keep).collect();
+    format!("{}...", truncated)
+}
+
+/// Format engagement counts as a single line (e.g. "♥ 12  ↻ 3  💬 5")
+fn format_engagement(post: &Post) -> Option<String> {
+    let parts: Vec<String> = [
+        ("♥", post.like_count),
+        ("↻", post.repost_count),
+        ("💬", post.reply_count),
+    ]
+    .iter()
+    .filter_map(|(icon, count)| count.map(|c| format!("{} {}", icon, c)))
+    .collect();
+
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join("  "))
+    }
+}
+
+/// Detail panel text built up from plain and highlighted fragments
+#[derive(Default)]
+struct StyledText {
+    lines: Vec<Line<'static>>,
+    current: Vec<Span<'static>>,
+}
+
+impl StyledText {
+    /// Append text in the given style, starting a new line at each `\n`
+    fn push(&mut self, text: &str, style: Style) {
+        for (i, part) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.lines
+                    .push(Line::from(std::mem::take(&mut self.current)));
+            }
+            if !part.is_empty() {
+                self.current.push(Span::styled(part.to_string(), style));
+            }
+        }
+    }
+
+    fn push_plain(&mut self, text: &str) {
+        self.push(text, Style::default());
+    }
+
+    /// Append post text with its links and mentions highlighted
+    fn push_post_text(&mut self, text: &str, facets: &[Facet]) {
+        let mut facets = facets.to_vec();
+        facets.sort_by_key(|f| f.start);
+
+        let mut pos = 0;
+        for facet in facets {
+            // Skip ranges that overlap one already drawn or don't fit the text
+            if facet.start < pos || text.get(facet.start..facet.end).is_none() {
+                continue;
+            }
+            self.push_plain(&text[pos..facet.start]);
+            let style = match facet.kind {
+                FacetKind::Link => Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::UNDERLINED),
+                FacetKind::Mention => Style::default().fg(Color::Magenta),
+            };
+            self.push(&text[facet.start..facet.end], style);
+            pos = facet.end;
+        }
+        self.push_plain(&text[pos..]);
+    }
+
+    fn into_text(mut self) -> Text<'static> {
+        self.lines.push(Line::from(self.current));
+        Text::from(self.lines)
+    }
+}
+
+/// Send composed text: a carousel when it has `@img:` URLs, a link card post
+/// for an `@link:` URL, a chain of self-replies when it has `---` separator
+/// lines, otherwise a single post
+/// Why `text` can never be sent to `platform` as it is (a bad `@link:`, a
+/// link with images, or text over the limit), if it can't
+fn unsendable(platform: Platform, text: &str) -> Option<String> {
+    let (rest, links) = extract_links(text);
+    if !links.is_empty() && (links.len() > 1 || !extract_images(&rest).1.is_empty()) {
+        return Some("A post can have one @link: and no images".to_string());
+    }
+    if let Some(link) = links.first()
+        && let Err(e) = validate_link(link)
+    {
+        return Some(e);
+    }
+    let length = post_length(text);
+    (length > platform.char_limit()).then(|| {
+        format!(
+            "Too long to send: {} characters, the limit is {}",
+            length,
+            platform.char_limit()
+        )
+    })
+}
+
+async fn publish(
+    client: &Arc<Box<dyn SocialClient>>,
+    text: &str,
+    alt_texts: &[String],
+) -> Result<(), String> {
+    let (text, links) = extract_links(text);
+    let (caption, urls) = extract_images(&text);
+    if !links.is_empty() {
+        if links.len() > 1 || !urls.is_empty() {
+            return Err("A post can have one @link: and no images".to_string());
+        }
+        let link = validate_link(&links[0])?;
+        return client
+            .create_link_post(&caption, &link)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string());
+    }
+    if !urls.is_empty() {
+        let images = ImageAttachment::with_alts(urls, alt_texts);
+        return client
+            .create_carousel(&caption, &images)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string());
+    }
+
+    let segments = split_thread(&text);
+    if segments.len() > 1 {
+        client
+            .create_thread(&segments)
+            .await
+            .map_err(|e| e.to_string())
+    } else {
+        client
+            .create_post(&text)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::Capabilities;
+    use async_trait::async_trait;
+
+    /// Client that never gets called: key handling doesn't touch the network
+    struct StubClient(Capabilities);
+
+    const ALL_FEATURES: Capabilities = Capabilities {
+        profiles: true,
+        follows: true,
+        moderation: true,
+        author_dids: true,
+        conversations: true,
+        likes: true,
+    };
+
+    #[async_trait]
+    impl SocialClient for StubClient {
+        fn capabilities(&self) -> Capabilities {
+            self.0
+        }
+
+        async fn get_posts(&self, _limit: Option<u32>) -> Result<Vec<Post>, PlatformError> {
+            Ok(Vec::new())
+        }
+
+        async fn get_post_replies(
+            &self,
+            _post_id: &str,
+            _depth: u8,
+        ) -> Result<Vec<ReplyThread>, PlatformError> {
+            Ok(Vec::new())
+        }
+
+        async fn create_post(&self, _text: &str) -> Result<String, PlatformError> {
+            Ok(String::new())
+        }
+
+        async fn reply_to_post(
+            &self,
+            _post_id: &str,
+            _text: &str,
+        ) -> Result<String, PlatformError> {
+            Ok(String::new())
+        }
+    }
+
+    #[test]
+    fn test_unsendable_scheduled_posts() {
+        assert_eq!(unsendable(Platform::Bluesky, "hello"), None);
+        assert!(unsendable(Platform::Bluesky, &"a".repeat(301)).is_some());
+        assert_eq!(unsendable(Platform::Threads, &"a".repeat(301)), None);
+        assert!(unsendable(Platform::Threads, "look @link:ftp://example.com").is_some());
+        assert!(
+            unsendable(
+                Platform::Threads,
+                "@link:https://a.example @link:https://b.example"
+            )
+            .is_some()
+        );
+    }
+
+    fn post(id: &str) -> Post {
+        Post {
+            id: id.to_string(),
+            text: Some(format!("post {}", id)),
+            author_handle: Some("someone".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn reply(id: &str, replies: Vec<ReplyThread>) -> ReplyThread {
+        ReplyThread {
+            post: post(id),
+            replies,
+        }
+    }
+
+    fn app_with(platforms: &[Platform], posts: usize) -> App {
+        let clients: HashMap<Platform, Box<dyn SocialClient>> = platforms
+            .iter()
+            .map(|p| {
+                let client = StubClient(ALL_FEATURES);
+                (*p, Box::new(client) as Box<dyn SocialClient>)
+            })
+            .collect();
+        let mut app = App::with_config(clients, AppConfig::default());
+        for state in app.platform_states.values_mut() {
+            state.posts = (0..posts).map(|i| post(&i.to_string())).collect();
+        }
+        app
+    }
+
+    fn press(app: &mut App, key: KeyCode) -> Option<Action> {
+        app.apply_key(key, KeyModifiers::NONE)
+    }
+
+    fn state(app: &App) -> &PlatformState {
+        &app.platform_states[&app.current_platform]
+    }
+
+    #[test]
+    fn test_empty_detail_distinguishes_loading_from_empty() {
+        let mut app = app_with(&[Platform::Threads], 0);
+        assert_eq!(app.empty_detail(state(&app)), "Loading Threads posts...");
+
+        app.platform_states
+            .get_mut(&Platform::Threads)
+            .unwrap()
+            .set_posts(Vec::new());
+        assert_eq!(
+            app.empty_detail(state(&app)),
+            "No Threads posts yet — press p to create one"
+        );
+
+        app.read_only = true;
+        assert_eq!(app.empty_detail(state(&app)), "No Threads posts yet");
+
+        let app = app_with(&[Platform::Threads], 2);
+        assert_eq!(app.empty_detail(state(&app)), "No post selected");
+    }
+
+    #[test]
+    fn test_list_navigation_wraps() {
+        let mut app = app_with(&[Platform::Threads], 3);
+
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(state(&app).list_state.selected(), Some(0));
+        press(&mut app, KeyCode::Char('j'));
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(state(&app).list_state.selected(), Some(2));
+
+        // At the end, j asks for another page first, and wraps once there isn't one
+        assert!(matches!(
+            press(&mut app, KeyCode::Down),
+            Some(Action::LoadMore)
+        ));
+        let threads = app.platform_states.get_mut(&Platform::Threads).unwrap();
+        threads.loading_more = false;
+        threads.append_page(Vec::new(), None);
+        press(&mut app, KeyCode::Down);
+        assert_eq!(state(&app).list_state.selected(), Some(0));
+        press(&mut app, KeyCode::Up);
+        assert_eq!(state(&app).list_state.selected(), Some(2));
+    }
+
+    #[test]
+    fn test_unavailable_post_blocks_reply_and_drops_on_refresh() {
+        let mut app = app_with(&[Platform::Threads], 3);
+        let platform = app.current_platform;
+        let state = app.platform_states.get_mut(&platform).unwrap();
+        state.list_state.select(Some(2));
+        state.unavailable.insert("2".to_string());
+
+        press(&mut app, KeyCode::Char('r'));
+        assert_eq!(app.input_mode, InputMode::Normal);
+
+        let posts = (0..3).map(|i| post(&i.to_string())).collect();
+        let state = app.platform_states.get_mut(&platform).unwrap();
+        state.set_posts(posts);
+        assert_eq!(state.posts.len(), 2);
+        assert!(state.unavailable.is_empty());
+        assert_eq!(state.list_state.selected(), Some(1));
+    }
+
+    #[test]
+    fn test_selection_follows_post_across_refresh() {
+        let mut app = app_with(&[Platform::Threads], 3);
+        let platform = app.current_platform;
+        let state = app.platform_states.get_mut(&platform).unwrap();
+        state.list_state.select(Some(1));
+
+        // Two new posts arrive at the top
+        let posts = ["new1", "new0", "0", "1", "2"].map(post).to_vec();
+        state.set_posts(posts);
+        assert_eq!(state.list_state.selected(), Some(3));
+        assert_eq!(state.posts[3].id, "1");
+    }
+
+    #[test]
+    fn test_like_toggles_and_adjusts_count() {
+        let mut app = app_with(&[Platform::Bluesky], 1);
+        let bluesky = app.platform_states.get_mut(&Platform::Bluesky).unwrap();
+        bluesky.posts[0].like_count = Some(2);
+        bluesky.list_state.select(Some(0));
+
+        assert!(matches!(
+            press(&mut app, KeyCode::Char('f')),
+            Some(Action::Like(ref id, true)) if id == "0"
+        ));
+        let bluesky = app.platform_states.get_mut(&Platform::Bluesky).unwrap();
+        bluesky.set_liked("0", true);
+        bluesky.set_liked("0", true);
+        assert_eq!(bluesky.posts[0].like_count, Some(3));
+
+        assert!(matches!(
+            press(&mut app, KeyCode::Char('f')),
+            Some(Action::Like(_, false))
+        ));
+    }
+
+    #[test]
+    fn test_post_liked_earlier_unlikes_without_recounting() {
+        let mut app = app_with(&[Platform::Bluesky], 1);
+        let bluesky = app.platform_states.get_mut(&Platform::Bluesky).unwrap();
+        bluesky.posts[0].like_count = Some(2);
+        bluesky.posts[0].liked = true;
+        bluesky.list_state.select(Some(0));
+
+        assert!(matches!(
+            press(&mut app, KeyCode::Char('f')),
+            Some(Action::Like(_, false))
+        ));
+        let bluesky = app.platform_states.get_mut(&Platform::Bluesky).unwrap();
+        bluesky.set_liked("0", true);
+        assert_eq!(bluesky.posts[0].like_count, Some(2));
+        bluesky.set_liked("0", false);
+        assert_eq!(bluesky.posts[0].like_count, Some(1));
+    }
+
+    #[test]
+    fn test_repost_and_quote_post() {
+        let mut app = app_with(&[Platform::Bluesky], 1);
+        let bluesky = app.platform_states.get_mut(&Platform::Bluesky).unwrap();
+        bluesky.posts[0].permalink = Some("https://bsky.app/profile/a/post/0".to_string());
+        bluesky.list_state.select(Some(0));
+
+        assert!(matches!(
+            press(&mut app, KeyCode::Char('b')),
+            Some(Action::Repost(ref id)) if id == "0"
+        ));
+
+        press(&mut app, KeyCode::Char('B'));
+        assert_eq!(app.input_mode, InputMode::Posting);
+        assert_eq!(app.input_buffer, "\n\nhttps://bsky.app/profile/a/post/0");
+        assert_eq!(app.input_cursor, 0);
+    }
+
+    #[test]
+    fn test_gg_and_g_jump_to_list_edges() {
+        let mut app = app_with(&[Platform::Threads], 5);
+        press(&mut app, KeyCode::Char('G'));
+        assert_eq!(state(&app).list_state.selected(), Some(4));
+
+        press(&mut app, KeyCode::Char('g'));
+        press(&mut app, KeyCode::Char('g'));
+        assert_eq!(state(&app).list_state.selected(), Some(0));
+
+        // Another key in between disarms the first `g`
+        press(&mut app, KeyCode::Char('G'));
+        press(&mut app, KeyCode::Char('g'));
+        press(&mut app, KeyCode::Char('k'));
+        press(&mut app, KeyCode::Char('g'));
+        assert_eq!(state(&app).list_state.selected(), Some(3));
+
+        let mut empty = app_with(&[Platform::Threads], 0);
+        press(&mut empty, KeyCode::Char('G'));
+        press(&mut empty, KeyCode::Char('g'));
+        press(&mut empty, KeyCode::Char('g'));
+        assert_eq!(state(&empty).list_state.selected(), None);
+    }
+
+    #[test]
+    fn test_profile_popup() {
+        let mut app = app_with(&[Platform::Bluesky], 1);
+        press(&mut app, KeyCode::Char('i'));
+        assert!(app.show_profile);
+        // `q` closes the popup rather than quitting
+        press(&mut app, KeyCode::Char('q'));
+        assert!(app.running);
+        assert!(!app.show_profile);
+
+        press(&mut app, KeyCode::Char('i'));
+        assert!(matches!(
+            press(&mut app, KeyCode::Char('r')),
+            Some(Action::RefreshProfile)
+        ));
+        assert!(app.show_profile);
+        press(&mut app, KeyCode::Esc);
+        assert!(!app.show_profile);
+
+        assert_eq!(profile_text(None), "Profile not loaded yet");
+        let profile = Profile {
+            handle: "alice.test".to_string(),
+            display_name: Some("Alice".to_string()),
+            bio: Some("hi".to_string()),
+            followers: Some(10),
+            following: Some(2),
+            posts: None,
+        };
+        assert_eq!(
+            profile_text(Some(&profile)),
+            "@alice.test\nAlice\n\nhi\n\nFollowers: 10  Following: 2  Posts: -"
+        );
+    }
+
+    #[test]
+    fn test_unified_view_merges_by_time() {
+        let mut app = app_with(&[Platform::Threads, Platform::Bluesky], 2);
+        let stamp = |app: &mut App, platform, i: usize, ts: &str| {
+            app.platform_states.get_mut(&platform).unwrap().posts[i].timestamp =
+                Some(ts.to_string());
+        };
+        stamp(&mut app, Platform::Threads, 0, "2024-06-01T12:00:00+0000");
+        stamp(&mut app, Platform::Threads, 1, "2024-06-01T09:00:00+0000");
+        stamp(&mut app, Platform::Bluesky, 0, "2024-06-01T11:00:00.000Z");
+        // Bluesky's second post has no timestamp and sorts last
+
+        press(&mut app, KeyCode::Char('u'));
+        assert!(app.unified_view);
+        assert_eq!(
+            app.unified_entries(),
+            vec![
+                (Platform::Threads, 0),
+                (Platform::Bluesky, 0),
+                (Platform::Threads, 1),
+                (Platform::Bluesky, 1),
+            ]
+        );
+
+        // Moving through the merged list follows each post to its platform
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(app.current_platform, Platform::Threads);
+        assert_eq!(state(&app).list_state.selected(), Some(0));
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(app.current_platform, Platform::Bluesky);
+        assert_eq!(state(&app).list_state.selected(), Some(0));
+        press(&mut app, KeyCode::Char('G'));
+        assert_eq!(app.current_platform, Platform::Bluesky);
+        assert_eq!(state(&app).list_state.selected(), Some(1));
+        press(&mut app, KeyCode::Char('k'));
+        assert_eq!(app.current_platform, Platform::Threads);
+        assert_eq!(state(&app).list_state.selected(), Some(1));
+
+        press(&mut app, KeyCode::Char('u'));
+        assert!(!app.unified_view);
+
+        let mut single = app_with(&[Platform::Threads], 2);
+        press(&mut single, KeyCode::Char('u'));
+        assert!(!single.unified_view);
+    }
+
+    #[test]
+    fn test_cross_post_summary_lists_every_platform() {
+        let mut batch = CrossPostBatch {
+            id: 0,
+            platforms: vec![Platform::Bluesky, Platform::Threads],
+            results: HashMap::new(),
+            started: Instant::now(),
+            complete: false,
+        };
+        batch.results.insert(Platform::Bluesky, Ok(()));
+        assert_eq!(batch.summary(), "Cross-posting: Bluesky ✓, Threads …");
+
+        batch
+            .results
+            .insert(Platform::Threads, Err("rate limited".to_string()));
+        assert!(batch.is_done());
+        batch.complete = true;
+        assert_eq!(
+            batch.summary(),
+            "Posted: Bluesky ✓, Threads ✗ (rate limited)"
+        );
+
+        batch.results.remove(&Platform::Threads);
+        assert_eq!(batch.summary(), "Posted: Bluesky ✓, Threads ✗ (timed out)");
+    }
+
+    #[test]
+    fn test_failed_send_can_be_retried() {
+        let mut app = app_with(&[Platform::Threads], 1);
+        press(&mut app, KeyCode::Char('.'));
+        assert_eq!(app.status_message.as_deref(), Some("Nothing to retry"));
+
+        let draft = Draft {
+            platform: Platform::Threads,
+            text: "hello".to_string(),
+            alt_texts: Vec::new(),
+            reply_to: Some("0".to_string()),
+        };
+        app.send_finished(draft.clone(), Err("container timed out".to_string()));
+        assert_eq!(app.failed_send.as_ref(), Some(&draft));
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Threads error: container timed out (. to retry)")
+        );
+        assert!(matches!(
+            press(&mut app, KeyCode::Char('.')),
+            Some(Action::RetrySend)
+        ));
+
+        // Another post succeeding doesn't drop the failed one
+        let other = Draft {
+            reply_to: None,
+            ..draft.clone()
+        };
+        app.send_finished(other, Ok(()));
+        assert!(app.failed_send.is_some());
+        app.send_finished(draft, Ok(()));
+        assert!(app.failed_send.is_none());
+        assert_eq!(app.status_message.as_deref(), Some("Replied on Threads!"));
+    }
+
+    #[test]
+    fn test_refresh_keeps_loaded_pages() {
+        let mut app = app_with(&[Platform::Threads], 2);
+        let threads = app.platform_states.get_mut(&Platform::Threads).unwrap();
+        threads.append_page(["2", "3"].map(post).to_vec(), Some("next".to_string()));
+        assert!(!threads.no_more);
+
+        // A refresh of the first page adds a new post above the older pages
+        threads.set_posts(["new", "0"].map(post).to_vec());
+        let ids: Vec<_> = threads.posts.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, ["new", "0", "1", "2", "3"]);
+    }
+
+    #[test]
+    fn test_streamed_post_prepends_once() {
+        let mut app = app_with(&[Platform::Bluesky], 2);
+        let state = app.platform_states.get_mut(&Platform::Bluesky).unwrap();
+        state.list_state.select(Some(1));
+
+        state.prepend_post(post("new"));
+        state.prepend_post(post("new"));
+        let ids: Vec<_> = state.posts.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, ["new", "0", "1"]);
+        assert_eq!(state.list_state.selected(), Some(2));
+    }
+
+    #[test]
+    fn test_navigation_on_empty_list() {
+        let mut app = app_with(&[Platform::Threads], 0);
+
+        press(&mut app, KeyCode::Char('j'));
+        press(&mut app, KeyCode::Char('k'));
+        assert_eq!(state(&app).list_state.selected(), None);
+    }
+
+    #[test]
+    fn test_panel_focus() {
+        let mut app = app_with(&[Platform::Threads], 1);
+        assert_eq!(app.active_panel, Panel::Threads);
+
+        press(&mut app, KeyCode::Char('l'));
+        assert_eq!(app.active_panel, Panel::Detail);
+        press(&mut app, KeyCode::Char('h'));
+        assert_eq!(app.active_panel, Panel::Threads);
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.active_panel, Panel::Detail);
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.active_panel, Panel::Threads);
+
+        press(&mut app, KeyCode::Char('t'));
+        assert!(app.swapped_layout);
+    }
+
+    #[test]
+    fn test_platform_toggle() {
+        let mut app = app_with(&[Platform::Threads, Platform::Bluesky], 1);
+        let first = app.current_platform;
+
+        press(&mut app, KeyCode::Tab);
+        assert_ne!(app.current_platform, first);
+        press(&mut app, KeyCode::Char(']'));
+        assert_eq!(app.current_platform, first);
+    }
+
+    #[test]
+    fn test_platform_toggle_single_platform() {
+        let mut app = app_with(&[Platform::Bluesky], 1);
+
+        press(&mut app, KeyCode::Tab);
+        assert_eq!(app.current_platform, Platform::Bluesky);
+        assert_eq!(app.status_message, None);
+    }
+
+    #[test]
+    fn test_reply_selection_bounds() {
+        let mut app = app_with(&[Platform::Threads], 1);
+        let platform = app.current_platform;
+        app.platform_states
+            .get_mut(&platform)
+            .unwrap()
+            .selected_replies = vec![
+            reply("a", vec![reply("a1", Vec::new())]),
+            reply("b", Vec::new()),
+        ];
+        press(&mut app, KeyCode::Char('l'));
+
+        // Up from no selection goes to the last flattened reply
+        press(&mut app, KeyCode::Char('k'));
+        assert_eq!(state(&app).reply_selection, Some(2));
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(state(&app).reply_selection, Some(0));
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(state(&app).reply_selection, Some(1));
+
+        // Esc clears the reply selection before leaving the detail panel
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(state(&app).reply_selection, None);
+        assert_eq!(app.active_panel, Panel::Detail);
+    }
+
+    #[test]
+    fn test_reply_target_follows_reply_selection() {
+        let mut app = app_with(&[Platform::Threads], 1);
+        let platform = app.current_platform;
+        let state = app.platform_states.get_mut(&platform).unwrap();
+        state.list_state.select(Some(0));
+        state.selected_replies = vec![reply("a", vec![reply("a1", Vec::new())])];
+
+        assert_eq!(state.reply_target().map(|p| p.id.as_str()), Some("0"));
+        state.reply_selection = Some(1);
+        assert_eq!(state.reply_target().map(|p| p.id.as_str()), Some("a1"));
+    }
+
+    #[test]
+    fn test_quote_reply_prefills_composer() {
+        let mut app = app_with(&[Platform::Threads], 1);
+        let platform = app.current_platform;
+        let state = app.platform_states.get_mut(&platform).unwrap();
+        state.list_state.select(Some(0));
+        state.posts[0].text = Some("first\n\nsecond".to_string());
+
+        press(&mut app, KeyCode::Char('>'));
+        assert_eq!(app.input_mode, InputMode::Replying);
+        assert_eq!(app.input_buffer, "> first\n>\n> second\n\n");
+    }
+
+    #[test]
+    fn test_restricted_replies_block_composer() {
+        let mut app = app_with(&[Platform::Bluesky], 2);
+        let state = app.platform_states.get_mut(&Platform::Bluesky).unwrap();
+        state.list_state.select(Some(0));
+        state.posts[0].reply_disabled = true;
+        state.posts[0].reply_audience = Some(ReplyAudience::Following);
+
+        press(&mut app, KeyCode::Char('r'));
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Replies restricted: Replies limited to followed accounts")
+        );
+
+        // Threads' own posts are always open to their author
+        let mut app = app_with(&[Platform::Threads], 1);
+        let state = app.platform_states.get_mut(&Platform::Threads).unwrap();
+        state.list_state.select(Some(0));
+        state.posts[0].author_handle = Some("me".to_string());
+        state.posts[0].reply_audience = Some(ReplyAudience::Author);
+        state.profile = Some(Profile {
+            handle: "me".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(app.reply_restriction(), None);
+
+        let state = app.platform_states.get_mut(&Platform::Threads).unwrap();
+        state.posts[0].author_handle = Some("someone".to_string());
+        press(&mut app, KeyCode::Char('>'));
+        assert_eq!(app.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_reply_navigation_without_replies() {
+        let mut app = app_with(&[Platform::Threads], 1);
+        press(&mut app, KeyCode::Char('l'));
+
+        press(&mut app, KeyCode::Char('j'));
+        press(&mut app, KeyCode::Char('k'));
+        assert_eq!(state(&app).reply_selection, None);
+    }
+
+    #[test]
+    fn test_io_keys_return_actions() {
+        let mut app = app_with(&[Platform::Threads], 1);
+
+        assert!(matches!(
+            press(&mut app, KeyCode::Char('R')),
+            Some(Action::Refresh)
+        ));
+        assert!(matches!(
+            press(&mut app, KeyCode::Char('Y')),
+            Some(Action::CopyAuthor(AuthorField::Handle))
+        ));
+        assert!(matches!(
+            press(&mut app, KeyCode::Char('y')),
+            Some(Action::CopyText)
+        ));
+        assert!(matches!(
+            press(&mut app, KeyCode::Char('o')),
+            Some(Action::OpenPermalink)
+        ));
+        assert!(press(&mut app, KeyCode::Char('j')).is_none());
+    }
+
+    #[test]
+    fn test_compose_and_send() {
+        let mut app = app_with(&[Platform::Threads], 1);
+
+        press(&mut app, KeyCode::Char('p'));
+        assert_eq!(app.input_mode, InputMode::Posting);
+        press(&mut app, KeyCode::Char('h'));
+        press(&mut app, KeyCode::Char('i'));
+        assert_eq!(app.input_buffer, "hi");
+
+        // Enter starts a new line; Ctrl+S sends
+        assert!(press(&mut app, KeyCode::Enter).is_none());
+        press(&mut app, KeyCode::Char('!'));
+        assert_eq!(app.input_buffer, "hi\n!");
+        assert!(matches!(
+            app.apply_key(KeyCode::Char('s'), KeyModifiers::CONTROL),
+            Some(Action::Send { keep_open: false })
+        ));
+        assert!(matches!(
+            app.apply_key(KeyCode::Enter, KeyModifiers::ALT),
+            Some(Action::Send { keep_open: true })
+        ));
+    }
+
+    #[test]
+    fn test_overlong_post_is_not_sent() {
+        let mut app = app_with(&[Platform::Threads, Platform::Bluesky], 1);
+        app.current_platform = Platform::Threads;
+
+        press(&mut app, KeyCode::Char('P'));
+        app.input_buffer = "x".repeat(400);
+        assert_eq!(app.compose_count(), Some((400, 300)));
+        assert!(
+            app.apply_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+                .is_none()
+        );
+        assert_eq!(app.input_mode, InputMode::CrossPosting);
+
+        // Threads alone allows 500
+        press(&mut app, KeyCode::Esc);
+        press(&mut app, KeyCode::Char('p'));
+        app.input_buffer = "x".repeat(400);
+        assert!(matches!(
+            app.apply_key(KeyCode::Char('s'), KeyModifiers::CONTROL),
+            Some(Action::Send { .. })
+        ));
+    }
+
+    #[test]
+    fn test_empty_composer_closes_without_sending() {
+        let mut app = app_with(&[Platform::Threads], 1);
+
+        press(&mut app, KeyCode::Char('p'));
+        press(&mut app, KeyCode::Enter);
+        assert!(
+            app.apply_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+                .is_none()
+        );
+        assert_eq!(app.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_goto_parses_link() {
+        let mut app = app_with(&[Platform::Threads], 1);
+
+        press(&mut app, KeyCode::Char('O'));
+        assert_eq!(app.input_mode, InputMode::Goto);
+        for c in "nonsense".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        assert!(press(&mut app, KeyCode::Enter).is_none());
+        assert_eq!(app.input_mode, InputMode::Goto);
+        assert!(app.status_message.is_some());
+
+        app.input_buffer = "https://bsky.app/profile/alice.test/post/3kxyz".to_string();
+        match press(&mut app, KeyCode::Enter) {
+            Some(Action::OpenPost(link)) => {
+                assert_eq!(link.platform, Platform::Bluesky);
+                assert_eq!(link.id, "at://alice.test/app.bsky.feed.post/3kxyz");
+            }
+            other => panic!("expected OpenPost, got {:?}", other),
+        }
+        assert_eq!(app.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_conversation_toggle_reloads_replies() {
+        let mut app = app_with(&[Platform::Threads], 1);
+        let platform = app.current_platform;
+        app.platform_states
+            .get_mut(&platform)
+            .unwrap()
+            .loaded_replies_for = Some("0".to_string());
+
+        press(&mut app, KeyCode::Char('c'));
+        assert!(app.show_conversation);
+        assert!(state(&app).loaded_replies_for.is_none());
+
+        press(&mut app, KeyCode::Char('c'));
+        assert!(!app.show_conversation);
+    }
+
+    #[test]
+    fn test_unsupported_keys_are_blocked() {
+        let mut app = app_with(&[Platform::Threads], 1);
+        let client = StubClient(Capabilities {
+            conversations: true,
+            ..Default::default()
+        });
+        app.clients
+            .insert(Platform::Threads, Arc::new(Box::new(client)));
+
+        assert!(press(&mut app, KeyCode::Char('+')).is_none());
+        assert!(app.status_message.as_deref().unwrap().contains("Following"));
+        press(&mut app, KeyCode::Char('M'));
+        assert!(app.pending_moderation.is_none());
+
+        press(&mut app, KeyCode::Char('c'));
+        assert!(app.show_conversation);
+    }
+
+    #[test]
+    fn test_ctrl_r_pauses_refresh_without_replying() {
+        let mut app = app_with(&[Platform::Threads], 1);
+
+        app.apply_key(KeyCode::Char('r'), KeyModifiers::CONTROL);
+        assert!(app.refresh_paused.load(Ordering::Relaxed));
+        assert_eq!(app.input_mode, InputMode::Normal);
+
+        app.apply_key(KeyCode::Char('r'), KeyModifiers::CONTROL);
+        assert!(!app.refresh_paused.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn test_status_log_keeps_new_messages() {
+        let mut app = app_with(&[Platform::Threads], 1);
+
+        app.status_message = Some("Threads error: API error: {\"code\":190}".to_string());
+        app.log_status();
+        app.log_status();
+        assert_eq!(app.status_log.len(), 1);
+
+        press(&mut app, KeyCode::Char('E'));
+        assert!(app.show_log);
+        press(&mut app, KeyCode::Esc);
+        assert!(!app.show_log);
+    }
+
+    #[test]
+    fn test_with_config_applies_tunables() {
+        let config = Config {
+            read_only: true,
+            pause_auto_refresh: true,
+            refresh_interval_secs: Some(1),
+            list_format: Some("{handle}: {text}".to_string()),
+            ..Default::default()
+        };
+        let clients: HashMap<Platform, Box<dyn SocialClient>> = HashMap::from([(
+            Platform::Threads,
+            Box::new(StubClient(ALL_FEATURES)) as Box<dyn SocialClient>,
+        )]);
+        let app = App::with_config(clients, AppConfig::from_config(&config));
+
+        assert!(app.read_only);
+        assert!(app.refresh_paused.load(Ordering::Relaxed));
+        assert_eq!(app.refresh_interval, MIN_REFRESH_INTERVAL);
+        assert!(app.list_format.is_some());
+        assert!(!app.stream_bluesky);
+
+        let disabled = AppConfig::from_config(&Config {
+            refresh_interval_secs: Some(0),
+            ..Default::default()
         });
+        assert!(disabled.refresh_paused);
+        assert_eq!(disabled.refresh_interval, REFRESH_INTERVAL);
+    }
+
+    #[test]
+    fn test_reply_sort_cycles_and_keeps_nesting() {
+        let mut app = app_with(&[Platform::Threads], 1);
+        let at = |id: &str, hour: u32, replies| {
+            let mut r = reply(id, replies);
+            r.post.timestamp = Some(format!("2024-06-01T{:02}:00:00+0000", hour));
+            r
+        };
+        let threads = app.platform_states.get_mut(&Platform::Threads).unwrap();
+        threads.loaded_replies = vec![
+            at(
+                "a",
+                10,
+                vec![at("a2", 13, Vec::new()), at("a1", 11, Vec::new())],
+            ),
+            at("b", 12, Vec::new()),
+        ];
+        threads.selected_replies = threads.loaded_replies.clone();
+        threads.reply_selection = Some(3); // "b"
+
+        let ids = |app: &App| {
+            ReplyThread::flatten(state(app).selected_replies.clone())
+                .into_iter()
+                .map(|p| p.id)
+                .collect::<Vec<_>>()
+        };
+
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(ids(&app), vec!["b", "a", "a2", "a1"]);
+        assert_eq!(state(&app).reply_selection, Some(0));
+
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(ids(&app), vec!["a", "a1", "a2", "b"]);
+
+        // No counts on these replies, so most-engaged is skipped
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.reply_sort, ReplySort::Api);
+        assert_eq!(ids(&app), vec!["a", "a2", "a1", "b"]);
+    }
+
+    #[test]
+    fn test_input_edits_at_cursor() {
+        let mut app = app_with(&[Platform::Threads], 0);
+        press(&mut app, KeyCode::Char('p'));
+        for c in "héllo".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+
+        press(&mut app, KeyCode::Left);
+        press(&mut app, KeyCode::Left);
+        press(&mut app, KeyCode::Left);
+        press(&mut app, KeyCode::Backspace);
+        assert_eq!(app.input_buffer, "hllo");
+        press(&mut app, KeyCode::Char('ë'));
+        assert_eq!(app.input_buffer, "hëllo");
+
+        press(&mut app, KeyCode::Home);
+        press(&mut app, KeyCode::Delete);
+        press(&mut app, KeyCode::End);
+        press(&mut app, KeyCode::Char('!'));
+        press(&mut app, KeyCode::Right);
+        assert_eq!(app.input_buffer, "ëllo!");
+        assert_eq!(app.input_cursor, 5);
+    }
+
+    #[test]
+    fn test_layout_input_wraps_and_places_cursor() {
+        let (rows, at) = layout_input("abcdef\ngh", 7, 4);
+        assert_eq!(rows, vec!["abcd", "ef", "gh"]);
+        assert_eq!(at, (2, 0));
+
+        // At the end of a full row, the cursor moves to the next one
+        assert_eq!(layout_input("abcd", 4, 4).1, (1, 0));
+        assert_eq!(layout_input("ab\n", 2, 4).1, (0, 2));
+        assert_eq!(layout_input("", 0, 4), (vec![String::new()], (0, 0)));
+    }
+
+    #[test]
+    fn test_delete_confirms_and_keeps_selection_in_range() {
+        let mut app = app_with(&[Platform::Threads], 2);
+        let threads = app.platform_states.get_mut(&Platform::Threads).unwrap();
+        threads.list_state.select(Some(1));
+
+        press(&mut app, KeyCode::Char('d'));
+        assert_eq!(app.input_mode, InputMode::ConfirmDelete);
+        assert!(press(&mut app, KeyCode::Char('n')).is_none());
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(app.pending_delete.is_none());
+
+        press(&mut app, KeyCode::Char('d'));
+        let action = press(&mut app, KeyCode::Char('y'));
+        assert!(matches!(action, Some(Action::DeletePost(ref id)) if id == "1"));
+
+        // Deleting the last post moves the selection up, then clears it
+        let threads = app.platform_states.get_mut(&Platform::Threads).unwrap();
+        threads.remove_post("1");
+        assert_eq!(threads.list_state.selected(), Some(0));
+        threads.remove_post("0");
+        assert!(threads.posts.is_empty());
+        assert_eq!(threads.list_state.selected(), None);
+    }
+
+    #[test]
+    fn test_detail_scrolls_without_replies() {
+        let mut app = app_with(&[Platform::Threads], 1);
+        app.active_panel = Panel::Detail;
+        app.detail_height = 10;
+
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(app.detail_scroll, 1);
+        press(&mut app, KeyCode::PageDown);
+        assert_eq!(app.detail_scroll, 10);
+        press(&mut app, KeyCode::PageUp);
+        press(&mut app, KeyCode::Char('k'));
+        press(&mut app, KeyCode::Char('k'));
+        assert_eq!(app.detail_scroll, 0);
+
+        // With replies loaded, j/k select them instead
+        app.platform_states
+            .get_mut(&Platform::Threads)
+            .unwrap()
+            .selected_replies = vec![reply("r", Vec::new())];
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(app.detail_scroll, 0);
+        assert_eq!(state(&app).reply_selection, Some(0));
+    }
+
+    #[test]
+    fn test_overlapping_pages_leave_no_duplicates() {
+        let mut app = app_with(&[Platform::Threads], 0);
+        let state = app.platform_states.get_mut(&Platform::Threads).unwrap();
+
+        // Two pages sharing posts 3 and 4, as a slow cursor returns them
+        let first: Vec<Post> = (1..=4).map(|i| post(&i.to_string())).collect();
+        let second: Vec<Post> = (3..=6).map(|i| post(&i.to_string())).collect();
+        state.set_posts(first.into_iter().chain(second).collect());
+
+        let ids: Vec<&str> = state.posts.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "2", "3", "4", "5", "6"]);
+    }
+
+    #[test]
+    fn test_unread_jump_selects_oldest_unread() {
+        let mut app = app_with(&[Platform::Threads], 0);
+        app.ui_state = Some(UiState::default());
+        let posts: Vec<Post> = (0..4)
+            .map(|i| Post {
+                timestamp: Some(format!("2024-06-01T1{}:00:00+0000", 4 - i)),
+                ..post(&i.to_string())
+            })
+            .collect();
+        app.ui_state
+            .as_mut()
+            .unwrap()
+            .mark_read(Platform::Threads, &posts[2]);
+        app.platform_states
+            .get_mut(&Platform::Threads)
+            .unwrap()
+            .posts = posts;
+
+        press(&mut app, KeyCode::Char('n'));
+        assert_eq!(state(&app).list_state.selected(), Some(1));
+        assert!(matches!(
+            press(&mut app, KeyCode::Char('A')),
+            Some(Action::MarkAllRead)
+        ));
+    }
+
+    #[test]
+    fn test_settings_overlay_toggles_and_saves_on_close() {
+        let mut app = app_with(&[Platform::Threads], 1);
+
+        press(&mut app, KeyCode::Char(','));
+        assert!(app.show_settings);
+        assert!(press(&mut app, KeyCode::Enter).is_none());
+        assert!(app.refresh_paused.load(Ordering::Relaxed));
+
+        press(&mut app, KeyCode::Char('j'));
+        press(&mut app, KeyCode::Char(' '));
+        assert_eq!(app.refresh_interval, Duration::from_secs(30));
+
+        // Keys go to the overlay, not the post list
+        press(&mut app, KeyCode::Char('j'));
+        press(&mut app, KeyCode::Char('p'));
+        assert_eq!(app.input_mode, InputMode::Normal);
+        press(&mut app, KeyCode::Enter);
+        assert!(app.confirm_before_post);
+
+        assert!(matches!(
+            press(&mut app, KeyCode::Esc),
+            Some(Action::SaveSettings)
+        ));
+        assert!(!app.show_settings);
+    }
+
+    #[test]
+    fn test_opened_post_survives_refresh() {
+        let mut app = app_with(&[Platform::Threads], 2);
+        let platform = app.current_platform;
+        let state = app.platform_states.get_mut(&platform).unwrap();
+
+        state.open_post(post("linked"));
+        assert_eq!(state.list_state.selected(), Some(0));
+        assert_eq!(state.posts.len(), 3);
+
+        state.set_posts(vec![post("0"), post("1")]);
+        assert_eq!(state.posts[0].id, "linked");
+        assert_eq!(state.posts.len(), 3);
+    }
+
+    #[test]
+    fn test_jump_to_parent() {
+        let mut app = app_with(&[Platform::Threads], 3);
+        let platform = app.current_platform;
+        let posts = app.platform_states.get_mut(&platform).unwrap();
+        posts.list_state.select(Some(2));
+        posts.posts[2].root_id = Some("0".to_string());
+        posts.selected_replies = vec![reply("a", vec![reply("a1", Vec::new())])];
+        posts.reply_selection = Some(1);
+
+        // Nested reply -> its parent -> the post the replies belong to
+        assert!(press(&mut app, KeyCode::Char('U')).is_none());
+        assert_eq!(state(&app).reply_selection, Some(0));
+        press(&mut app, KeyCode::Char('U'));
+        assert_eq!(state(&app).reply_selection, None);
+
+        // A reply post jumps to its root in the list
+        press(&mut app, KeyCode::Char('U'));
+        assert_eq!(state(&app).list_state.selected(), Some(0));
+        press(&mut app, KeyCode::Char('U'));
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Already at the top of the thread")
+        );
+
+        // A root that isn't loaded is fetched
+        let posts = app.platform_states.get_mut(&platform).unwrap();
+        posts.list_state.select(Some(1));
+        posts.posts[1].root_id = Some("elsewhere".to_string());
+        match press(&mut app, KeyCode::Char('U')) {
+            Some(Action::OpenPost(link)) => {
+                assert_eq!(link.platform, Platform::Threads);
+                assert_eq!(link.id, "elsewhere");
+            }
+            other => panic!("expected OpenPost, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_post_opened_switches_platform() {
+        let mut app = app_with(&[Platform::Threads, Platform::Bluesky], 1);
+        app.current_platform = Platform::Threads;
+        let link = PostLink {
+            platform: Platform::Bluesky,
+            id: "linked".to_string(),
+        };
+
+        app.post_opened(link, Ok(post("linked")));
+        assert_eq!(app.current_platform, Platform::Bluesky);
+        assert_eq!(app.active_panel, Panel::Detail);
+        assert_eq!(app.selected_post().map(|p| p.id.as_str()), Some("linked"));
+    }
+
+    #[test]
+    fn test_read_only_blocks_posting() {
+        let mut app = app_with(&[Platform::Threads], 1);
+        app.read_only = true;
+
+        press(&mut app, KeyCode::Char('p'));
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(app.status_message.is_some());
+    }
+
+    #[test]
+    fn test_offline_after_repeated_failures() {
+        let mut app = app_with(&[Platform::Threads], 1);
+
+        for _ in 0..OFFLINE_AFTER_FAILURES - 1 {
+            app.record_network(false);
+        }
+        assert!(!app.offline);
+        app.record_network(false);
+        assert!(app.offline);
+
+        press(&mut app, KeyCode::Char('p'));
+        assert_eq!(app.input_mode, InputMode::Normal);
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(state(&app).list_state.selected(), Some(0));
+
+        app.record_network(true);
+        assert!(!app.offline);
+        assert_eq!(app.network_failures, 0);
+    }
+
+    #[test]
+    fn test_help_popup_ignores_other_keys() {
+        let mut app = app_with(&[Platform::Threads], 1);
+
+        press(&mut app, KeyCode::Char('?'));
+        press(&mut app, KeyCode::Char('q'));
+        assert!(app.show_help);
+        assert!(app.running);
+
+        press(&mut app, KeyCode::Esc);
+        assert!(!app.show_help);
+    }
+
+    #[test]
+    fn test_truncate_preview_counts_chars() {
+        assert_eq!(truncate_preview("héllo wörld", 20), "héllo wörld");
+        assert_eq!(truncate_preview("héllo wörld", 8), "héllo...");
+        assert_eq!(truncate_preview("🦀🦀🦀🦀", 3), "...");
+
+        // 20 emoji are 80 bytes but fit in a 50-char row untouched
+        let emoji = "🦀".repeat(20);
+        assert_eq!(truncate_preview(&emoji, 50), emoji);
+
+        let cjk = "日本語".repeat(20);
+        let preview = truncate_preview(&cjk, 50);
+        assert_eq!(preview.chars().count(), 50);
+        assert!(preview.ends_with("..."));
     }
 }
@@ -0,0 +1,205 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::config::{Config, ConfigError, write_file};
+use crate::platform::{Platform, Post, parse_timestamp};
+
+/// TUI state kept between runs (~/.config/ndl/ui_state.json)
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct UiState {
+    /// Newest post viewed on each platform
+    #[serde(default)]
+    pub last_seen: HashMap<Platform, ReadMarker>,
+}
+
+/// The newest post viewed on a platform; anything posted after it is unread
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadMarker {
+    pub id: String,
+    pub timestamp: Option<String>,
+}
+
+impl ReadMarker {
+    fn from_post(post: &Post) -> Self {
+        Self {
+            id: post.id.clone(),
+            timestamp: post.timestamp.clone(),
+        }
+    }
+
+    /// Whether the marker has a timestamp posts can be compared against
+    fn has_timestamp(&self) -> bool {
+        self.timestamp
+            .as_deref()
+            .and_then(parse_timestamp)
+            .is_some()
+    }
+
+    /// Whether `post` came after this marker. Posts without a usable timestamp
+    /// never count as newer.
+    pub fn is_before(&self, post: &Post) -> bool {
+        if post.id == self.id {
+            return false;
+        }
+        let marker = self.timestamp.as_deref().and_then(parse_timestamp);
+        let post = post.timestamp.as_deref().and_then(parse_timestamp);
+        matches!((marker, post), (Some(marker), Some(post)) if post > marker)
+    }
+}
+
+impl UiState {
+    /// Get the state file path (~/.config/ndl/ui_state.json)
+    pub fn path() -> Result<PathBuf, ConfigError> {
+        Ok(Config::dir()?.join("ui_state.json"))
+    }
+
+    /// Load saved state, or return default if there is none
+    pub fn load() -> Result<Self, ConfigError> {
+        let path = Self::path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read_to_string(&path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    pub fn save(&self) -> Result<(), ConfigError> {
+        let contents = serde_json::to_string_pretty(self)?;
+        write_file(&Self::path()?, &contents)
+    }
+
+    /// Posts newer than the platform's marker (none before anything was viewed)
+    pub fn unread_count(&self, platform: Platform, posts: &[Post]) -> usize {
+        self.last_seen.get(&platform).map_or(0, |marker| {
+            posts.iter().filter(|p| marker.is_before(p)).count()
+        })
+    }
+
+    /// Index of the oldest unread post in a newest-first list, so catching up
+    /// can read upward from there
+    pub fn oldest_unread(&self, platform: Platform, posts: &[Post]) -> Option<usize> {
+        let marker = self.last_seen.get(&platform)?;
+        posts.iter().rposition(|p| marker.is_before(p))
+    }
+
+    /// Move the marker up to the newest of `posts`, returning whether it moved
+    pub fn mark_all_read(&mut self, platform: Platform, posts: &[Post]) -> bool {
+        let mut moved = false;
+        for post in posts {
+            moved |= self.mark_read(platform, post);
+        }
+        moved
+    }
+
+    /// Move the marker up to `post` if it's newer, returning whether it moved.
+    /// Posts without a usable timestamp can't be compared, so they never
+    /// become the marker; a marker without one is replaced by any post that has.
+    pub fn mark_read(&mut self, platform: Platform, post: &Post) -> bool {
+        if post
+            .timestamp
+            .as_deref()
+            .and_then(parse_timestamp)
+            .is_none()
+        {
+            return false;
+        }
+        match self.last_seen.get(&platform) {
+            Some(marker) if marker.has_timestamp() && !marker.is_before(post) => false,
+            _ => {
+                self.last_seen.insert(platform, ReadMarker::from_post(post));
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(id: &str, timestamp: &str) -> Post {
+        Post {
+            id: id.to_string(),
+            timestamp: Some(timestamp.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_unread_count_after_marker() {
+        let mut state = UiState::default();
+        let posts = vec![
+            post("3", "2024-06-01T12:00:00+0000"),
+            post("2", "2024-06-01T11:00:00.000Z"),
+            post("1", "2024-06-01T10:00:00+0000"),
+        ];
+        assert_eq!(state.unread_count(Platform::Threads, &posts), 0);
+
+        assert!(state.mark_read(Platform::Threads, &posts[2]));
+        assert_eq!(state.unread_count(Platform::Threads, &posts), 2);
+        assert_eq!(state.unread_count(Platform::Bluesky, &posts), 0);
+
+        // Scrolling back down to an older post doesn't move the marker back
+        assert!(state.mark_read(Platform::Threads, &posts[0]));
+        assert!(!state.mark_read(Platform::Threads, &posts[1]));
+        assert_eq!(state.unread_count(Platform::Threads, &posts), 0);
+    }
+
+    #[test]
+    fn test_marker_needs_a_timestamp() {
+        let mut state = UiState::default();
+        let undated = Post {
+            id: "x".to_string(),
+            ..Default::default()
+        };
+        let posts = vec![
+            post("2", "2024-06-01T11:00:00+0000"),
+            post("1", "2024-06-01T10:00:00+0000"),
+        ];
+
+        // A post without a timestamp never becomes the marker
+        assert!(!state.mark_read(Platform::Threads, &undated));
+        assert!(state.last_seen.is_empty());
+
+        // A marker saved without one is replaced rather than stuck forever
+        state.last_seen.insert(
+            Platform::Threads,
+            ReadMarker {
+                id: "old".to_string(),
+                timestamp: None,
+            },
+        );
+        assert!(state.mark_read(Platform::Threads, &posts[1]));
+        assert_eq!(state.unread_count(Platform::Threads, &posts), 1);
+    }
+
+    #[test]
+    fn test_oldest_unread_and_mark_all_read() {
+        let mut state = UiState::default();
+        let posts = vec![
+            post("3", "2024-06-01T12:00:00+0000"),
+            post("2", "2024-06-01T11:00:00+0000"),
+            post("1", "2024-06-01T10:00:00+0000"),
+        ];
+        assert_eq!(state.oldest_unread(Platform::Threads, &posts), None);
+
+        state.mark_read(Platform::Threads, &posts[2]);
+        assert_eq!(state.oldest_unread(Platform::Threads, &posts), Some(1));
+
+        assert!(state.mark_all_read(Platform::Threads, &posts));
+        assert_eq!(state.last_seen[&Platform::Threads].id, "3");
+        assert_eq!(state.oldest_unread(Platform::Threads, &posts), None);
+        assert!(!state.mark_all_read(Platform::Threads, &posts));
+    }
+
+    #[test]
+    fn test_state_roundtrip() {
+        let mut state = UiState::default();
+        state.mark_read(Platform::Bluesky, &post("at://x", "2024-06-01T12:00:00Z"));
+
+        let json = serde_json::to_string(&state).unwrap();
+        let loaded: UiState = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.last_seen, state.last_seen);
+    }
+}
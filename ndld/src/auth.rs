@@ -5,10 +5,30 @@ use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
-use ndl_core::OAUTH_SCOPES;
 pub use ndl_core::TokenResponse;
 
-const SESSION_TTL: Duration = Duration::from_secs(300); // 5 minutes
+/// Default session lifetime when `NDLD_SESSION_TTL_SECS` is unset
+pub const DEFAULT_SESSION_TTL: Duration = Duration::from_secs(300); // 5 minutes
+/// Bounds accepted for `NDLD_SESSION_TTL_SECS`
+pub const MIN_SESSION_TTL_SECS: u64 = 60;
+pub const MAX_SESSION_TTL_SECS: u64 = 1800;
+
+/// Parse and validate a session TTL (in seconds) from configuration
+pub fn parse_session_ttl(value: &str) -> Result<Duration, String> {
+    let secs: u64 = value
+        .trim()
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number of seconds", value))?;
+
+    if !(MIN_SESSION_TTL_SECS..=MAX_SESSION_TTL_SECS).contains(&secs) {
+        return Err(format!(
+            "{} is out of range ({}-{} seconds)",
+            secs, MIN_SESSION_TTL_SECS, MAX_SESSION_TTL_SECS
+        ));
+    }
+
+    Ok(Duration::from_secs(secs))
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "status", rename_all = "snake_case")]
@@ -23,19 +43,25 @@ pub struct AuthSession {
     pub id: String,
     pub state: RwLock<AuthState>,
     pub created_at: Instant,
+    pub ttl: Duration,
 }
 
 impl AuthSession {
     pub fn new() -> Self {
+        Self::with_ttl(DEFAULT_SESSION_TTL)
+    }
+
+    pub fn with_ttl(ttl: Duration) -> Self {
         Self {
             id: Uuid::new_v4().to_string(),
             state: RwLock::new(AuthState::Pending),
             created_at: Instant::now(),
+            ttl,
         }
     }
 
     pub fn is_expired(&self) -> bool {
-        self.created_at.elapsed() > SESSION_TTL
+        self.created_at.elapsed() > self.ttl
     }
 }
 
@@ -48,6 +74,7 @@ impl Default for AuthSession {
 #[derive(Clone)]
 pub struct SessionStore {
     sessions: Arc<DashMap<String, Arc<AuthSession>>>,
+    ttl: Duration,
 }
 
 impl Default for SessionStore {
@@ -58,13 +85,24 @@ impl Default for SessionStore {
 
 impl SessionStore {
     pub fn new() -> Self {
+        Self::with_ttl(DEFAULT_SESSION_TTL)
+    }
+
+    /// Create a store whose sessions expire after `ttl`
+    pub fn with_ttl(ttl: Duration) -> Self {
         Self {
             sessions: Arc::new(DashMap::new()),
+            ttl,
         }
     }
 
+    /// How long sessions last, reported to clients so they poll that long
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
     pub fn create_session(&self) -> Arc<AuthSession> {
-        let session = Arc::new(AuthSession::new());
+        let session = Arc::new(AuthSession::with_ttl(self.ttl));
         self.sessions
             .insert(session.id.clone(), Arc::clone(&session));
         session
@@ -74,8 +112,9 @@ impl SessionStore {
         self.sessions.get(id).map(|r| Arc::clone(r.value()))
     }
 
-    pub fn remove_session(&self, id: &str) {
-        self.sessions.remove(id);
+    /// Remove a session, returning whether it existed
+    pub fn remove_session(&self, id: &str) -> bool {
+        self.sessions.remove(id).is_some()
     }
 
     /// Remove expired sessions
@@ -96,14 +135,9 @@ impl OAuthConfig {
         format!("{}/auth/callback", self.public_url)
     }
 
-    pub fn authorization_url(&self, state: &str) -> String {
-        format!(
-            "https://threads.net/oauth/authorize?client_id={}&redirect_uri={}&scope={}&response_type=code&state={}",
-            self.client_id,
-            urlencoding::encode(&self.redirect_uri()),
-            OAUTH_SCOPES,
-            state
-        )
+    /// Authorization URL for a session requesting `scopes`
+    pub fn authorization_url(&self, state: &str, scopes: &str) -> String {
+        ndl_core::authorization_url(&self.client_id, &self.redirect_uri(), scopes, Some(state))
     }
 
     /// Exchange an authorization code for an access token, then upgrade to long-lived
@@ -1,4 +1,6 @@
-use ndld::auth::{OAuthConfig, SessionStore, spawn_cleanup_task};
+use ndld::auth::{
+    DEFAULT_SESSION_TTL, OAuthConfig, SessionStore, parse_session_ttl, spawn_cleanup_task,
+};
 use ndld::routes::{AppState, create_router};
 
 use axum_server::Handle;
@@ -92,6 +94,17 @@ async fn main() {
         .ok()
         .and_then(|p| p.parse().ok())
         .unwrap_or(8080);
+    let session_ttl = match env::var("NDLD_SESSION_TTL_SECS") {
+        Ok(value) => parse_session_ttl(&value)
+            .unwrap_or_else(|e| panic!("Invalid NDLD_SESSION_TTL_SECS: {}", e)),
+        Err(_) => DEFAULT_SESSION_TTL,
+    };
+
+    // Token exchange goes through ndl-core, which honors NDL_PROXY / NDL_CA_BUNDLE
+    ndl_core::set_user_agent_product("ndld");
+    if let Err(e) = ndl_core::http_client() {
+        panic!("{}", e);
+    }
 
     // TLS options (priority: ACME > manual TLS > plain HTTP)
     let acme_domain = env::var("NDLD_ACME_DOMAIN").ok();
@@ -110,7 +123,8 @@ async fn main() {
         public_url,
     };
 
-    let sessions = SessionStore::new();
+    let sessions = SessionStore::with_ttl(session_ttl);
+    tracing::info!("Auth session TTL: {}s", session_ttl.as_secs());
 
     // Spawn cleanup task
     spawn_cleanup_task(sessions.clone());
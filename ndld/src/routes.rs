@@ -2,13 +2,14 @@ use axum::{
     Router,
     extract::{ConnectInfo, Path, Query, State},
     http::{HeaderMap, StatusCode, request::Request},
-    response::{Html, IntoResponse, Json},
+    response::{IntoResponse, Json},
     routing::{get, post},
 };
 use maud::{DOCTYPE, Markup, html};
 use serde::{Deserialize, Serialize};
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::sync::Arc;
+use std::time::Duration;
 use tower_governor::{
     GovernorLayer, errors::GovernorError, governor::GovernorConfigBuilder,
     key_extractor::KeyExtractor,
@@ -82,6 +83,10 @@ use crate::auth::{AuthState, OAuthConfig, SessionStore};
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 const GIT_VERSION: &str = env!("NDLD_GIT_VERSION");
 
+/// How long the success page waits on the username lookup before rendering
+/// without it
+const USERNAME_LOOKUP_TIMEOUT: Duration = Duration::from_secs(3);
+
 #[derive(Clone)]
 pub struct AppState {
     pub sessions: SessionStore,
@@ -94,6 +99,8 @@ pub struct AppState {
 pub struct StartAuthResponse {
     pub session_id: String,
     pub auth_url: String,
+    /// Seconds until the session expires, so clients know how long to poll
+    pub expires_in: u64,
 }
 
 #[derive(Deserialize)]
@@ -104,6 +111,17 @@ pub struct CallbackParams {
     pub error_description: Option<String>,
 }
 
+/// Optional body for POST /auth/start
+#[derive(Debug, Default, Deserialize)]
+pub struct StartAuthRequest {
+    /// Request only the read scopes (no publishing)
+    #[serde(default)]
+    pub read_only: bool,
+    /// Explicit scope list (comma-separated), overriding `read_only`
+    #[serde(default)]
+    pub scopes: Option<String>,
+}
+
 #[derive(Serialize)]
 pub struct PollResponse {
     #[serde(flatten)]
@@ -117,17 +135,48 @@ pub struct ErrorResponse {
 
 // Route handlers
 
+/// A Threads scope name: lowercase letters and underscores only
+fn is_valid_scope(scope: &str) -> bool {
+    scope.chars().all(|c| c.is_ascii_lowercase() || c == '_')
+}
+
 /// POST /auth/start - Create a new auth session
-pub async fn start_auth(State(state): State<Arc<AppState>>) -> Json<StartAuthResponse> {
+pub async fn start_auth(
+    State(state): State<Arc<AppState>>,
+    request: Option<Json<StartAuthRequest>>,
+) -> Result<Json<StartAuthResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let request = request.map(|Json(r)| r).unwrap_or_default();
+    // Only the client's request decides the scopes; NDL_SCOPES is for ndl's
+    // own login, not for sessions started here
+    let scopes = match request
+        .scopes
+        .as_deref()
+        .and_then(ndl_core::normalize_scopes)
+    {
+        Some(scopes) => {
+            if let Some(bad) = scopes.split(',').find(|s| !is_valid_scope(s)) {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorResponse {
+                        error: format!("Invalid scope: {}", bad),
+                    }),
+                ));
+            }
+            scopes
+        }
+        None if request.read_only => ndl_core::READ_ONLY_SCOPES.to_string(),
+        None => ndl_core::OAUTH_SCOPES.to_string(),
+    };
     let session = state.sessions.create_session();
-    let auth_url = state.oauth.authorization_url(&session.id);
+    let auth_url = state.oauth.authorization_url(&session.id, &scopes);
 
     tracing::info!(session_id = %session.id, "Created new auth session");
 
-    Json(StartAuthResponse {
+    Ok(Json(StartAuthResponse {
         session_id: session.id.clone(),
         auth_url,
-    })
+        expires_in: state.sessions.ttl().as_secs(),
+    }))
 }
 
 /// GET /auth/callback - OAuth callback from Threads
@@ -177,10 +226,19 @@ pub async fn auth_callback(
     match state.oauth.exchange_code(&code).await {
         Ok(token) => {
             *session.state.write().await = AuthState::Completed {
-                access_token: token.access_token,
+                access_token: token.access_token.clone(),
             };
             tracing::info!(session_id = %session_id, "Token exchange successful");
-            Html(success_html()).into_response()
+
+            // Shown on the success page only; the username isn't stored or logged
+            let username = tokio::time::timeout(
+                USERNAME_LOOKUP_TIMEOUT,
+                ndl_core::fetch_username(&token.access_token),
+            )
+            .await
+            .ok()
+            .and_then(Result::ok);
+            success_html(username.as_deref()).into_response()
         }
         Err(e) => {
             *session.state.write().await = AuthState::Failed { error: e.clone() };
@@ -217,6 +275,38 @@ pub async fn poll_auth(
     Ok(Json(PollResponse { state: auth_state }))
 }
 
+/// POST /auth/cancel/:session_id - Abandon a pending auth session
+pub async fn cancel_auth(
+    State(state): State<Arc<AppState>>,
+    Path(session_id): Path<String>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+    let session = state.sessions.get_session(&session_id).ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: "Session not found or expired".to_string(),
+            }),
+        )
+    })?;
+
+    // Hold the write lock so the callback can't complete the session while
+    // it's being removed
+    let auth_state = session.state.write().await;
+    if !matches!(*auth_state, AuthState::Pending) {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse {
+                error: "Session is no longer pending".to_string(),
+            }),
+        ));
+    }
+    state.sessions.remove_session(&session_id);
+    drop(auth_state);
+
+    tracing::info!(session_id = %session_id, "Auth session cancelled by client");
+    Ok(StatusCode::NO_CONTENT)
+}
+
 /// GET /health - Health check with version info
 pub async fn health() -> Json<HealthResponse> {
     Json(HealthResponse {
@@ -333,6 +423,10 @@ pub async fn index() -> Markup {
                             li { "🔥 " a href="https://github.com/tokio-rs/tracing" { "tracing" } " - logging" }
                             li { "🔥 " a href="https://github.com/rustls/rustls" { "rustls" } " - TLS" }
                             li { "🔥 " a href="https://github.com/benwis/tower-governor" { "tower-governor" } " - rate limiting" }
+                            li { "🔥 " a href="https://github.com/snapview/tokio-tungstenite" { "tokio-tungstenite" } " - WebSockets" }
+                            li { "🔥 " a href="https://github.com/chronotope/chrono" { "chrono" } " - date and time" }
+                            li { "🔥 " a href="https://github.com/marshallpierce/rust-base64" { "base64" } " - encoding" }
+                            li { "🔥 " a href="https://github.com/atrium-rs/atrium" { "atrium-xrpc-client" } " - AT Protocol XRPC" }
                         }
                     }
 
@@ -699,6 +793,7 @@ fn base_router(state: Arc<AppState>) -> Router {
         .route("/auth/start", post(start_auth))
         .route("/auth/callback", get(auth_callback))
         .route("/auth/poll/{session_id}", get(poll_auth))
+        .route("/auth/cancel/{session_id}", post(cancel_auth))
         .route("/health", get(health))
         .with_state(state)
 }
@@ -716,7 +811,7 @@ pub fn create_router(state: Arc<AppState>) -> Router {
             .expect("Failed to create rate limiter for /auth/start"),
     );
 
-    // Rate limit for /auth/poll: 60 requests per minute per IP
+    // Rate limit for /auth/poll and /auth/cancel: 60 requests per minute per IP
     // Polling is expected to be frequent during auth flow
     let poll_limiter = Arc::new(
         GovernorConfigBuilder::default()
@@ -734,6 +829,7 @@ pub fn create_router(state: Arc<AppState>) -> Router {
 
     let auth_poll = Router::new()
         .route("/auth/poll/{session_id}", get(poll_auth))
+        .route("/auth/cancel/{session_id}", post(cancel_auth))
         .layer(GovernorLayer::new(poll_limiter));
 
     Router::new()
@@ -756,37 +852,46 @@ pub fn create_test_router(state: Arc<AppState>) -> Router {
 
 // HTML responses
 
-fn success_html() -> &'static str {
-    r#"<!DOCTYPE html>
-<html>
-<head>
-    <title>ndl - Authorization Complete</title>
-    <style>
-        body {
-            font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif;
-            display: flex;
-            justify-content: center;
-            align-items: center;
-            height: 100vh;
-            margin: 0;
-            background: #0a0a0a;
-            color: #fff;
-        }
-        .container {
-            text-align: center;
-            padding: 2rem;
+/// The page after a successful login, naming the Threads account if the
+/// lookup worked
+fn success_html(username: Option<&str>) -> Markup {
+    html! {
+        (DOCTYPE)
+        html {
+            head {
+                title { "ndl - Authorization Complete" }
+                style {
+                    r#"
+                    body {
+                        font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif;
+                        display: flex;
+                        justify-content: center;
+                        align-items: center;
+                        height: 100vh;
+                        margin: 0;
+                        background: #0a0a0a;
+                        color: #fff;
+                    }
+                    .container {
+                        text-align: center;
+                        padding: 2rem;
+                    }
+                    h1 { color: #00d4aa; }
+                    p { color: #888; }
+                    "#
+                }
+            }
+            body {
+                div.container {
+                    h1 { "Authorization Complete" }
+                    @if let Some(username) = username {
+                        p { "Logged in as " strong { "@" (username) } "." }
+                    }
+                    p { "You can close this window and return to ndl." }
+                }
+            }
         }
-        h1 { color: #00d4aa; }
-        p { color: #888; }
-    </style>
-</head>
-<body>
-    <div class="container">
-        <h1>Authorization Complete</h1>
-        <p>You can close this window and return to ndl.</p>
-    </div>
-</body>
-</html>"#
+    }
 }
 
 fn error_html(error: &str) -> Markup {
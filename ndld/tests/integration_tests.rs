@@ -3,10 +3,11 @@ use axum::{
     http::{Request, StatusCode},
 };
 use ndld::{
-    auth::{OAuthConfig, SessionStore},
+    auth::{AuthState, OAuthConfig, SessionStore, parse_session_ttl},
     routes::{AppState, create_test_router},
 };
 use std::sync::Arc;
+use std::time::Duration;
 use tower::ServiceExt;
 
 fn create_test_state() -> Arc<AppState> {
@@ -94,6 +95,7 @@ async fn test_start_auth() {
 
     assert!(json["session_id"].is_string());
     assert!(json["auth_url"].is_string());
+    assert_eq!(json["expires_in"], 300);
 
     let auth_url = json["auth_url"].as_str().unwrap();
     assert!(auth_url.contains("threads.net/oauth/authorize"));
@@ -101,6 +103,87 @@ async fn test_start_auth() {
     assert!(auth_url.contains("redirect_uri="));
 }
 
+#[tokio::test]
+async fn test_start_auth_read_only() {
+    let state = create_test_state();
+    let app = create_test_router(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/auth/start")
+                .header("content-type", "application/json")
+                .body(Body::from(r#"{"read_only": true}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    let auth_url = json["auth_url"].as_str().unwrap();
+    assert!(auth_url.contains("scope=threads_basic%2Cthreads_read_replies&"));
+    assert!(!auth_url.contains("threads_content_publish"));
+}
+
+#[tokio::test]
+async fn test_start_auth_custom_scopes() {
+    let state = create_test_state();
+    let app = create_test_router(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/auth/start")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    r#"{"scopes": "threads_basic, threads_manage_insights,threads_basic"}"#,
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    let auth_url = json["auth_url"].as_str().unwrap();
+    assert!(auth_url.contains("scope=threads_basic%2Cthreads_manage_insights&"));
+}
+
+#[tokio::test]
+async fn test_start_auth_rejects_malformed_scopes() {
+    let state = create_test_state();
+    let app = create_test_router(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/auth/start")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    r#"{"scopes": "threads_basic&state=x&redirect_uri=https://evil.example"}"#,
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test]
 async fn test_poll_pending_session() {
     let state = create_test_state();
@@ -156,6 +239,76 @@ async fn test_poll_nonexistent_session() {
     assert!(json["error"].as_str().unwrap().contains("not found"));
 }
 
+#[tokio::test]
+async fn test_cancel_session() {
+    let state = create_test_state();
+
+    let session = state.sessions.create_session();
+    let session_id = session.id.clone();
+
+    let app = create_test_router(Arc::clone(&state));
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/auth/cancel/{}", session_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert!(state.sessions.get_session(&session_id).is_none());
+}
+
+#[tokio::test]
+async fn test_cancel_completed_session_conflicts() {
+    let state = create_test_state();
+
+    let session = state.sessions.create_session();
+    let session_id = session.id.clone();
+    *session.state.write().await = AuthState::Completed {
+        access_token: "token".to_string(),
+    };
+
+    let app = create_test_router(Arc::clone(&state));
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/auth/cancel/{}", session_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert!(state.sessions.get_session(&session_id).is_some());
+}
+
+#[tokio::test]
+async fn test_cancel_nonexistent_session() {
+    let state = create_test_state();
+    let app = create_test_router(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/auth/cancel/nonexistent-session-id")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
 #[tokio::test]
 async fn test_callback_missing_state() {
     let state = create_test_state();
@@ -308,3 +461,23 @@ async fn test_tos_page() {
 
     assert!(html.contains("Terms of Service"));
 }
+
+#[test]
+fn test_parse_session_ttl() {
+    assert_eq!(parse_session_ttl("300"), Ok(Duration::from_secs(300)));
+    assert_eq!(parse_session_ttl("60"), Ok(Duration::from_secs(60)));
+    assert_eq!(parse_session_ttl("1800"), Ok(Duration::from_secs(1800)));
+
+    assert!(parse_session_ttl("59").is_err());
+    assert!(parse_session_ttl("1801").is_err());
+    assert!(parse_session_ttl("five minutes").is_err());
+}
+
+#[test]
+fn test_session_store_applies_ttl() {
+    let store = SessionStore::with_ttl(Duration::from_secs(900));
+    let session = store.create_session();
+
+    assert_eq!(session.ttl, Duration::from_secs(900));
+    assert!(!session.is_expired());
+}